@@ -0,0 +1,409 @@
+//! Kanban board of the issue list.
+//!
+//! The board lays out the issues the list shows (same project, filters and sort
+//! order) in one column per issue status. Moving a card to the neighbouring
+//! column sends an update with just the new status, queued like any other
+//! update while the server can't be reached.
+
+use anyhow::Result;
+
+use super::offline_queue::QueuedWrite;
+use super::state::{App, Screen};
+use crate::redmine::{IdName, Issue, UpdateIssue};
+
+/// A board column: a status and the listed issues that have it
+pub struct BoardColumn<'a> {
+    pub status: IdName,
+    pub issues: Vec<&'a Issue>,
+}
+
+impl App {
+    /// Switch to the board of the issues the list shows
+    pub fn show_board(&mut self) {
+        if !self.shows_issue_list() {
+            self.error_message = Some("Please select a project first".to_string());
+            return;
+        }
+        self.screen = Screen::Board;
+        // Start on the issue under the list cursor
+        if let Some(issue_id) = self.get_issue_at_cursor().map(|i| i.id) {
+            self.select_board_issue(issue_id);
+        }
+    }
+
+    /// Every status in workflow order, followed by statuses only the listed issues know about
+    pub fn board_columns(&self) -> Vec<BoardColumn<'_>> {
+        let mut columns: Vec<BoardColumn> = self
+            .statuses
+            .iter()
+            .map(|status| BoardColumn {
+                status: IdName {
+                    id: status.id,
+                    name: status.name.clone(),
+                },
+                issues: Vec::new(),
+            })
+            .collect();
+        for issue in &self.filtered_issues {
+            match columns.iter_mut().find(|column| column.status.id == issue.status.id) {
+                Some(column) => column.issues.push(issue),
+                None => columns.push(BoardColumn {
+                    status: issue.status.clone(),
+                    issues: vec![issue],
+                }),
+            }
+        }
+        columns
+    }
+
+    /// Index of the focused column, clamped to the columns there are
+    pub fn board_column_index(&self, column_count: usize) -> usize {
+        self.board_column.min(column_count.saturating_sub(1))
+    }
+
+    /// Index of the highlighted card in a column, clamped to its cards
+    pub fn board_card_index(&self, column: &BoardColumn) -> usize {
+        self.board_cards
+            .get(&column.status.id)
+            .copied()
+            .unwrap_or(0)
+            .min(column.issues.len().saturating_sub(1))
+    }
+
+    pub fn selected_board_issue(&self) -> Option<&Issue> {
+        let columns = self.board_columns();
+        let column = columns.get(self.board_column_index(columns.len()))?;
+        column.issues.get(self.board_card_index(column)).copied()
+    }
+
+    /// Focus the next (or with a negative step, previous) column, stopping at the edges
+    pub fn move_board_column(&mut self, step: isize) {
+        let count = self.board_columns().len();
+        let last = count.saturating_sub(1) as isize;
+        self.board_column = (self.board_column_index(count) as isize + step).clamp(0, last) as usize;
+    }
+
+    /// Move the highlight within the focused column, clamped to its cards
+    pub fn move_board_card(&mut self, delta: isize) {
+        let columns = self.board_columns();
+        let Some(column) = columns.get(self.board_column_index(columns.len())) else {
+            return;
+        };
+        let last = column.issues.len().saturating_sub(1) as isize;
+        let index = (self.board_card_index(column) as isize + delta).clamp(0, last) as usize;
+        let status_id = column.status.id;
+        self.board_cards.insert(status_id, index);
+    }
+
+    /// Focus the column and card of an issue, if the board shows it
+    pub fn select_board_issue(&mut self, issue_id: u64) {
+        let position = self
+            .board_columns()
+            .iter()
+            .enumerate()
+            .find_map(|(column_index, column)| {
+                column
+                    .issues
+                    .iter()
+                    .position(|issue| issue.id == issue_id)
+                    .map(|card| (column_index, column.status.id, card))
+            });
+        if let Some((column_index, status_id, card)) = position {
+            self.board_column = column_index;
+            self.board_cards.insert(status_id, card);
+        }
+    }
+
+    /// Ask the main loop to move the selected card to the next (or with a negative step, previous) status
+    pub fn request_move_board_card(&mut self, step: isize) {
+        let columns = self.board_columns();
+        let index = self.board_column_index(columns.len());
+        let Some(issue_id) = columns
+            .get(index)
+            .and_then(|column| column.issues.get(self.board_card_index(column)))
+            .map(|issue| issue.id)
+        else {
+            return;
+        };
+        let target = usize::try_from(index as isize + step)
+            .ok()
+            .and_then(|i| columns.get(i))
+            .map(|column| column.status.clone());
+
+        let Some(status) = target else {
+            self.status_message = Some(format!(
+                "#{} is already in the {} status",
+                issue_id,
+                if step < 0 { "first" } else { "last" }
+            ));
+            return;
+        };
+        if self.client.is_none() {
+            self.error_message = Some("Not connected to a Redmine server".to_string());
+            return;
+        }
+        self.status_message = Some(format!("Moving #{} to {}...", issue_id, status.name));
+        self.move_board_card_to = Some((issue_id, status.id));
+    }
+
+    /// Change an issue's status, queueing the change while offline, and keep the card selected
+    pub async fn move_issue_to_status(&mut self, issue_id: u64, status_id: u64) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+        let status_name = self.status_ref(status_id).name;
+        let update = UpdateIssue {
+            status_id: Some(status_id),
+            ..Default::default()
+        };
+
+        // Changes already waiting in the queue go first
+        if self.has_pending_operations() {
+            self.queue_write(Some(issue_id), QueuedWrite::UpdateIssue(update));
+        } else {
+            match client.update_issue(issue_id, update.clone()).await {
+                Ok(()) => {
+                    // Cache the server's copy so the list and board show the new status
+                    match client.get_issue(issue_id).await {
+                        Ok(response) => self.db.insert_issue_with_journals(&response.issue)?,
+                        Err(e) => tracing::warn!("Failed to reload issue #{}: {}", issue_id, e),
+                    }
+                    self.apply_filters();
+                    self.status_message = Some(format!("Moved #{} to {}", issue_id, status_name));
+                }
+                Err(e) if e.may_have_been_applied() => {
+                    self.queue_timed_out_write(Some(issue_id), QueuedWrite::UpdateIssue(update));
+                }
+                Err(e) if e.is_offline() => {
+                    self.queue_write(Some(issue_id), QueuedWrite::UpdateIssue(update));
+                }
+                Err(e) => {
+                    self.report_error(&format!("Failed to move #{} to {}", issue_id, status_name), e);
+                    return Ok(());
+                }
+            }
+        }
+
+        self.select_board_issue(issue_id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::db::Database;
+    use crate::redmine::{IssueStatus, Project, RedmineClient};
+    use ratatui_image::picker::Picker;
+    use tempfile::TempDir;
+
+    fn status(id: u64, name: &str) -> IssueStatus {
+        IssueStatus {
+            id,
+            name: name.to_string(),
+            is_closed: name == "Closed",
+        }
+    }
+
+    /// An issue of project 1; higher ids were updated more recently
+    fn issue(id: u64, status_id: u64, status_name: &str) -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "project": {"id": 1, "name": "Project"},
+            "tracker": {"id": 1, "name": "Bug"},
+            "status": {"id": status_id, "name": status_name},
+            "priority": {"id": 2, "name": "Normal"},
+            "author": {"id": 1, "name": "Me"},
+            "subject": format!("Issue {}", id),
+            "created_on": "2025-01-01T10:00:00Z",
+            "updated_on": format!("2025-01-01T10:{:02}:00Z", id),
+        }))
+        .unwrap()
+    }
+
+    /// The board of project 1 over a fresh cache holding the given issues
+    fn board_app(issues: &[Issue]) -> (App, TempDir) {
+        let temp = TempDir::new().unwrap();
+        let db = Database::new(temp.path().join("cache.db")).unwrap();
+        let project: Project = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": "Project",
+            "identifier": "project",
+            "description": null,
+            "status": 1,
+            "parent": null,
+            "created_on": null,
+            "updated_on": null,
+        }))
+        .unwrap();
+        db.insert_projects(std::slice::from_ref(&project)).unwrap();
+        db.insert_issues(issues).unwrap();
+
+        let mut app = App::with_database(Config::default(), db, Picker::from_fontsize((8, 16)));
+        app.statuses = vec![status(1, "New"), status(2, "In Progress"), status(3, "Closed")];
+        app.selected_project = Some(project);
+        app.apply_filters();
+        app.show_board();
+        (app, temp)
+    }
+
+    /// Client for a server that refuses connections, so every request fails as offline
+    fn offline_client() -> RedmineClient {
+        RedmineClient::new("http://127.0.0.1:1".to_string(), "key".to_string())
+    }
+
+    /// Client for a server that accepts connections but never answers, so requests time out
+    fn unresponsive_client(listener: &std::net::TcpListener) -> RedmineClient {
+        RedmineClient::with_timeouts(
+            format!("http://{}", listener.local_addr().unwrap()),
+            "key".to_string(),
+            std::time::Duration::from_millis(200),
+            std::time::Duration::from_millis(200),
+        )
+    }
+
+    fn column_ids(app: &App) -> Vec<(String, Vec<u64>)> {
+        app.board_columns()
+            .iter()
+            .map(|column| (column.status.name.clone(), column.issues.iter().map(|i| i.id).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_board_columns() {
+        let (mut app, _temp) = board_app(&[
+            issue(1, 1, "New"),
+            issue(2, 2, "In Progress"),
+            issue(3, 1, "New"),
+            issue(4, 9, "Rejected"),
+        ]);
+
+        // Statuses in workflow order, issues in list order, unknown statuses last
+        let columns = column_ids(&app);
+        assert_eq!(
+            columns,
+            vec![
+                ("New".to_string(), vec![3, 1]),
+                ("In Progress".to_string(), vec![2]),
+                ("Closed".to_string(), vec![]),
+                ("Rejected".to_string(), vec![4]),
+            ]
+        );
+
+        // The board opens on the issue under the list cursor, the most recently updated
+        assert_eq!(app.board_column, 3);
+        assert_eq!(app.selected_board_issue().map(|i| i.id), Some(4));
+
+        // An empty column has no selected card, and moving within it stays put
+        app.move_board_column(-1);
+        assert_eq!(app.board_column, 2);
+        assert!(app.selected_board_issue().is_none());
+        app.move_board_card(1);
+        assert!(app.selected_board_issue().is_none());
+
+        // Column focus stops at the edges
+        app.move_board_column(5);
+        assert_eq!(app.board_column, 3);
+        app.move_board_column(-9);
+        assert_eq!(app.board_column, 0);
+    }
+
+    #[test]
+    fn test_board_columns_without_statuses() {
+        let (mut app, _temp) = board_app(&[issue(1, 1, "New")]);
+        app.statuses.clear();
+        assert_eq!(column_ids(&app), vec![("New".to_string(), vec![1])]);
+
+        let (mut app, _temp) = board_app(&[]);
+        app.statuses.clear();
+        assert!(app.board_columns().is_empty());
+        assert!(app.selected_board_issue().is_none());
+        app.move_board_column(1);
+        app.move_board_card(1);
+        assert_eq!(app.board_column, 0);
+    }
+
+    #[test]
+    fn test_request_move_board_card() {
+        let (mut app, _temp) = board_app(&[issue(1, 1, "New"), issue(4, 9, "Rejected")]);
+
+        // Without a server nothing is requested
+        app.request_move_board_card(-1);
+        assert!(app.move_board_card_to.is_none());
+        assert!(app.error_message.take().is_some());
+
+        app.client = Some(offline_client());
+
+        // Past the last and first columns
+        app.request_move_board_card(1);
+        assert!(app.move_board_card_to.is_none());
+        assert_eq!(app.status_message.as_deref(), Some("#4 is already in the last status"));
+        app.move_board_column(-3);
+        app.request_move_board_card(-1);
+        assert!(app.move_board_card_to.is_none());
+        assert_eq!(app.status_message.as_deref(), Some("#1 is already in the first status"));
+
+        // An empty column has no card to move
+        app.move_board_column(2);
+        app.status_message = None;
+        app.request_move_board_card(-1);
+        assert!(app.move_board_card_to.is_none());
+        assert!(app.status_message.is_none());
+
+        // The move goes to the neighbouring column's status
+        app.move_board_column(-2);
+        app.request_move_board_card(1);
+        assert_eq!(app.move_board_card_to, Some((1, 2)));
+    }
+
+    #[tokio::test]
+    async fn test_move_board_card_offline() {
+        let (mut app, _temp) = board_app(&[issue(1, 1, "New"), issue(3, 1, "New")]);
+        app.client = Some(offline_client());
+
+        // Highlight #1, the second card of "New", and move it on
+        app.move_board_card(1);
+        app.request_move_board_card(1);
+        let (issue_id, status_id) = app.move_board_card_to.take().unwrap();
+        assert_eq!((issue_id, status_id), (1, 2));
+        app.move_issue_to_status(issue_id, status_id).await.unwrap();
+
+        // Queued, shown right away, and still selected in its new column
+        assert_eq!(app.pending_operations_count, 1);
+        assert_eq!(app.db.get_issue(1).unwrap().unwrap().status.id, 2);
+        assert_eq!(
+            column_ids(&app)[..2],
+            [("New".to_string(), vec![3]), ("In Progress".to_string(), vec![1])]
+        );
+        assert_eq!(app.board_column, 1);
+        assert_eq!(app.selected_board_issue().map(|i| i.id), Some(1));
+
+        // The cursor left behind in "New" is clamped to the card that remains
+        app.move_board_column(-1);
+        assert_eq!(app.selected_board_issue().map(|i| i.id), Some(3));
+
+        // Later moves queue behind the first without trying the server
+        app.move_issue_to_status(3, 3).await.unwrap();
+        assert_eq!(app.pending_operations_count, 2);
+        assert_eq!(app.board_column, 2);
+        assert_eq!(app.selected_board_issue().map(|i| i.id), Some(3));
+        assert!(app.board_columns()[0].issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_move_board_card_timed_out() {
+        let (mut app, _temp) = board_app(&[issue(1, 1, "New")]);
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        app.client = Some(unresponsive_client(&listener));
+
+        // The server may have applied the move, so it is held instead of replayed
+        app.move_issue_to_status(1, 2).await.unwrap();
+        let operations = app.db.get_pending_operations().unwrap();
+        assert_eq!(operations.len(), 1);
+        assert!(operations[0].blocked);
+        assert!(app.error_message.as_deref().unwrap().contains("may have been saved anyway"));
+        assert_eq!(app.db.get_issue(1).unwrap().unwrap().status.id, 2);
+        drop(listener);
+    }
+}
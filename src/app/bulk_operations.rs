@@ -1,4 +1,5 @@
 //! Bulk operations for performing actions on multiple issues at once.
+use super::offline_queue::QueuedWrite;
 use super::state::App;
 
 impl App {
@@ -118,7 +119,7 @@ impl App {
             return Ok(());
         }
 
-        let client = self.client.as_ref().ok_or_else(|| anyhow::anyhow!("No API client"))?;
+        let client = self.client.clone().ok_or_else(|| anyhow::anyhow!("No API client"))?;
 
         // Extract values from the form
         let status_id = self.bulk_edit_form.as_ref()
@@ -146,6 +147,8 @@ impl App {
             start_date: None,
             due_date: None,
             estimated_hours: None,
+            fixed_version_id: None,
+            parent_issue_id: None,
             notes: None,
             private_notes: None,
             uploads: None,
@@ -154,16 +157,32 @@ impl App {
         // Track success/failure counts
         let total = self.selected_issues.len();
         let mut success_count = 0;
+        let mut queued_count = 0;
         let mut failed_issues = Vec::new();
 
-        // Update each issue
-        for issue_id in &self.selected_issues {
-            match client.update_issue(*issue_id, update.clone()).await {
+        // Update each issue, queueing the rest once the server can't be reached
+        let mut issue_ids: Vec<u64> = self.selected_issues.iter().copied().collect();
+        issue_ids.sort_unstable();
+        for issue_id in issue_ids {
+            if self.has_pending_operations() {
+                self.queue_write(Some(issue_id), QueuedWrite::UpdateIssue(update.clone()));
+                queued_count += 1;
+                continue;
+            }
+            match client.update_issue(issue_id, update.clone()).await {
                 Ok(_) => {
                     success_count += 1;
                 }
+                Err(e) if e.may_have_been_applied() => {
+                    self.queue_timed_out_write(Some(issue_id), QueuedWrite::UpdateIssue(update.clone()));
+                    queued_count += 1;
+                }
+                Err(e) if e.is_offline() => {
+                    self.queue_write(Some(issue_id), QueuedWrite::UpdateIssue(update.clone()));
+                    queued_count += 1;
+                }
                 Err(e) => {
-                    failed_issues.push((*issue_id, e.to_string()));
+                    failed_issues.push((issue_id, e.user_message()));
                 }
             }
         }
@@ -173,7 +192,12 @@ impl App {
         self.selected_issues.clear();
 
         // Show result message
-        if failed_issues.is_empty() {
+        if failed_issues.is_empty() && queued_count > 0 {
+            self.status_message = Some(format!(
+                "Updated {} issue(s), {} queued until the server is reachable",
+                success_count, queued_count
+            ));
+        } else if failed_issues.is_empty() {
             self.status_message = Some(format!("Successfully updated {} issue(s)", success_count));
         } else {
             let error_details = failed_issues
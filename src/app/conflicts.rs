@@ -0,0 +1,377 @@
+//! Conflict detection for issue updates.
+//!
+//! The update form remembers the issue's `updated_on` when it opens. Before the update is
+//! sent the issue is fetched again, and if someone changed it in the meantime the changed
+//! fields are collected from the journals so the user can merge, overwrite or abort.
+
+use chrono::{DateTime, Utc};
+
+use super::state::{App, InputMode};
+use crate::redmine::{Issue, RedmineClient, UpdateIssue};
+
+/// How to send an update after a conflict
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConflictResolution {
+    /// Keep their changes to fields we didn't edit, send ours for the rest
+    Merge,
+    /// Send the update as it is, replacing their changes
+    Overwrite,
+}
+
+/// A field changed on the server while the update form was open
+#[derive(Debug, Clone)]
+pub struct FieldChange {
+    /// Journal attribute name, e.g. `status_id`
+    pub field: String,
+    /// Value when the form opened
+    pub before: Option<String>,
+    /// Value on the server now
+    pub theirs: Option<String>,
+    /// Value our update sets, if it touches the field
+    pub ours: Option<String>,
+    pub changed_by: String,
+}
+
+impl FieldChange {
+    /// Our update changes the field from what the form started with
+    pub fn edited_by_us(&self) -> bool {
+        self.ours.is_some() && !same_value(&self.field, self.ours.as_deref(), self.before.as_deref())
+    }
+
+    /// Both sides changed the field to different values
+    pub fn is_conflict(&self) -> bool {
+        self.edited_by_us() && !same_value(&self.field, self.ours.as_deref(), self.theirs.as_deref())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IssueConflict {
+    pub issue_id: u64,
+    pub update: UpdateIssue,
+    pub changes: Vec<FieldChange>,
+    /// Notes others added meanwhile (author, text)
+    pub new_notes: Vec<(String, String)>,
+    pub server_updated_on: DateTime<Utc>,
+}
+
+impl IssueConflict {
+    /// Compare the server's copy with the state the update form was opened on. Returns `None`
+    /// when nothing changed, or only notes were added (those never clash with an update).
+    pub fn detect(update: &UpdateIssue, opened_at: DateTime<Utc>, server: &Issue) -> Option<Self> {
+        if server.updated_on <= opened_at {
+            return None;
+        }
+
+        let mut journals: Vec<_> = server.journals.iter().filter(|j| j.created_on > opened_at).collect();
+        journals.sort_by_key(|j| j.created_on);
+
+        let mut changes: Vec<FieldChange> = Vec::new();
+        let mut new_notes = Vec::new();
+        for journal in journals {
+            if let Some(notes) = journal.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+                new_notes.push((journal.user.name.clone(), notes.to_string()));
+            }
+            for detail in journal.details.iter().filter(|d| d.property == "attr") {
+                match changes.iter_mut().find(|c| c.field == detail.name) {
+                    Some(change) => {
+                        change.theirs = detail.new_value.clone();
+                        change.changed_by = journal.user.name.clone();
+                    }
+                    None => changes.push(FieldChange {
+                        field: detail.name.clone(),
+                        before: detail.old_value.clone(),
+                        theirs: detail.new_value.clone(),
+                        ours: update_value(update, &detail.name),
+                        changed_by: journal.user.name.clone(),
+                    }),
+                }
+            }
+        }
+
+        // A field changed and changed back is no change
+        changes.retain(|c| !same_value(&c.field, c.before.as_deref(), c.theirs.as_deref()));
+        if changes.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            issue_id: server.id,
+            update: update.clone(),
+            changes,
+            new_notes,
+            server_updated_on: server.updated_on,
+        })
+    }
+
+    /// Our update without the fields we only resent unchanged, so their edits survive
+    pub fn merged_update(&self) -> UpdateIssue {
+        let mut update = self.update.clone();
+        for change in self.changes.iter().filter(|c| c.ours.is_some() && !c.edited_by_us()) {
+            clear_field(&mut update, &change.field);
+        }
+        update
+    }
+}
+
+/// The value an update sets for a journal attribute, as Redmine writes it in journal details
+fn update_value(update: &UpdateIssue, field: &str) -> Option<String> {
+    match field {
+        "subject" => update.subject.clone(),
+        "description" => update.description.clone(),
+        "status_id" => update.status_id.map(|v| v.to_string()),
+        "priority_id" => update.priority_id.map(|v| v.to_string()),
+        "assigned_to_id" => update.assigned_to_id.map(|v| v.to_string()),
+        "done_ratio" => update.done_ratio.map(|v| v.to_string()),
+        "category_id" => update.category_id.map(|v| v.to_string()),
+        "fixed_version_id" => update.fixed_version_id.map(|v| v.to_string()),
+        "start_date" => update.start_date.clone(),
+        "due_date" => update.due_date.clone(),
+        "estimated_hours" => update.estimated_hours.map(|v| v.to_string()),
+        "parent_id" => update.parent_issue_id.map(|v| v.to_string()),
+        _ => None,
+    }
+}
+
+fn clear_field(update: &mut UpdateIssue, field: &str) {
+    match field {
+        "subject" => update.subject = None,
+        "description" => update.description = None,
+        "status_id" => update.status_id = None,
+        "priority_id" => update.priority_id = None,
+        "assigned_to_id" => update.assigned_to_id = None,
+        "done_ratio" => update.done_ratio = None,
+        "category_id" => update.category_id = None,
+        "fixed_version_id" => update.fixed_version_id = None,
+        "start_date" => update.start_date = None,
+        "due_date" => update.due_date = None,
+        "estimated_hours" => update.estimated_hours = None,
+        "parent_id" => update.parent_issue_id = None,
+        _ => {}
+    }
+}
+
+/// Compare journal values: empty means unset (as does id 0), and numbers compare numerically
+fn same_value(field: &str, a: Option<&str>, b: Option<&str>) -> bool {
+    let normalize = |v: Option<&str>| {
+        v.map(str::trim)
+            .filter(|v| !(v.is_empty() || field.ends_with("_id") && *v == "0"))
+            .map(str::to_string)
+    };
+    match (normalize(a), normalize(b)) {
+        (Some(a), Some(b)) => match (a.parse::<f64>(), b.parse::<f64>()) {
+            (Ok(a), Ok(b)) => (a - b).abs() < f64::EPSILON,
+            _ => a == b,
+        },
+        (a, b) => a == b,
+    }
+}
+
+impl App {
+    /// Check the server's copy before sending an update. Returns true when a conflict was
+    /// found and is waiting for the user to resolve it.
+    pub async fn detect_update_conflict(&mut self, client: &RedmineClient, issue_id: u64, update: &UpdateIssue) -> bool {
+        let Some(opened_at) = self.update_form_opened_at else {
+            return false;
+        };
+
+        // If the issue can't be fetched, sending the update reports (or queues) it
+        let server = match client.get_issue(issue_id).await {
+            Ok(response) => response.issue,
+            Err(e) => {
+                tracing::warn!("Could not check #{} for conflicting changes: {}", issue_id, e);
+                return false;
+            }
+        };
+
+        match IssueConflict::detect(update, opened_at, &server) {
+            Some(conflict) => {
+                if let Err(e) = self.db.insert_issue_with_journals(&server) {
+                    tracing::warn!("Failed to cache issue #{}: {}", issue_id, e);
+                }
+                self.current_issue = Some(server);
+                self.status_message = Some(format!(
+                    "#{} was changed by someone else while you edited it",
+                    issue_id
+                ));
+                self.issue_conflict = Some(conflict);
+                self.input_mode = InputMode::ResolvingConflict;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Send the update waiting on a conflict, merged with or overwriting the server's changes
+    pub async fn resolve_issue_conflict(&mut self, resolution: ConflictResolution) -> anyhow::Result<()> {
+        let Some(conflict) = self.issue_conflict.take() else {
+            return Ok(());
+        };
+        self.update_form_opened_at = None;
+
+        let update = match resolution {
+            ConflictResolution::Merge => conflict.merged_update(),
+            ConflictResolution::Overwrite => conflict.update.clone(),
+        };
+        self.send_issue_update(conflict.issue_id, update).await
+    }
+
+    /// Go back to the update form without sending anything. The form now counts as opened on
+    /// the server's latest version, so saving again sends it as it is.
+    pub fn abort_issue_conflict(&mut self) {
+        if let Some(conflict) = self.issue_conflict.take() {
+            self.update_form_opened_at = Some(conflict.server_updated_on);
+        }
+        self.input_mode = InputMode::ReplyingToIssue;
+        self.status_message = Some("Update not sent. Review the form and press Ctrl+S again".to_string());
+    }
+
+    /// Human readable value of a journal attribute for the conflict diff
+    pub fn conflict_value_label(&self, field: &str, value: Option<&str>) -> String {
+        let Some(value) = value.filter(|v| !v.is_empty()) else {
+            return "(none)".to_string();
+        };
+        let id = value.parse::<u64>().ok();
+        match (field, id) {
+            ("status_id", Some(id)) => self.status_ref(id).name,
+            ("priority_id", Some(id)) => self.priority_ref(id).name,
+            ("tracker_id", Some(id)) => self.tracker_ref(id).name,
+            ("assigned_to_id", Some(0))
+            | ("category_id", Some(0))
+            | ("fixed_version_id", Some(0))
+            | ("parent_id", Some(0)) => "(none)".to_string(),
+            ("assigned_to_id", Some(id)) => self.user_ref(id).name,
+            ("category_id", Some(id)) => self
+                .categories
+                .iter()
+                .find(|c| c.id == id)
+                .map(|c| c.name.clone())
+                .unwrap_or_else(|| format!("#{}", id)),
+            ("fixed_version_id", Some(id)) => self.version_ref(id).name,
+            ("parent_id", Some(id)) => format!("#{}", id),
+            ("done_ratio", _) => format!("{}%", value),
+            _ => {
+                // Long text (descriptions) only shows its first line
+                let first_line = value.lines().next().unwrap_or_default();
+                if first_line.chars().count() > 40 || value.lines().nth(1).is_some() {
+                    format!("{}…", first_line.chars().take(40).collect::<String>())
+                } else {
+                    first_line.to_string()
+                }
+            }
+        }
+    }
+}
+
+/// Label for a journal attribute name
+pub fn field_label(field: &str) -> String {
+    match field {
+        "status_id" => "Status".to_string(),
+        "priority_id" => "Priority".to_string(),
+        "assigned_to_id" => "Assignee".to_string(),
+        "tracker_id" => "Tracker".to_string(),
+        "category_id" => "Category".to_string(),
+        "fixed_version_id" => "Target version".to_string(),
+        "parent_id" => "Parent task".to_string(),
+        "done_ratio" => "% Done".to_string(),
+        "estimated_hours" => "Estimated time".to_string(),
+        "start_date" => "Start date".to_string(),
+        "due_date" => "Due date".to_string(),
+        "subject" => "Subject".to_string(),
+        "description" => "Description".to_string(),
+        other => other.replace('_', " "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::redmine::{IdName, Journal, JournalDetail};
+
+    fn id_name(id: u64, name: &str) -> IdName {
+        IdName {
+            id,
+            name: name.to_string(),
+        }
+    }
+
+    fn journal(id: u64, created_on: DateTime<Utc>, details: &[(&str, &str, &str)]) -> Journal {
+        Journal {
+            id,
+            user: id_name(2, "Teammate"),
+            notes: None,
+            created_on,
+            private_notes: false,
+            details: details
+                .iter()
+                .map(|(name, old, new)| JournalDetail {
+                    property: "attr".to_string(),
+                    name: name.to_string(),
+                    old_value: Some(old.to_string()),
+                    new_value: Some(new.to_string()),
+                })
+                .collect(),
+        }
+    }
+
+    fn server_issue(updated_on: DateTime<Utc>, journals: Vec<Journal>) -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "project": {"id": 1, "name": "Project"},
+            "tracker": {"id": 1, "name": "Bug"},
+            "status": {"id": 2, "name": "In Progress"},
+            "priority": {"id": 2, "name": "Normal"},
+            "author": {"id": 1, "name": "Me"},
+            "assigned_to": null,
+            "subject": "Subject",
+            "description": null,
+            "start_date": null,
+            "due_date": null,
+            "done_ratio": 0,
+            "is_private": false,
+            "estimated_hours": null,
+            "created_on": "2025-01-01T00:00:00Z",
+            "updated_on": updated_on,
+            "closed_on": null,
+            "journals": journals,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_detect_and_merge_conflicts() {
+        let opened_at = "2025-01-02T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let later = opened_at + chrono::Duration::minutes(5);
+
+        // The form resends status 1 unchanged and edits done_ratio, the teammate changed both
+        let update = UpdateIssue {
+            status_id: Some(1),
+            done_ratio: Some(50),
+            notes: Some("My note".to_string()),
+            ..Default::default()
+        };
+        let server = server_issue(
+            later,
+            vec![
+                journal(1, opened_at - chrono::Duration::hours(1), &[("subject", "Old", "Subject")]),
+                journal(2, later, &[("status_id", "1", "2"), ("done_ratio", "0", "30")]),
+            ],
+        );
+
+        let conflict = IssueConflict::detect(&update, opened_at, &server).unwrap();
+        assert_eq!(conflict.changes.len(), 2);
+        let status = &conflict.changes[0];
+        assert_eq!(status.field, "status_id");
+        assert!(!status.edited_by_us());
+        assert!(!status.is_conflict());
+        assert!(conflict.changes[1].is_conflict());
+
+        // Merging keeps their status but still sends our progress and note
+        let merged = conflict.merged_update();
+        assert_eq!(merged.status_id, None);
+        assert_eq!(merged.done_ratio, Some(50));
+        assert_eq!(merged.notes.as_deref(), Some("My note"));
+
+        // Nothing changed since the form opened
+        assert!(IssueConflict::detect(&update, later, &server).is_none());
+    }
+}
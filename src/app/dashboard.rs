@@ -0,0 +1,284 @@
+//! Cross-project "My Work" dashboard.
+//!
+//! Sections are read from the cache with the same typed filters as the issue
+//! list, so they show up instantly and offline. Opening the dashboard also asks
+//! the server for the user's open issues in every project (including projects
+//! that were never synced) and for the issues they watch. Cached issues the
+//! server no longer lists in a section are fetched again by id, so issues closed
+//! or reassigned elsewhere leave the dashboard.
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use super::offline_queue::is_local_id;
+use super::state::{App, IssueSortOrder, Pane, Screen};
+use crate::issue_query::{Comparison, DateField, DateUnit, DateValue, FilterQuery, StatusValue, Term, UserValue};
+use crate::redmine::{Issue, IssueQuery};
+
+/// Most issues fetched from the server per section
+const DASHBOARD_FETCH_LIMIT: usize = 500;
+
+/// Issues re-fetched by id per request when reconciling the sections
+const RECONCILE_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DashboardSection {
+    Assigned,
+    Reported,
+    Watched,
+    Overdue,
+}
+
+impl DashboardSection {
+    pub const ALL: [DashboardSection; 4] = [
+        DashboardSection::Assigned,
+        DashboardSection::Reported,
+        DashboardSection::Watched,
+        DashboardSection::Overdue,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            DashboardSection::Assigned => "Assigned to me",
+            DashboardSection::Reported => "Reported by me",
+            DashboardSection::Watched => "Watched",
+            DashboardSection::Overdue => "Overdue",
+        }
+    }
+
+    /// Open issues belonging in the section
+    pub fn query(self) -> FilterQuery {
+        let mut terms = vec![Term::Status(Comparison::Eq, StatusValue::Open)];
+        match self {
+            DashboardSection::Assigned => terms.push(Term::Assignee(Comparison::Eq, UserValue::Me)),
+            DashboardSection::Reported => terms.push(Term::Author(Comparison::Eq, UserValue::Me)),
+            DashboardSection::Watched => terms.push(Term::Watched(Comparison::Eq)),
+            DashboardSection::Overdue => {
+                terms.push(Term::Assignee(Comparison::Eq, UserValue::Me));
+                terms.push(Term::Date(
+                    DateField::Due,
+                    Comparison::Lt,
+                    DateValue::Relative {
+                        amount: 0,
+                        unit: DateUnit::Days,
+                    },
+                ));
+            }
+        }
+        FilterQuery { terms }
+    }
+}
+
+impl App {
+    /// Switch to the dashboard, showing cached issues and refreshing them from the server
+    pub fn show_dashboard(&mut self) {
+        self.screen = Screen::Dashboard;
+        self.reload_dashboard();
+        self.refresh_dashboard_flag = self.client.is_some();
+    }
+
+    /// Re-read every section from the cache
+    pub fn reload_dashboard(&mut self) {
+        if self.current_user_id.is_none() {
+            // "me" isn't known until the server has been asked once
+            self.dashboard_issues = Default::default();
+            return;
+        }
+
+        let context = self.issue_query_context();
+        for section in DashboardSection::ALL {
+            let i = section.index();
+            match self
+                .db
+                .get_issues(None, self.dashboard_sort_orders[i], &section.query(), &context)
+            {
+                Ok(issues) => self.dashboard_issues[i] = issues,
+                Err(e) => {
+                    self.report_error("Failed to read dashboard issues", e);
+                    return;
+                }
+            }
+            self.dashboard_list_states[i] =
+                self.dashboard_list_states[i].min(self.dashboard_issues[i].len().saturating_sub(1));
+        }
+    }
+
+    /// Fetch the user's open issues across all projects, store them in the cache and reload the sections
+    pub async fn fetch_dashboard(&mut self) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+        self.loading = true;
+        self.status_message = Some("Refreshing dashboard...".to_string());
+
+        let me = || Some("me".to_string());
+        let queries = [
+            IssueQuery {
+                assigned_to: me(),
+                ..Default::default()
+            },
+            IssueQuery {
+                author: me(),
+                ..Default::default()
+            },
+            IssueQuery {
+                watcher: me(),
+                ..Default::default()
+            },
+        ];
+
+        let mut fetched: Vec<Vec<Issue>> = Vec::new();
+        for mut query in queries {
+            query.status = Some("open".to_string());
+            let mut issues: Vec<Issue> = Vec::new();
+            loop {
+                let response = match client.query_issues(&query, 100, issues.len() as u32).await {
+                    Ok(response) => response,
+                    Err(e) if e.is_offline() => {
+                        self.loading = false;
+                        self.status_message = Some("Offline: showing cached issues".to_string());
+                        return Ok(());
+                    }
+                    Err(e) => {
+                        self.loading = false;
+                        return Err(e.into());
+                    }
+                };
+                let count = response.issues.len();
+                issues.extend(response.issues);
+                if count < 100 || issues.len() >= DASHBOARD_FETCH_LIMIT {
+                    break;
+                }
+            }
+            fetched.push(issues);
+        }
+        self.loading = false;
+
+        let watched: Vec<u64> = fetched[2].iter().map(|issue| issue.id).collect();
+        let issues: Vec<Issue> = fetched.into_iter().flatten().collect();
+        let listed: HashSet<u64> = issues.iter().map(|issue| issue.id).collect();
+        self.db.insert_issues(&issues)?;
+        self.db.set_watched_issues(&watched)?;
+        if let Err(e) = self.reconcile_dashboard(&listed).await {
+            tracing::warn!("Failed to refresh issues that left the dashboard: {}", e);
+        }
+        self.reapply_pending_operations();
+
+        self.reload_dashboard();
+        self.status_message = Some("Dashboard updated".to_string());
+        Ok(())
+    }
+
+    /// Fetch again the cached issues a section shows but the server no longer listed for it
+    /// (closed or reassigned since the last sync), and drop the ones the server doesn't return at all
+    async fn reconcile_dashboard(&mut self, listed: &HashSet<u64>) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+        let context = self.issue_query_context();
+        let mut missing: Vec<u64> = Vec::new();
+        for section in [DashboardSection::Assigned, DashboardSection::Reported] {
+            for issue in self
+                .db
+                .get_issues(None, IssueSortOrder::UpdatedDesc, &section.query(), &context)?
+            {
+                // Issues created offline or with queued changes are the queue's to settle
+                if !listed.contains(&issue.id)
+                    && !is_local_id(issue.id)
+                    && !self.pending_issue_ids.contains(&issue.id)
+                    && !missing.contains(&issue.id)
+                {
+                    missing.push(issue.id);
+                }
+            }
+        }
+
+        for batch in missing.chunks(RECONCILE_BATCH_SIZE) {
+            let query = IssueQuery {
+                status: Some("*".to_string()),
+                issue_ids: batch.to_vec(),
+                ..Default::default()
+            };
+            let response = client.query_issues(&query, RECONCILE_BATCH_SIZE as u32, 0).await?;
+            let returned: HashSet<u64> = response.issues.iter().map(|issue| issue.id).collect();
+            self.db.insert_issues(&response.issues)?;
+            // Deleted, or no longer visible to the user
+            for issue_id in batch.iter().filter(|id| !returned.contains(id)) {
+                self.db.delete_issue(*issue_id)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn selected_dashboard_issue(&self) -> Option<&Issue> {
+        let i = self.dashboard_section.index();
+        self.dashboard_issues[i].get(self.dashboard_list_states[i])
+    }
+
+    /// Move to the next (or with a negative step, previous) section
+    pub fn cycle_dashboard_section(&mut self, step: isize) {
+        let count = DashboardSection::ALL.len() as isize;
+        let index = (self.dashboard_section.index() as isize + step).rem_euclid(count) as usize;
+        self.dashboard_section = DashboardSection::ALL[index];
+    }
+
+    /// Move the cursor in the focused section, clamped to its issues
+    pub fn move_dashboard_cursor(&mut self, delta: isize) {
+        let i = self.dashboard_section.index();
+        let last = self.dashboard_issues[i].len().saturating_sub(1) as isize;
+        self.dashboard_list_states[i] = (self.dashboard_list_states[i] as isize + delta).clamp(0, last) as usize;
+    }
+
+    pub fn cycle_dashboard_sort(&mut self) {
+        let i = self.dashboard_section.index();
+        self.dashboard_sort_orders[i] = self.dashboard_sort_orders[i].next();
+        self.dashboard_list_states[i] = 0;
+        self.reload_dashboard();
+        self.status_message = Some(format!(
+            "{} sorted by: {}",
+            self.dashboard_section.title(),
+            self.dashboard_sort_orders[i].as_str()
+        ));
+    }
+
+    /// Leave the dashboard for the selected issue's project, with the cursor on the issue
+    pub fn jump_to_dashboard_issue_project(&mut self) {
+        let Some(issue) = self.selected_dashboard_issue().cloned() else {
+            return;
+        };
+        let project = match self.db.get_projects(None) {
+            Ok(projects) => projects.into_iter().find(|p| p.id == issue.project.id),
+            Err(e) => {
+                self.report_error("Failed to read projects", e);
+                return;
+            }
+        };
+        let Some(project) = project else {
+            self.error_message = Some(format!(
+                "Project '{}' is not in the cache (press P to sync projects)",
+                issue.project.name
+            ));
+            return;
+        };
+
+        self.active_saved_query = None;
+        self.selected_project = Some(project);
+        self.screen = Screen::Main;
+        self.focused_pane = Pane::Issues;
+        self.apply_filters();
+        self.issues_list_state = if self.group_issues_by_status {
+            0
+        } else {
+            self.filtered_issues.iter().position(|i| i.id == issue.id).unwrap_or(0)
+        };
+    }
+
+    pub fn dashboard_sort_order(&self, section: DashboardSection) -> IssueSortOrder {
+        self.dashboard_sort_orders[section.index()]
+    }
+}
@@ -1,12 +1,21 @@
 use anyhow::Result;
+use chrono::{Duration, Utc};
 use std::collections::HashMap;
 
-use super::state::App;
-use crate::issue_form::IssueForm;
-use crate::redmine::User;
+use super::handlers::FormTarget;
+use super::offline_queue::{is_local_id, QueuedWrite};
+use super::state::{App, CURRENT_USER_KEY};
+use crate::redmine::{UpdateIssue, User};
+
+/// How far before the last sync an incremental issue sync starts
+const ISSUES_SYNC_OVERLAP_MINUTES: i64 = 5;
+
+/// A delta sync can't see issues deleted or moved to another project, so a project's issues
+/// are downloaded in full again once its last full sync is this old
+const FULL_SYNC_INTERVAL_HOURS: i64 = 24;
 
 impl App {
-    /// Load global metadata (trackers, statuses, priorities, current user)
+    /// Load global metadata (trackers, statuses, priorities, time entry activities, current user)
     pub async fn load_metadata(&mut self) -> Result<()> {
         if let Some(client) = &self.client {
             self.status_message = Some("Loading metadata...".to_string());
@@ -26,9 +35,17 @@ impl App {
                 self.priorities = response.issue_priorities;
             }
 
+            // Load time entry activities
+            if let Ok(response) = client.get_time_entry_activities().await {
+                self.time_entry_activities = response.time_entry_activities;
+            }
+
             // Load current user
             if let Ok(response) = client.get_current_user().await {
                 self.current_user_id = Some(response.user.id);
+                if let Err(e) = self.db.set_metadata(CURRENT_USER_KEY, &response.user.id.to_string()) {
+                    tracing::warn!("Failed to store current user: {}", e);
+                }
             }
             
             self.status_message = Some("Metadata loaded successfully".to_string());
@@ -36,10 +53,66 @@ impl App {
         Ok(())
     }
 
+    /// Fetch metadata on startup (or after switching profile), plus projects and users when the cache is empty
+    pub async fn load_initial_data(&mut self) {
+        self.refresh_saved_queries();
+        if self.client.is_none() {
+            return;
+        }
+
+        if let Err(e) = self.load_metadata().await {
+            self.report_error("Failed to load metadata", e);
+        }
+        // Only fetch from API if we have no cached data
+        if self.total_projects == 0 {
+            if let Err(e) = self.load_projects().await {
+                self.report_error("Failed to load projects", e);
+            }
+        }
+
+        // If users cache is empty, load them from API
+        if self.users.is_empty() {
+            if let Err(e) = self.load_all_users().await {
+                tracing::warn!("Failed to load users from API: {}", e);
+            }
+        }
+
+        // Saved queries are listed under the projects, so fetch them once up front
+        if self.saved_queries.is_empty() {
+            if let Err(e) = self.fetch_saved_queries().await {
+                tracing::warn!("Failed to load saved queries from API: {}", e);
+            }
+        }
+
+        // Clear status message after startup
+        self.status_message = None;
+    }
+
     /// Load issues for the currently selected project (incrementally)
     pub async fn load_issues(&mut self) -> Result<()> {
         // Initialize loading state
         if !self.issues_loading_in_progress {
+            // After the first sync only ask for issues changed since the last one, until a
+            // full sync is due to drop the issues removed from the project
+            let last_sync = match &self.selected_project {
+                Some(project) if !self.issues_full_sync => {
+                    let full_sync_due = self
+                        .db
+                        .get_project_full_sync(project.id)?
+                        .is_none_or(|at| Utc::now() - at >= Duration::hours(FULL_SYNC_INTERVAL_HOURS));
+                    if full_sync_due {
+                        None
+                    } else {
+                        self.db.get_project_issues_sync(project.id)?
+                    }
+                }
+                _ => None,
+            };
+            self.issues_full_sync = false;
+            self.issues_sync_started = Some(Utc::now());
+            // Overlap a little so a clock difference with the server can't skip updates
+            self.issues_sync_since = last_sync.map(|t| t - Duration::minutes(ISSUES_SYNC_OVERLAP_MINUTES));
+
             self.issues_loading_in_progress = true;
             self.issues_loaded_count = 0;
             self.issues_total_count = 0;
@@ -59,8 +132,12 @@ impl App {
                 let limit = 100;
                 let offset = self.issues_loaded_count as u32;
                 let exclude_subprojects = self.config.exclude_subprojects;
+                let since = self.issues_sync_since;
 
-                match client.get_issues(Some(project_id), Some("*"), limit, offset, exclude_subprojects).await {
+                match client
+                    .get_issues(Some(project_id), Some("*"), limit, offset, exclude_subprojects, since)
+                    .await
+                {
                     Ok(response) => {
                         let total_count = response.total_count.unwrap_or(0);
                         let received_count = response.issues.len();
@@ -70,8 +147,10 @@ impl App {
                         self.issues_total_count = total_count as usize;
                         
                         // Update progress message
+                        let verb = if since.is_some() { "Syncing updated issues" } else { "Loading issues" };
                         self.status_message = Some(format!(
-                            "Loading issues... {}/{}", 
+                            "{}... {}/{}",
+                            verb,
                             self.issues_loaded_count, 
                             self.issues_total_count
                         ));
@@ -79,12 +158,42 @@ impl App {
                         // Check if we've loaded all issues
                         if received_count < limit as usize || self.issues_loaded_count >= self.issues_total_count {
                             // All pages loaded - finalize
-                            if let Err(e) = self.db.insert_issues(&self.issues_temp_buffer) {
-                                self.error_message = Some(format!("Failed to store issues: {}", e));
+                            let mut removed = 0;
+                            match self.db.insert_issues(&self.issues_temp_buffer) {
+                                Ok(()) => {
+                                    // Put queued offline changes back on top of the server's copies
+                                    self.reapply_pending_operations();
+                                    // A full sync saw every issue, so anything else cached is gone
+                                    if since.is_none() {
+                                        removed = self.remove_stale_issues(project_id, exclude_subprojects);
+                                    }
+                                    let synced_at = self.issues_sync_started.take().unwrap_or_else(Utc::now);
+                                    if let Err(e) = self.db.set_project_issues_sync(project_id, synced_at) {
+                                        tracing::warn!("Failed to record issues sync time: {}", e);
+                                    }
+                                    if since.is_none() {
+                                        if let Err(e) = self.db.set_project_full_sync(project_id, synced_at) {
+                                            tracing::warn!("Failed to record full sync time: {}", e);
+                                        }
+                                    }
+                                    if let Some(project) = self.selected_project.as_mut() {
+                                        project.last_issues_sync = Some(synced_at);
+                                    }
+                                }
+                                Err(e) => self.error_message = Some(format!("Failed to store issues: {}", e)),
                             }
 
                             self.apply_filters();
-                            self.status_message = Some(format!("Loaded {} issues from {}", self.issues_loaded_count, project_name));
+                            self.status_message = Some(if since.is_some() {
+                                format!("Synced {} updated issues from {}", self.issues_loaded_count, project_name)
+                            } else if removed > 0 {
+                                format!(
+                                    "Loaded {} issues from {} ({} no longer on the server removed)",
+                                    self.issues_loaded_count, project_name, removed
+                                )
+                            } else {
+                                format!("Loaded {} issues from {}", self.issues_loaded_count, project_name)
+                            });
                             
                             self.issues_loading_in_progress = false;
                             self.issues_temp_buffer.clear();
@@ -95,7 +204,7 @@ impl App {
                         return Ok(false); // More pages to load
                     }
                     Err(e) => {
-                        self.error_message = Some(format!("Failed to load issues: {}", e));
+                        self.report_error("Failed to load issues", e);
                         self.issues_loading_in_progress = false;
                         self.issues_temp_buffer.clear();
                         self.loading = false;
@@ -107,8 +216,31 @@ impl App {
         Ok(true) // No client or project, stop
     }
 
+    /// Drop cached issues the last full sync of a project didn't return, returning how many were removed
+    fn remove_stale_issues(&mut self, project_id: u64, exclude_subprojects: bool) -> usize {
+        // Issues created offline aren't on the server yet
+        let synced_ids: Vec<u64> = self
+            .issues_temp_buffer
+            .iter()
+            .map(|i| i.id)
+            .chain(self.pending_issue_ids.iter().copied())
+            .collect();
+        match self.db.remove_stale_issues(project_id, !exclude_subprojects, &synced_ids) {
+            Ok(0) => 0,
+            Ok(removed) => {
+                tracing::info!("Removed {} stale issues from the cache of project {}", removed, project_id);
+                self.selected_issues.retain(|id| synced_ids.contains(id));
+                removed
+            }
+            Err(e) => {
+                tracing::warn!("Failed to remove stale issues: {}", e);
+                0
+            }
+        }
+    }
+
     pub async fn load_projects(&mut self) -> Result<()> {
-        if let Some(client) = &self.client {
+        if let Some(client) = self.client.clone() {
             self.loading = true;
             self.status_message = Some("Loading projects...".to_string());
             let mut all_projects = Vec::new();
@@ -138,7 +270,7 @@ impl App {
                         offset += limit;
                     }
                     Err(e) => {
-                        self.error_message = Some(format!("Failed to load projects: {}", e));
+                        self.report_error("Failed to load projects", e);
                         had_error = true;
                         self.loading = false;
                         break;
@@ -260,7 +392,7 @@ impl App {
     }
 
     pub async fn load_project_metadata(&mut self, project_id: u64) -> Result<()> {
-        if let Some(client) = &self.client {
+        if let Some(client) = self.client.clone() {
             if let Ok(response) = client.get_project_detail(project_id).await {
                 self.categories = response.project.issue_categories;
                 // Use project-specific trackers instead of global ones
@@ -268,6 +400,14 @@ impl App {
                     self.trackers = response.project.trackers;
                 }
             }
+            if let Err(e) = self.fetch_project_versions(project_id).await {
+                tracing::warn!("Failed to load versions of project {}: {}", project_id, e);
+            }
+
+            // Offer the fresh categories and versions in an open create form
+            if self.show_create_issue_form && self.create_issue_project_id == Some(project_id) {
+                self.rebuild_create_issue_form_preserving_values();
+            }
         }
         Ok(())
     }
@@ -303,13 +443,7 @@ impl App {
 
                 // Rebuild create form if it exists (to update user list)
                 if self.show_create_issue_form {
-                    self.create_issue_form = Some(IssueForm::new_issue_form(
-                        &self.trackers,
-                        &self.statuses,
-                        &self.priorities,
-                        &self.users,
-                        &self.categories,
-                    ));
+                    self.rebuild_create_issue_form_preserving_values();
                 }
                 
                 self.status_message = Some(format!("Project data loaded ({} users)", self.users.len()));
@@ -319,6 +453,12 @@ impl App {
     }
 
     pub async fn load_issue_detail(&mut self, issue_id: u64) -> Result<()> {
+        // Issues created offline only exist in the cache until their creation is replayed
+        if is_local_id(issue_id) {
+            self.show_cached_issue(issue_id);
+            return Ok(());
+        }
+
         if let Some(client) = &self.client {
             self.loading_issue = true;
             self.status_message = Some(format!("Loading issue #{}...", issue_id));
@@ -330,12 +470,22 @@ impl App {
                     if let Err(e) = self.db.insert_issue_with_journals(&issue) {
                         self.error_message = Some(format!("Failed to store issue: {}", e));
                     }
+                    self.sync_watched_issue(&issue);
 
-                    self.current_issue = Some(issue.clone());
                     self.status_message = Some(format!("Loaded issue #{}: {}", issue.id, issue.subject));
+                    if self.pending_issue_ids.contains(&issue.id) {
+                        self.reapply_pending_operations();
+                        self.show_cached_issue(issue.id);
+                    } else {
+                        self.current_issue = Some(issue);
+                    }
+                }
+                Err(e) if e.is_offline() => {
+                    self.show_cached_issue(issue_id);
+                    self.status_message = Some(format!("Offline: showing cached copy of #{}", issue_id));
                 }
                 Err(e) => {
-                    self.error_message = Some(format!("Failed to load issue: {}", e));
+                    self.report_error("Failed to load issue", e);
                 }
             }
             self.loading_issue = false;
@@ -343,6 +493,15 @@ impl App {
         Ok(())
     }
 
+    /// Show the cached copy of an issue in the popup
+    fn show_cached_issue(&mut self, issue_id: u64) {
+        match self.db.get_issue_with_journals(issue_id) {
+            Ok(Some(issue)) => self.current_issue = Some(issue),
+            Ok(None) => self.error_message = Some(format!("Issue #{} is not in the cache", issue_id)),
+            Err(e) => self.report_error("Failed to load cached issue", e),
+        }
+    }
+
     pub async fn add_comment_to_issue(&mut self, issue_id: u64) -> Result<()> {
         let client = match &self.client {
             Some(c) => c.clone(),
@@ -375,16 +534,35 @@ impl App {
 
             let private_notes = form.get_value("private_notes").map(|v| v.as_bool());
 
+            // Only sent when changed, 0 removes the target version
+            let current_version_id = self
+                .current_issue
+                .as_ref()
+                .filter(|issue| issue.id == issue_id)
+                .map(|issue| issue.fixed_version.as_ref().map_or(0, |v| v.id));
+            let fixed_version_id = form
+                .get_value("fixed_version_id")
+                .and_then(|v| v.as_option_id())
+                .filter(|id| Some(*id) != current_version_id);
+
+            // Only sent when changed, 0 removes the parent
+            let current_parent_id = self
+                .current_issue
+                .as_ref()
+                .filter(|issue| issue.id == issue_id)
+                .map(|issue| issue.parent.as_ref().map_or(0, |p| p.id));
+            let parent_issue_id = form
+                .get_value("parent_issue_id")
+                .and_then(|v| v.as_option_id())
+                .filter(|id| Some(*id) != current_parent_id);
+
             // Upload any pending attachments
             let uploads = if !self.pending_attachments.is_empty() {
                 self.status_message = Some("Uploading attachments...".to_string());
                 match self.upload_files(&self.pending_attachments.clone()).await {
-                    Ok(uploads) => {
-                        self.pending_attachments.clear();
-                        Some(uploads)
-                    }
+                    Ok(uploads) => Some(uploads),
                     Err(e) => {
-                        self.error_message = Some(format!("Failed to upload attachments: {}", e));
+                        self.report_error("Failed to upload attachments", e);
                         return Ok(());
                     }
                 }
@@ -392,7 +570,7 @@ impl App {
                 None
             };
 
-            let update = crate::redmine::UpdateIssue {
+            let update = UpdateIssue {
                 subject: None,
                 description: None,
                 status_id,
@@ -403,42 +581,75 @@ impl App {
                 start_date: None,
                 due_date,
                 estimated_hours,
+                fixed_version_id,
+                parent_issue_id,
                 notes,
                 private_notes,
                 uploads,
             };
 
-            match client.update_issue_with_comment(issue_id, update).await {
-                Ok(()) => {
-                    self.status_message = Some("Issue updated successfully".to_string());
-                    // Reload the issue detail to show the changes
-                    self.load_issue_detail(issue_id).await?;
-
-                    // Refresh the issues list to reflect the new state
-                    let project_id = self.selected_project.as_ref().map(|p| p.id);
-                    if let Some(pid) = project_id {
-                        let exclude_subprojects = self.config.exclude_subprojects;
-                        if let Ok(response) = client.get_issues(Some(pid), Some("*"), 100, 0, exclude_subprojects).await {
-                            // Update the issue in database
-                            if let Err(e) = self.db.insert_issues(&response.issues) {
-                                self.error_message = Some(format!("Failed to update issues cache: {}", e));
-                            }
-                            // Refresh the filtered view
-                            self.apply_filters();
-                        }
-                    }
-                }
-                Err(e) => {
-                    self.error_message = Some(format!("Failed to update issue: {}", e));
+            // Later changes wait behind queued ones so the server sees them in order
+            if self.has_pending_operations() {
+                self.pending_attachments.clear();
+                self.queue_write(Some(issue_id), QueuedWrite::UpdateIssue(update));
+                self.show_cached_issue(issue_id);
+                return Ok(());
+            }
+
+            // Someone else may have changed the issue while the form was open
+            if self.detect_update_conflict(&client, issue_id, &update).await {
+                return Ok(());
+            }
+
+            self.send_issue_update(issue_id, update).await?;
+        }
+        Ok(())
+    }
+
+    /// Send an issue update, queueing it if the server can't be reached
+    pub async fn send_issue_update(&mut self, issue_id: u64, update: UpdateIssue) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+
+        match client.update_issue_with_comment(issue_id, update.clone()).await {
+            Ok(()) => {
+                self.pending_attachments.clear();
+                self.status_message = Some("Issue updated successfully".to_string());
+                // Reload the issue detail to show the changes
+                self.load_issue_detail(issue_id).await?;
+
+                // Refresh the issues list to reflect the new state (a delta sync picks up the change)
+                if self.selected_project.is_some() {
+                    self.refresh_issues = true;
                 }
             }
+            Err(e) if e.may_have_been_applied() => {
+                self.pending_attachments.clear();
+                self.queue_timed_out_write(Some(issue_id), QueuedWrite::UpdateIssue(update));
+                self.show_cached_issue(issue_id);
+            }
+            Err(e) if e.is_offline() => {
+                self.pending_attachments.clear();
+                self.queue_write(Some(issue_id), QueuedWrite::UpdateIssue(update));
+                self.show_cached_issue(issue_id);
+            }
+            Err(e) if !e.validation_errors().is_empty() => {
+                self.show_form_errors(FormTarget::Update, e.validation_errors());
+            }
+            Err(e) => {
+                self.report_error("Failed to update issue", e);
+            }
         }
         Ok(())
     }
 
     pub async fn create_new_issue(&mut self) -> Result<()> {
+        let project_id = self
+            .create_issue_project_id
+            .or_else(|| self.selected_project.as_ref().map(|p| p.id));
         if let Some(client) = &self.client {
-            if let Some(project) = &self.selected_project {
+            if let Some(project_id) = project_id {
                 if let Some(form) = &self.create_issue_form {
                     // Extract values from form
                     let subject = form
@@ -488,16 +699,28 @@ impl App {
 
                     let done_ratio = form.get_value("done_ratio").and_then(|v| v.as_number());
 
+                    let fixed_version_id = form
+                        .get_value("fixed_version_id")
+                        .and_then(|v| v.as_option_id())
+                        .filter(|id| *id != 0);
+
+                    let parent_issue_id = form
+                        .get_value("parent_issue_id")
+                        .and_then(|v| v.as_option_id())
+                        .filter(|id| *id != 0);
+
+                    let watcher_user_ids = form
+                        .get_value("watcher_user_ids")
+                        .map(|v| v.as_option_ids().to_vec())
+                        .filter(|ids| !ids.is_empty());
+
                     // Upload any pending attachments
                     let uploads = if !self.pending_attachments.is_empty() {
                         self.status_message = Some("Uploading attachments...".to_string());
                         match self.upload_files(&self.pending_attachments.clone()).await {
-                            Ok(uploads) => {
-                                self.pending_attachments.clear();
-                                Some(uploads)
-                            }
+                            Ok(uploads) => Some(uploads),
                             Err(e) => {
-                                self.error_message = Some(format!("Failed to upload attachments: {}", e));
+                                self.report_error("Failed to upload attachments", e);
                                 return Ok(());
                             }
                         }
@@ -506,7 +729,7 @@ impl App {
                     };
 
                     let new_issue = crate::redmine::CreateIssue {
-                        project_id: project.id,
+                        project_id,
                         tracker_id,
                         status_id,
                         priority_id,
@@ -518,11 +741,21 @@ impl App {
                         due_date,
                         estimated_hours,
                         done_ratio,
+                        fixed_version_id,
+                        parent_issue_id,
+                        watcher_user_ids,
                         uploads,
                     };
 
-                    match client.create_issue(new_issue).await {
+                    if self.has_pending_operations() {
+                        self.pending_attachments.clear();
+                        self.queue_write(None, QueuedWrite::CreateIssue(new_issue));
+                        return Ok(());
+                    }
+
+                    match client.create_issue(new_issue.clone()).await {
                         Ok(response) => {
+                            self.pending_attachments.clear();
                             // Store in database
                             if let Err(e) = self.db.insert_issue_with_journals(&response.issue) {
                                 self.error_message = Some(format!("Failed to store issue: {}", e));
@@ -530,9 +763,26 @@ impl App {
                             self.status_message = Some(format!("Created issue #{}", response.issue.id));
                             // Refresh the list
                             self.apply_filters();
+                            // Show a new subtask under its parent in the popup
+                            if let Some(parent_id) = parent_issue_id.filter(|id| {
+                                self.show_issue_popup && self.current_issue.as_ref().is_some_and(|i| i.id == *id)
+                            }) {
+                                self.load_issue_detail(parent_id).await?;
+                            }
+                        }
+                        Err(e) if e.may_have_been_applied() => {
+                            self.pending_attachments.clear();
+                            self.queue_timed_out_write(None, QueuedWrite::CreateIssue(new_issue));
+                        }
+                        Err(e) if e.is_offline() => {
+                            self.pending_attachments.clear();
+                            self.queue_write(None, QueuedWrite::CreateIssue(new_issue));
+                        }
+                        Err(e) if !e.validation_errors().is_empty() => {
+                            self.show_form_errors(FormTarget::Create, e.validation_errors());
                         }
                         Err(e) => {
-                            self.error_message = Some(format!("Failed to create issue: {}", e));
+                            self.report_error("Failed to create issue", e);
                         }
                     }
                 }
@@ -581,3 +831,83 @@ impl App {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::db::Database;
+    use crate::redmine::{Issue, Project, RedmineClient};
+    use mockito::Matcher;
+    use ratatui_image::picker::Picker;
+    use tempfile::TempDir;
+
+    fn issue_json(id: u64) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "project": {"id": 1, "name": "Project"},
+            "tracker": {"id": 1, "name": "Bug"},
+            "status": {"id": 1, "name": "New"},
+            "priority": {"id": 2, "name": "Normal"},
+            "author": {"id": 1, "name": "Me"},
+            "subject": format!("Issue {}", id),
+            "created_on": "2025-01-01T10:00:00Z",
+            "updated_on": "2025-01-01T10:00:00Z",
+        })
+    }
+
+    /// Refresh the selected project's issues the way the main loop does
+    async fn refresh_issues(app: &mut App) {
+        app.load_issues().await.unwrap();
+        while !app.load_issues_next_page().await.unwrap() {}
+    }
+
+    #[tokio::test]
+    async fn test_refresh_drops_issues_removed_from_the_server() {
+        let temp = TempDir::new().unwrap();
+        let db = Database::new(temp.path().join("cache.db")).unwrap();
+        let project: Project = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": "Project",
+            "identifier": "project",
+            "status": 1,
+        }))
+        .unwrap();
+        db.insert_projects(std::slice::from_ref(&project)).unwrap();
+        let issues: Vec<Issue> = [1, 2].iter().map(|id| serde_json::from_value(issue_json(*id)).unwrap()).collect();
+        db.insert_issues(&issues).unwrap();
+        // Synced an hour ago, but the last full sync is older than the interval
+        db.set_project_issues_sync(1, Utc::now() - Duration::hours(1)).unwrap();
+        db.set_project_full_sync(1, Utc::now() - Duration::hours(FULL_SYNC_INTERVAL_HOURS + 1))
+            .unwrap();
+
+        let mut server = mockito::Server::new_async().await;
+        let mut app = App::with_database(Config::default(), db, Picker::from_fontsize((8, 16)));
+        app.client = Some(RedmineClient::new(server.url(), "key".to_string()));
+        app.selected_project = Some(project);
+
+        // #2 was deleted on the server: the refresh downloads everything and drops it
+        let full = server
+            .mock("GET", "/issues.json")
+            .match_query(Matcher::Any)
+            .with_body(serde_json::json!({"issues": [issue_json(1)], "total_count": 1}).to_string())
+            .create_async()
+            .await;
+        refresh_issues(&mut app).await;
+        full.assert_async().await;
+        assert!(app.db.get_issue(1).unwrap().is_some());
+        assert!(app.db.get_issue(2).unwrap().is_none());
+        full.remove_async().await;
+
+        // With a recent full sync the next refresh only asks for updated issues
+        let delta = server
+            .mock("GET", "/issues.json")
+            .match_query(Matcher::Regex("updated_on=".to_string()))
+            .with_body(serde_json::json!({"issues": [], "total_count": 0}).to_string())
+            .create_async()
+            .await;
+        refresh_issues(&mut app).await;
+        delta.assert_async().await;
+        assert!(app.db.get_issue(1).unwrap().is_some());
+    }
+}
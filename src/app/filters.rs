@@ -1,9 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use super::state::{App, IssueSortOrder};
+use super::state::{App, InputMode, IssueSortOrder};
 use crate::form_field::FieldValue;
-use crate::issue_form::IssueForm;
-use crate::redmine::{Issue, Project};
+use crate::issue_form::{IssueForm, ProjectChoices};
+use crate::issue_query::{Comparison, FilterQuery, QueryContext, QueryError, Term, UserValue};
+use crate::redmine::{Issue, Project, Version};
 
 impl App {
     pub fn apply_filters(&mut self) {
@@ -29,36 +30,43 @@ impl App {
         if self.projects_list_state >= self.filtered_projects.len() {
             self.projects_list_state = self.filtered_projects.len().saturating_sub(1);
         }
+        // The saved queries listed depend on the selected project
+        self.saved_queries_list_state = self
+            .saved_queries_list_state
+            .min(self.visible_saved_queries().len().saturating_sub(1));
 
         // Query issues from database with sorting and filtering built-in
-        let project_id = self.selected_project.as_ref().map(|p| p.id);
-        let issue_filter = if self.issue_filter.is_empty() {
-            None
-        } else {
-            Some(self.issue_filter.as_str())
+        let context = self.issue_query_context();
+        // A saved query's results replace the project scope
+        let (project_id, scope) = match &self.active_saved_query {
+            Some(query) => (None, FilterQuery { terms: vec![Term::SavedQuery(query.id)] }),
+            None => (self.selected_project.as_ref().map(|p| p.id), FilterQuery::default()),
         };
+        self.search_error = None;
+        let mut issue_query = FilterQuery::parse(&self.issue_filter).unwrap_or_else(|e| {
+            self.search_error = Some(e);
+            FilterQuery::default()
+        });
+        issue_query.terms.extend(scope.terms.iter().cloned());
 
         // Apply "my issues" filter if enabled
-        let assigned_to_filter = if self.my_issues_filter {
-            self.current_user_id
-        } else {
-            None
-        };
+        if self.my_issues_filter && self.current_user_id.is_some() {
+            issue_query.terms.push(Term::Assignee(Comparison::Eq, UserValue::Me));
+        }
 
         // Get total count first (no filter)
-        self.total_issues = self
-            .db
-            .get_issues(project_id, self.issue_sort_order, None, None)
-            .map(|i| i.len())
-            .unwrap_or(0);
-
-        self.filtered_issues = self
-            .db
-            .get_issues(project_id, self.issue_sort_order, issue_filter, assigned_to_filter)
-            .unwrap_or_else(|e| {
-                self.error_message = Some(format!("Failed to query issues: {}", e));
+        self.total_issues = self.db.count_issues(project_id, &scope, &context).unwrap_or(0);
+
+        self.filtered_issues = match self.db.get_issues(project_id, self.issue_sort_order, &issue_query, &context) {
+            Ok(issues) => issues,
+            Err(e) => {
+                match e.downcast::<QueryError>() {
+                    Ok(e) => self.search_error = Some(e),
+                    Err(e) => self.error_message = Some(format!("Failed to query issues: {}", e)),
+                }
                 Vec::new()
-            });
+            }
+        };
 
         // Apply custom status ordering when sorting by status
         if matches!(
@@ -87,12 +95,145 @@ impl App {
             });
         }
 
+        if self.issue_tree_view && !self.group_issues_by_status {
+            self.arrange_issue_tree();
+        } else {
+            self.issue_depths.clear();
+        }
+
         // Reset selection if out of bounds
         if self.issues_list_state >= self.filtered_issues.len() {
             self.issues_list_state = self.filtered_issues.len().saturating_sub(1);
         }
     }
 
+    /// Reorder the listed issues so subtasks follow their parent, recording each issue's depth.
+    /// Issues whose parent isn't listed are roots; siblings keep the current sort order.
+    fn arrange_issue_tree(&mut self) {
+        let listed: HashSet<u64> = self.filtered_issues.iter().map(|issue| issue.id).collect();
+        let mut roots = Vec::new();
+        let mut children: HashMap<u64, Vec<Issue>> = HashMap::new();
+        for issue in std::mem::take(&mut self.filtered_issues) {
+            match issue.parent.as_ref().map(|p| p.id).filter(|id| listed.contains(id)) {
+                Some(parent_id) => children.entry(parent_id).or_default().push(issue),
+                None => roots.push(issue),
+            }
+        }
+
+        fn add_issue_tree(
+            issue: Issue,
+            depth: usize,
+            children: &mut HashMap<u64, Vec<Issue>>,
+            list: &mut Vec<Issue>,
+            depths: &mut HashMap<u64, usize>,
+        ) {
+            let id = issue.id;
+            depths.insert(id, depth);
+            list.push(issue);
+            for child in children.remove(&id).unwrap_or_default() {
+                add_issue_tree(child, depth + 1, children, list, depths);
+            }
+        }
+
+        self.issue_depths.clear();
+        for root in roots {
+            add_issue_tree(root, 0, &mut children, &mut self.filtered_issues, &mut self.issue_depths);
+        }
+        // Parent ids forming a cycle leave issues unreached; list them flat rather than lose them
+        for issue in children.into_values().flatten() {
+            self.issue_depths.insert(issue.id, 0);
+            self.filtered_issues.push(issue);
+        }
+    }
+
+    /// What compiling the issue filter needs from the app's state
+    pub fn issue_query_context(&self) -> QueryContext {
+        QueryContext {
+            current_user_id: self.current_user_id,
+            closed_status_ids: self.statuses.iter().filter(|s| s.is_closed).map(|s| s.id).collect(),
+            priorities: self.priorities.iter().map(|p| (p.id, p.name.clone())).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Open the new issue form for a project
+    pub fn open_create_issue_form(&mut self, project_id: u64) {
+        // Check if metadata is loaded
+        if self.trackers.is_empty() || self.statuses.is_empty() || self.priorities.is_empty() {
+            self.error_message = Some(format!(
+                "Metadata not loaded yet. Trackers: {}, Statuses: {}, Priorities: {}",
+                self.trackers.len(),
+                self.statuses.len(),
+                self.priorities.len()
+            ));
+            return;
+        }
+
+        // Trigger loading users for the project
+        self.load_users_flag = true;
+        self.load_users_project_id = Some(project_id);
+
+        // Get custom fields for default tracker from cache
+        let default_tracker_id = self
+            .trackers
+            .iter()
+            .find(|t| t.name.to_lowercase() == "task")
+            .or_else(|| self.trackers.first())
+            .map(|t| t.id);
+
+        let custom_fields = if let Some(tracker_id) = default_tracker_id {
+            self.tracker_custom_fields_cache
+                .get(&tracker_id)
+                .cloned()
+                .unwrap_or_else(Vec::new)
+        } else {
+            Vec::new()
+        };
+
+        // Create data-driven form with cached custom fields
+        let parent_issues = self.parent_issue_candidates(project_id);
+        let versions = self.project_versions(project_id);
+        self.create_issue_form = Some(IssueForm::new_issue_form_with_custom_fields(
+            &self.trackers,
+            &self.statuses,
+            &self.priorities,
+            &self.users,
+            ProjectChoices {
+                categories: &self.categories,
+                versions: &versions,
+                parent_issues: &parent_issues,
+            },
+            &custom_fields,
+        ));
+        self.create_issue_project_id = Some(project_id);
+
+        self.show_create_issue_form = true;
+        self.input_mode = InputMode::CreatingIssue;
+    }
+
+    /// Cached issues of a project, offered as parents in the issue forms
+    pub fn parent_issue_candidates(&self, project_id: u64) -> Vec<Issue> {
+        self.db
+            .get_issues(
+                Some(project_id),
+                IssueSortOrder::UpdatedDesc,
+                &FilterQuery::default(),
+                &self.issue_query_context(),
+            )
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to read parent issue candidates: {}", e);
+                Vec::new()
+            })
+    }
+
+    /// Cached versions of a project, offered as target versions in the issue forms
+    pub fn project_versions(&self, project_id: u64) -> Vec<Version> {
+        self.db.get_project_versions(project_id).unwrap_or_else(|e| {
+            tracing::warn!("Failed to read project versions: {}", e);
+            Vec::new()
+        })
+    }
+
     /// Rebuild the create issue form while preserving user-entered values
     /// This is called when the tracker changes to potentially show different fields
     pub fn rebuild_create_issue_form_preserving_values(&mut self) {
@@ -133,13 +274,26 @@ impl App {
             Vec::new()
         };
 
+        let parent_issues = self
+            .create_issue_project_id
+            .map(|project_id| self.parent_issue_candidates(project_id))
+            .unwrap_or_default();
+        let versions = self
+            .create_issue_project_id
+            .map(|project_id| self.project_versions(project_id))
+            .unwrap_or_default();
+
         // Create new form with cached custom fields (instant!)
         let mut new_form = IssueForm::new_issue_form_with_custom_fields(
             &self.trackers,
             &self.statuses,
             &self.priorities,
             &self.users,
-            &self.categories,
+            ProjectChoices {
+                categories: &self.categories,
+                versions: &versions,
+                parent_issues: &parent_issues,
+            },
             &custom_fields,
         );
 
@@ -1,8 +1,9 @@
-//! Form field input handlers for issue creation and update forms.
+//! Form field input handlers for issue creation, update, time entry and relation forms.
 //!
 //! This module handles keyboard input for various form field types including:
 //! - Text and textarea fields
 //! - Dropdown menus (simple and searchable)
+//! - Multi-selects
 //! - Progress bars
 //! - Checkboxes
 //!
@@ -10,22 +11,56 @@
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::app::App;
+use crate::app::{App, InputMode};
 use crate::form_field::{FieldType, FieldValue};
+use crate::issue_form::IssueForm;
+
+/// Which open form a key event is routed to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum FormTarget {
+    Create,
+    Update,
+    TimeEntry,
+    Relation,
+}
 
 impl App {
-    /// Route form field input to the appropriate handler
-    pub(crate) fn handle_form_input(&mut self, key: KeyEvent, is_update: bool) {
-        let form = if is_update {
-            self.update_issue_form.as_mut()
-        } else {
-            self.create_issue_form.as_mut()
+    fn form_mut(&mut self, target: FormTarget) -> Option<&mut IssueForm> {
+        match target {
+            FormTarget::Create => self.create_issue_form.as_mut(),
+            FormTarget::Update => self.update_issue_form.as_mut(),
+            FormTarget::TimeEntry => self.time_entry_form.as_mut(),
+            FormTarget::Relation => self.relation_form.as_mut(),
+        }
+    }
+
+    /// Reopen a form the server rejected, with each message shown next to the field it names
+    pub(crate) fn show_form_errors(&mut self, target: FormTarget, errors: &[String]) {
+        let Some(form) = self.form_mut(target) else {
+            return;
         };
+        form.apply_server_errors(errors);
+
+        match target {
+            FormTarget::Create => {
+                self.show_create_issue_form = true;
+                self.input_mode = InputMode::CreatingIssue;
+            }
+            FormTarget::Update => self.input_mode = InputMode::ReplyingToIssue,
+            FormTarget::TimeEntry => self.input_mode = InputMode::LoggingTime,
+            FormTarget::Relation => self.input_mode = InputMode::AddingRelation,
+        }
+        self.status_message = Some("Fix the highlighted fields and press Ctrl+S to resubmit".to_string());
+    }
+
+    /// Route form field input to the appropriate handler
+    pub(crate) fn handle_form_input(&mut self, key: KeyEvent, target: FormTarget) {
+        let form = self.form_mut(target);
 
         // Extract field info before calling handler methods to avoid borrow conflicts
         let field_info = form.and_then(|f| {
             f.get_current_field().map(|field| {
-                let old_tracker_id = if field.key == "tracker_id" && !is_update {
+                let old_tracker_id = if field.key == "tracker_id" && target == FormTarget::Create {
                     f.get_value("tracker_id").and_then(|v| v.as_option_id())
                 } else {
                     None
@@ -39,25 +74,28 @@ impl App {
 
             match field_type {
                 FieldType::Text | FieldType::TextArea | FieldType::Date | FieldType::Float => {
-                    self.handle_text_field_input(key, &field_key, field_type, is_update);
+                    self.handle_text_field_input(key, &field_key, field_type, target);
                 }
                 FieldType::Dropdown => {
-                    self.handle_dropdown_input(key, &field_key, is_update);
+                    self.handle_dropdown_input(key, &field_key, target);
                 }
                 FieldType::SearchableDropdown => {
-                    self.handle_searchable_dropdown_input(key, &field_key, is_update);
+                    self.handle_searchable_dropdown_input(key, &field_key, target);
+                }
+                FieldType::MultiSelect => {
+                    self.handle_multi_select_input(key, &field_key, target);
                 }
                 FieldType::Progress => {
-                    self.handle_progress_input(key, &field_key, is_update);
+                    self.handle_progress_input(key, &field_key, target);
                 }
                 FieldType::Checkbox => {
-                    self.handle_checkbox_input(key, &field_key, is_update);
+                    self.handle_checkbox_input(key, &field_key, target);
                 }
                 _ => {}
             }
 
             // Check if tracker changed - if so, rebuild form with preserved values
-            if is_tracker_field && !is_update {
+            if is_tracker_field && target == FormTarget::Create {
                 if let Some(form) = self.create_issue_form.as_ref() {
                     let new_tracker_id = form.get_value("tracker_id").and_then(|v| v.as_option_id());
                     if old_tracker_id != new_tracker_id && new_tracker_id.is_some() {
@@ -73,13 +111,9 @@ impl App {
         key: KeyEvent,
         field_key: &str,
         field_type: FieldType,
-        is_update: bool,
+        target: FormTarget,
     ) {
-        let form = if is_update {
-            self.update_issue_form.as_mut()
-        } else {
-            self.create_issue_form.as_mut()
-        };
+        let form = self.form_mut(target);
 
         if let Some(form) = form {
             match key.code {
@@ -115,41 +149,33 @@ impl App {
         }
     }
 
-    pub(crate) fn handle_dropdown_input(&mut self, key: KeyEvent, field_key: &str, is_update: bool) {
-        let form = if is_update {
-            self.update_issue_form.as_mut()
-        } else {
-            self.create_issue_form.as_mut()
-        };
+    pub(crate) fn handle_dropdown_input(&mut self, key: KeyEvent, field_key: &str, target: FormTarget) {
+        let form = self.form_mut(target);
 
         if let Some(form) = form {
             let field = form.get_current_field();
             if let Some(field) = field {
                 let options = &field.options;
                 match key.code {
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        if !options.is_empty() {
-                            let current_id = form.get_value(field_key).and_then(|v| v.as_option_id());
-                            let current_idx = current_id
-                                .and_then(|id| options.iter().position(|opt| opt.id == id))
-                                .unwrap_or(0);
-                            let new_idx = if current_idx > 0 {
-                                current_idx - 1
-                            } else {
-                                options.len() - 1
-                            };
-                            form.set_value(field_key.to_string(), FieldValue::OptionId(Some(options[new_idx].id)));
-                        }
+                    KeyCode::Up | KeyCode::Char('k') if !options.is_empty() => {
+                        let current_id = form.get_value(field_key).and_then(|v| v.as_option_id());
+                        let current_idx = current_id
+                            .and_then(|id| options.iter().position(|opt| opt.id == id))
+                            .unwrap_or(0);
+                        let new_idx = if current_idx > 0 {
+                            current_idx - 1
+                        } else {
+                            options.len() - 1
+                        };
+                        form.set_value(field_key.to_string(), FieldValue::OptionId(Some(options[new_idx].id)));
                     }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        if !options.is_empty() {
-                            let current_id = form.get_value(field_key).and_then(|v| v.as_option_id());
-                            let current_idx = current_id
-                                .and_then(|id| options.iter().position(|opt| opt.id == id))
-                                .unwrap_or(0);
-                            let new_idx = (current_idx + 1) % options.len();
-                            form.set_value(field_key.to_string(), FieldValue::OptionId(Some(options[new_idx].id)));
-                        }
+                    KeyCode::Down | KeyCode::Char('j') if !options.is_empty() => {
+                        let current_id = form.get_value(field_key).and_then(|v| v.as_option_id());
+                        let current_idx = current_id
+                            .and_then(|id| options.iter().position(|opt| opt.id == id))
+                            .unwrap_or(0);
+                        let new_idx = (current_idx + 1) % options.len();
+                        form.set_value(field_key.to_string(), FieldValue::OptionId(Some(options[new_idx].id)));
                     }
                     _ => {}
                 }
@@ -157,12 +183,9 @@ impl App {
         }
     }
 
-    pub(crate) fn handle_searchable_dropdown_input(&mut self, key: KeyEvent, field_key: &str, is_update: bool) {
-        let form = if is_update {
-            self.update_issue_form.as_mut()
-        } else {
-            self.create_issue_form.as_mut()
-        };
+    pub(crate) fn handle_searchable_dropdown_input(&mut self, key: KeyEvent, field_key: &str, target: FormTarget) {
+        let mut status_message = None;
+        let form = self.form_mut(target);
 
         if let Some(form) = form {
             let is_search_mode = form.is_search_mode(field_key);
@@ -183,7 +206,7 @@ impl App {
                     navigate_dropdown(form, field_key, -1);
                 }
                 KeyCode::Enter if is_search_mode => {
-                    handle_dropdown_enter(form, field_key, &mut self.status_message);
+                    handle_dropdown_enter(form, field_key, &mut status_message);
                 }
                 KeyCode::Up if is_search_mode => {
                     navigate_dropdown(form, field_key, -1);
@@ -216,14 +239,63 @@ impl App {
                 _ => {}
             }
         }
+
+        if status_message.is_some() {
+            self.status_message = status_message;
+        }
     }
 
-    pub(crate) fn handle_progress_input(&mut self, key: KeyEvent, field_key: &str, is_update: bool) {
-        let form = if is_update {
-            self.update_issue_form.as_mut()
-        } else {
-            self.create_issue_form.as_mut()
-        };
+    pub(crate) fn handle_multi_select_input(&mut self, key: KeyEvent, field_key: &str, target: FormTarget) {
+        let form = self.form_mut(target);
+
+        if let Some(form) = form {
+            let is_search_mode = form.is_search_mode(field_key);
+
+            match key.code {
+                KeyCode::Char('/') if !is_search_mode => {
+                    form.set_search_mode(field_key.to_string(), true);
+                    form.set_search_text(field_key.to_string(), String::new());
+                }
+                KeyCode::Esc if is_search_mode => {
+                    form.clear_search(field_key);
+                }
+                KeyCode::Enter if is_search_mode => {
+                    // Check the match and go back to the full list
+                    form.toggle_highlighted_option(field_key);
+                    form.clear_search(field_key);
+                }
+                KeyCode::Char(' ') => {
+                    form.toggle_highlighted_option(field_key);
+                }
+                KeyCode::Up | KeyCode::BackTab if is_search_mode => {
+                    form.move_option_cursor(field_key, -1);
+                }
+                KeyCode::Down | KeyCode::Tab if is_search_mode => {
+                    form.move_option_cursor(field_key, 1);
+                }
+                KeyCode::Up | KeyCode::Char('k') if !is_search_mode => {
+                    form.move_option_cursor(field_key, -1);
+                }
+                KeyCode::Down | KeyCode::Char('j') if !is_search_mode => {
+                    form.move_option_cursor(field_key, 1);
+                }
+                KeyCode::Char(c) if is_search_mode && !c.is_control() => {
+                    let mut search = form.get_search_text(field_key);
+                    search.push(c);
+                    form.set_search_text(field_key.to_string(), search);
+                }
+                KeyCode::Backspace if is_search_mode => {
+                    let mut search = form.get_search_text(field_key);
+                    search.pop();
+                    form.set_search_text(field_key.to_string(), search);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn handle_progress_input(&mut self, key: KeyEvent, field_key: &str, target: FormTarget) {
+        let form = self.form_mut(target);
 
         if let Some(form) = form {
             match key.code {
@@ -242,12 +314,8 @@ impl App {
         }
     }
 
-    pub(crate) fn handle_checkbox_input(&mut self, key: KeyEvent, field_key: &str, is_update: bool) {
-        let form = if is_update {
-            self.update_issue_form.as_mut()
-        } else {
-            self.create_issue_form.as_mut()
-        };
+    pub(crate) fn handle_checkbox_input(&mut self, key: KeyEvent, field_key: &str, target: FormTarget) {
+        let form = self.form_mut(target);
 
         if let Some(form) = form {
             match key.code {
@@ -1,2 +1,4 @@
 // Form field input handlers - extracted to separate module for better organization
 mod form_fields;
+
+pub(crate) use form_fields::FormTarget;
@@ -5,9 +5,15 @@ mod handler_modules;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
 use ratatui::layout::Rect;
 
+use super::conflicts::ConflictResolution;
+use super::dashboard::DashboardSection;
+use super::issue_links::IssueList;
 use super::state::{App, InputMode, Pane, Screen};
+pub(crate) use handler_modules::FormTarget;
+use crate::config::{ApiKeySource, API_KEY_ENV};
 use crate::form_field::FieldValue;
-use crate::issue_form::IssueForm;
+use crate::issue_form::{IssueForm, ProjectChoices};
+use crate::issue_query::FilterQuery;
 use crate::redmine::RedmineClient;
 
 impl App {
@@ -19,13 +25,22 @@ impl App {
 
         match self.input_mode {
             InputMode::Normal => self.handle_normal_mode_key(key),
-            InputMode::Editing | InputMode::EditingUrl | InputMode::EditingApiKey => self.handle_editing_mode_key(key),
+            InputMode::Editing | InputMode::EditingUrl | InputMode::EditingApiKey | InputMode::EditingProfileName => {
+                self.handle_editing_mode_key(key)
+            }
             InputMode::Searching => self.handle_search_mode_key(key),
             InputMode::CreatingIssue => self.handle_create_issue_key(key),
             InputMode::ReplyingToIssue => self.handle_reply_key(key),
             InputMode::BulkEditing => self.handle_bulk_editing_key(key),
             InputMode::AddingAttachment => self.handle_adding_attachment_key(key),
             InputMode::ManagingAttachments => self.handle_managing_attachments_key(key),
+            InputMode::LoggingTime => self.handle_logging_time_key(key),
+            InputMode::ManagingTimeEntries => self.handle_managing_time_entries_key(key),
+            InputMode::ManagingPendingOperations => self.handle_managing_pending_operations_key(key),
+            InputMode::ResolvingConflict => self.handle_resolving_conflict_key(key),
+            InputMode::GlobalSearch => self.handle_global_search_key(key),
+            InputMode::ManagingFilterPresets => self.handle_managing_filter_presets_key(key),
+            InputMode::AddingRelation => self.handle_adding_relation_key(key),
         }
     }
 
@@ -38,6 +53,14 @@ impl App {
             || self.show_image_viewer
             || self.show_error_popup
             || self.show_help_popup
+            || self.show_time_entries_popup
+            || self.input_mode == InputMode::ManagingPendingOperations
+            || self.input_mode == InputMode::ResolvingConflict
+            || self.input_mode == InputMode::GlobalSearch
+            || self.input_mode == InputMode::ManagingFilterPresets
+            || self.screen == Screen::Dashboard
+            || self.screen == Screen::Roadmap
+            || self.screen == Screen::Board
         {
             return;
         }
@@ -55,7 +78,18 @@ impl App {
                 // Check if click is in main area (not status bar)
                 if y < main_height {
                     // Determine which pane was clicked
-                    if x < projects_width {
+                    let queries_top = main_height - self.saved_queries_pane_height(main_height);
+                    if x < projects_width && y >= queries_top {
+                        // Saved queries under the projects clicked
+                        self.focused_pane = Pane::Queries;
+                        let clicked_index = y.saturating_sub(queries_top + 1) as usize;
+                        if y > queries_top && clicked_index < self.visible_saved_queries().len() {
+                            self.saved_queries_list_state = clicked_index;
+                            if mouse.kind == MouseEventKind::Down(crossterm::event::MouseButton::Left) {
+                                self.run_selected_saved_query();
+                            }
+                        }
+                    } else if x < projects_width {
                         // Projects pane clicked
                         self.focused_pane = Pane::Projects;
                         // Calculate which project was clicked (y - 1 for border)
@@ -66,6 +100,7 @@ impl App {
                                 // Double click or Enter-like behavior: select project
                                 if mouse.kind == MouseEventKind::Down(crossterm::event::MouseButton::Left) {
                                     if let Some(project) = self.filtered_projects.get(clicked_index).cloned() {
+                                        self.clear_saved_query();
                                         self.selected_project = Some(project);
                                         self.focused_pane = Pane::Issues;
                                         self.issues_list_state = 0;
@@ -75,7 +110,7 @@ impl App {
                         }
                     } else {
                         // Issues pane clicked - only allow if project is selected
-                        if self.selected_project.is_some() {
+                        if self.shows_issue_list() {
                             self.focused_pane = Pane::Issues;
                             if y >= 1 && !self.filtered_issues.is_empty() {
                                 let clicked_index = (y - 1) as usize;
@@ -106,6 +141,7 @@ impl App {
                             let max = self.filtered_projects.len().saturating_sub(1);
                             self.projects_list_state = (self.projects_list_state + 3).min(max);
                         }
+                        Pane::Queries => self.move_saved_query_cursor(3),
                         Pane::Issues => {
                             let max = self.filtered_issues.len().saturating_sub(1);
                             self.issues_list_state = (self.issues_list_state + 3).min(max);
@@ -121,6 +157,7 @@ impl App {
                         Pane::Projects => {
                             self.projects_list_state = self.projects_list_state.saturating_sub(3);
                         }
+                        Pane::Queries => self.move_saved_query_cursor(-3),
                         Pane::Issues => {
                             self.issues_list_state = self.issues_list_state.saturating_sub(3);
                         }
@@ -132,95 +169,100 @@ impl App {
     }
 
     fn handle_normal_mode_key(&mut self, key: KeyEvent) {
+        if self.confirm_delete_relation {
+            // Any key other than 'y' cancels the pending delete
+            self.confirm_delete_relation = false;
+            if key.code == KeyCode::Char('y') {
+                if let Some(relation) = self.selected_relation() {
+                    self.delete_relation_id = Some(relation.id);
+                    self.status_message = Some("Removing relation...".to_string());
+                }
+            }
+            return;
+        }
+
         match key.code {
             KeyCode::Char('q') => self.running = false,
+            KeyCode::Char('Q') => self.show_pending_operations(),
+            KeyCode::Char('F') => self.show_global_search(),
+            KeyCode::Char('f') if self.screen == Screen::Main && !self.show_issue_popup => self.show_filter_presets(),
+            KeyCode::Char('v') if self.screen == Screen::Main && !self.show_issue_popup => {
+                if self.focused_pane == Pane::Queries {
+                    self.focused_pane = Pane::Projects;
+                } else {
+                    self.show_saved_queries();
+                }
+            }
+            KeyCode::Char('V') if self.screen == Screen::Main && !self.show_issue_popup => self.show_roadmap(),
+            KeyCode::Char('C') if self.screen == Screen::Main && !self.show_issue_popup => self.show_board(),
             KeyCode::Char('?') => self.show_help_popup = !self.show_help_popup,
-            KeyCode::Char('c') => self.screen = Screen::Config,
+            KeyCode::Char('c') => {
+                if self.screen != Screen::Config {
+                    self.config_return_screen = self.screen;
+                }
+                self.screen = Screen::Config;
+            }
+            KeyCode::Char('D') if !self.show_issue_popup => match self.screen {
+                Screen::Main => self.show_dashboard(),
+                Screen::Dashboard => self.screen = Screen::Main,
+                Screen::Roadmap | Screen::Board => self.show_dashboard(),
+                Screen::Config => {}
+            },
             KeyCode::Char('e') => {
                 // Show error popup if there's an error
                 if let Some(error) = &self.error_message {
                     self.show_error_popup = true;
                     // Also save error to a file for easier debugging
                     let error_file = "/tmp/minecli-last-error.txt";
-                    if let Err(e) = std::fs::write(error_file, error) {
+                    let contents = match self.current_error_detail() {
+                        Some(detail) => format!("{}\n\n{}", error, detail),
+                        None => error.clone(),
+                    };
+                    if let Err(e) = std::fs::write(error_file, contents) {
                         tracing::error!("Failed to write error file: {}", e);
                     } else {
                         self.status_message = Some(format!("Error saved to {}", error_file));
                     }
                 }
             }
+            KeyCode::Char('d') if self.show_error_popup => {
+                self.show_error_detail = !self.show_error_detail;
+            }
             KeyCode::Char('n') => {
                 // Open new issue form (only if project is selected)
-                if let Some(project) = &self.selected_project {
-                    // Check if metadata is loaded
-                    if self.trackers.is_empty() || self.statuses.is_empty() || self.priorities.is_empty() {
-                        self.error_message = Some(format!(
-                            "Metadata not loaded yet. Trackers: {}, Statuses: {}, Priorities: {}",
-                            self.trackers.len(),
-                            self.statuses.len(),
-                            self.priorities.len()
-                        ));
-                        return;
-                    }
-
-                    let project_id = project.id;
-                    // Trigger loading users for the project
-                    self.load_users_flag = true;
-                    self.load_users_project_id = Some(project_id);
-
-                    // Get custom fields for default tracker from cache
-                    let default_tracker_id = self
-                        .trackers
-                        .iter()
-                        .find(|t| t.name.to_lowercase() == "task")
-                        .or_else(|| self.trackers.first())
-                        .map(|t| t.id);
-
-                    let custom_fields = if let Some(tracker_id) = default_tracker_id {
-                        self.tracker_custom_fields_cache
-                            .get(&tracker_id)
-                            .cloned()
-                            .unwrap_or_else(Vec::new)
-                    } else {
-                        Vec::new()
-                    };
-
-                    // Create data-driven form with cached custom fields
-                    self.create_issue_form = Some(IssueForm::new_issue_form_with_custom_fields(
-                        &self.trackers,
-                        &self.statuses,
-                        &self.priorities,
-                        &self.users,
-                        &self.categories,
-                        &custom_fields,
-                    ));
-
-                    self.show_create_issue_form = true;
-                    self.input_mode = InputMode::CreatingIssue;
+                if let Some(project_id) = self.selected_project.as_ref().map(|p| p.id) {
+                    self.open_create_issue_form(project_id);
                 } else {
                     self.error_message = Some("Please select a project first".to_string());
                 }
             }
-            KeyCode::Char('/') => {
+            KeyCode::Char('/') if self.screen == Screen::Main && self.focused_pane != Pane::Queries => {
                 self.input_mode = InputMode::Searching;
                 self.search_query.clear();
+                self.search_error = None;
             }
             KeyCode::Esc => {
                 if self.show_help_popup {
                     self.show_help_popup = false;
                 } else if self.screen == Screen::Config {
                     // Check if configuration is complete before allowing exit
-                    if self.config.redmine_url.is_empty() || self.config.api_key.is_empty() {
+                    if self.config.redmine_url().is_empty() || self.config.api_key().is_empty() {
                         self.error_message = Some(
                             "Configuration incomplete! Please set both Redmine URL and API Key before continuing."
                                 .to_string(),
                         );
                     } else {
-                        self.screen = Screen::Main;
+                        match self.config_return_screen {
+                            Screen::Dashboard => self.show_dashboard(),
+                            Screen::Roadmap => self.show_roadmap(),
+                            Screen::Board => self.screen = Screen::Board,
+                            _ => self.screen = Screen::Main,
+                        }
                         self.error_message = None;
                     }
                 } else if self.show_error_popup {
                     self.show_error_popup = false;
+                    self.show_error_detail = false;
                 } else if self.show_image_viewer {
                     self.show_image_viewer = false;
                     self.viewing_image_url = None;
@@ -230,11 +272,32 @@ impl App {
                     self.current_issue = None;
                     self.popup_scroll = 0;
                     self.attachment_page = 0;
+                    self.selected_issue_link = 0;
+                    self.issue_history.clear();
+                    self.collapsed_subtasks.clear();
                     self.error_message = None;
+                    // Pick up changes made from the popup
+                    if self.screen == Screen::Dashboard {
+                        self.reload_dashboard();
+                    } else if self.screen == Screen::Roadmap {
+                        self.reload_roadmap();
+                    }
                 } else if self.show_create_issue_form {
                     self.show_create_issue_form = false;
                     self.input_mode = InputMode::Normal;
                     self.error_message = None;
+                } else if matches!(self.screen, Screen::Dashboard | Screen::Board) {
+                    self.screen = Screen::Main;
+                } else if self.screen == Screen::Roadmap {
+                    if self.roadmap_focus_issues {
+                        self.roadmap_focus_issues = false;
+                    } else {
+                        self.screen = Screen::Main;
+                    }
+                } else if self.active_saved_query.is_some() {
+                    // Leave the saved query before clearing filters
+                    self.clear_saved_query();
+                    self.error_message = None;
                 } else {
                     // Only clear filters if not closing a popup
                     self.project_filter.clear();
@@ -246,8 +309,10 @@ impl App {
             }
             _ => match self.screen {
                 Screen::Main => self.handle_main_screen_key(key),
+                Screen::Dashboard => self.handle_dashboard_key(key),
+                Screen::Roadmap => self.handle_roadmap_key(key),
+                Screen::Board => self.handle_board_key(key),
                 Screen::Config => self.handle_config_key(key),
-                _ => {}
             },
         }
     }
@@ -257,8 +322,13 @@ impl App {
             KeyCode::Esc => {
                 self.input_mode = InputMode::Normal;
                 self.search_query.clear();
+                self.search_error = None;
             }
             KeyCode::Enter => {
+                // Syntax errors stay in the search bar until fixed
+                if self.search_error.is_some() {
+                    return;
+                }
                 self.input_mode = InputMode::Normal;
                 match self.focused_pane {
                     Pane::Projects => {
@@ -267,20 +337,37 @@ impl App {
                     Pane::Issues => {
                         self.issue_filter = self.search_query.clone();
                     }
+                    Pane::Queries => {}
                 }
                 self.apply_filters();
+
+                // A filter naming an unknown priority etc. goes back to the search bar
+                if self.focused_pane == Pane::Issues && self.search_error.is_some() {
+                    self.input_mode = InputMode::Searching;
+                    return;
+                }
                 self.search_query.clear();
             }
             KeyCode::Backspace => {
                 self.search_query.pop();
+                self.check_search_query();
             }
             KeyCode::Char(c) => {
                 self.search_query.push(c);
+                self.check_search_query();
             }
             _ => {}
         }
     }
 
+    /// Parse the issue filter as it's typed so syntax errors show right away
+    fn check_search_query(&mut self) {
+        self.search_error = match self.focused_pane {
+            Pane::Issues => FilterQuery::parse(&self.search_query).err(),
+            Pane::Projects | Pane::Queries => None,
+        };
+    }
+
     fn handle_editing_mode_key(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Esc => {
@@ -300,7 +387,7 @@ impl App {
                             self.error_message = Some("Invalid URL format".to_string());
                         } else {
                             // Valid URL
-                            self.config.redmine_url = url.to_string();
+                            self.config.set_redmine_url(url.to_string());
                             self.input_mode = InputMode::Normal;
                             self.save_config();
                             self.status_message = Some("URL saved successfully".to_string());
@@ -314,12 +401,15 @@ impl App {
                         } else if api_key.len() != 40 {
                             self.error_message = Some(format!("API Key must be exactly 40 characters (current: {})", api_key.len()));
                         } else {
-                            self.config.api_key = api_key.to_string();
+                            self.config.set_api_key(api_key.to_string());
                             self.input_mode = InputMode::Normal;
                             self.save_config();
                             self.status_message = Some("API Key saved successfully".to_string());
                         }
                     }
+                    InputMode::EditingProfileName => {
+                        self.add_profile_from_input();
+                    }
                     _ => {
                         self.input_mode = InputMode::Normal;
                     }
@@ -332,6 +422,9 @@ impl App {
                 InputMode::EditingApiKey => {
                     self.api_key_input.pop();
                 }
+                InputMode::EditingProfileName => {
+                    self.profile_name_input.pop();
+                }
                 _ => {}
             },
             KeyCode::Char(c) => match self.input_mode {
@@ -341,6 +434,9 @@ impl App {
                 InputMode::EditingApiKey => {
                     self.api_key_input.push(c);
                 }
+                InputMode::EditingProfileName => {
+                    self.profile_name_input.push(c);
+                }
                 _ => {}
             },
             _ => {}
@@ -351,6 +447,21 @@ impl App {
         if self.show_issue_popup {
             // Issue popup is open, handle scrolling and reply
             match key.code {
+                // Parents, related issues and subtasks: highlight, open, go back
+                KeyCode::Tab => self.cycle_issue_link(1),
+                KeyCode::BackTab => self.cycle_issue_link(-1),
+                KeyCode::Enter => self.open_selected_issue_link(),
+                KeyCode::Backspace => self.open_previous_issue(),
+                KeyCode::Char(' ') => self.toggle_selected_subtask(),
+                KeyCode::Char('N') => self.open_subtask_form(),
+                KeyCode::Char('L') => {
+                    // Relate this issue to another one
+                    if let Some(issue_id) = self.current_issue.as_ref().map(|i| i.id) {
+                        self.open_relation_form(issue_id);
+                    }
+                }
+                KeyCode::Char('X') => self.request_delete_selected_relation(),
+                KeyCode::Char('W') => self.request_toggle_watch(),
                 KeyCode::Down | KeyCode::Char('j') => {
                     self.popup_scroll = self.popup_scroll.saturating_add(1);
                 }
@@ -368,7 +479,7 @@ impl App {
                 KeyCode::Char('O') => {
                     // Open issue in browser (Shift+O)
                     if let Some(issue) = &self.current_issue {
-                        let url = format!("{}/issues/{}", self.config.redmine_url, issue.id);
+                        let url = format!("{}/issues/{}", self.config.redmine_url(), issue.id);
                         if let Err(e) = open::that(&url) {
                             self.error_message = Some(format!("Failed to open browser: {}", e));
                         } else {
@@ -386,19 +497,41 @@ impl App {
                         }
 
                         // Create data-driven update form with current values
+                        let parent_issues = self.parent_issue_candidates(issue.project.id);
+                        let versions = self.project_versions(issue.project.id);
                         self.update_issue_form = Some(IssueForm::update_issue_form(
                             &self.statuses,
                             &self.users,
-                            &self.categories,
-                            issue.status.id,
-                            issue.assigned_to.as_ref().map(|a| a.id),
-                            issue.done_ratio,
-                            None, // category_id not tracked in issue struct yet
+                            ProjectChoices {
+                                categories: &self.categories,
+                                versions: &versions,
+                                parent_issues: &parent_issues,
+                            },
+                            issue,
                         ));
+                        // Compared with the server's copy on submit to catch concurrent edits
+                        self.update_form_opened_at = Some(issue.updated_on);
 
                         self.input_mode = InputMode::ReplyingToIssue;
                     }
                 }
+                KeyCode::Char('t') => {
+                    // Log time on this issue
+                    if let Some(issue_id) = self.current_issue.as_ref().map(|i| i.id) {
+                        self.open_time_entry_form(issue_id, None);
+                    }
+                }
+                KeyCode::Char('S') => {
+                    // Start/stop timer on this issue
+                    let issue = self.current_issue.as_ref().map(|i| (i.id, i.subject.clone()));
+                    self.toggle_timer(issue);
+                }
+                KeyCode::Char('T') => {
+                    // List time entries of this issue
+                    if let Some(issue_id) = self.current_issue.as_ref().map(|i| i.id) {
+                        self.show_time_entries(issue_id);
+                    }
+                }
                 KeyCode::Char(c @ '1'..='9') => {
                     // View attachment - with pagination support
                     if let Some(issue) = &self.current_issue {
@@ -412,14 +545,14 @@ impl App {
                             {
                                 attachment.content_url.clone()
                             } else {
-                                format!("{}{}", self.config.redmine_url, attachment.content_url)
+                                format!("{}{}", self.config.redmine_url(), attachment.content_url)
                             };
 
                             // Check if Shift is pressed - if so, open in browser regardless of type
                             let force_browser = key.modifiers.contains(KeyModifiers::SHIFT);
 
                             // If it's an image and Shift is NOT pressed, show in viewer
-                            let is_image = attachment.content_type.as_ref().map_or(false, |ct| ct.starts_with("image/"));
+                            let is_image = attachment.content_type.as_ref().is_some_and(|ct| ct.starts_with("image/"));
                             if is_image && !force_browser {
                                 self.show_image_viewer = true;
                                 self.viewing_image_url = Some(url);
@@ -439,54 +572,32 @@ impl App {
                         }
                     }
                 }
-                KeyCode::Char('[') => {
-                    // Previous page of attachments
-                    if self.show_issue_popup && self.attachment_page > 0 {
-                        self.attachment_page -= 1;
-                        self.status_message = Some(format!("Page {}", self.attachment_page + 1));
-                    }
+                // Previous page of attachments
+                KeyCode::Char('[') if self.show_issue_popup && self.attachment_page > 0 => {
+                    self.attachment_page -= 1;
+                    self.status_message = Some(format!("Page {}", self.attachment_page + 1));
                 }
-                KeyCode::Char(']') => {
-                    // Next page of attachments
-                    if self.show_issue_popup {
-                        if let Some(issue) = &self.current_issue {
-                            const ATTACHMENTS_PER_PAGE: usize = 9;
-                            let total_pages = issue.attachments.len().div_ceil(ATTACHMENTS_PER_PAGE);
-                            if self.attachment_page + 1 < total_pages {
-                                self.attachment_page += 1;
-                                self.status_message = Some(format!("Page {}", self.attachment_page + 1));
-                            }
-                        }
-                    }
-                }
-                KeyCode::Char('J') => {
-                    // Next issue (Shift+J)
-                    if !self.filtered_issues.is_empty() {
-                        let max_index = if self.group_issues_by_status {
-                            self.get_visible_items_count().saturating_sub(1)
-                        } else {
-                            self.filtered_issues.len().saturating_sub(1)
-                        };
-                        
-                        if self.issues_list_state < max_index {
-                            self.issues_list_state += 1;
-                            
-                            // Load the new issue
-                            if let Some(issue) = self.get_issue_at_cursor() {
-                                let issue_clone = issue.clone();
-                                self.loading_issue = true;
-                                self.popup_scroll = 0;
-                                self.attachment_page = 0;
-                                self.current_issue = Some(issue_clone);
-                                self.status_message = None; // Clear status to show help
-                            }
+                // Next page of attachments
+                KeyCode::Char(']') if self.show_issue_popup => {
+                    if let Some(issue) = &self.current_issue {
+                        const ATTACHMENTS_PER_PAGE: usize = 9;
+                        let total_pages = issue.attachments.len().div_ceil(ATTACHMENTS_PER_PAGE);
+                        if self.attachment_page + 1 < total_pages {
+                            self.attachment_page += 1;
+                            self.status_message = Some(format!("Page {}", self.attachment_page + 1));
                         }
                     }
                 }
-                KeyCode::Char('K') => {
-                    // Previous issue (Shift+K)
-                    if !self.filtered_issues.is_empty() && self.issues_list_state > 0 {
-                        self.issues_list_state -= 1;
+                // Next issue (Shift+J)
+                KeyCode::Char('J') if !self.filtered_issues.is_empty() => {
+                    let max_index = if self.group_issues_by_status {
+                        self.get_visible_items_count().saturating_sub(1)
+                    } else {
+                        self.filtered_issues.len().saturating_sub(1)
+                    };
+                    
+                    if self.issues_list_state < max_index {
+                        self.issues_list_state += 1;
                         
                         // Load the new issue
                         if let Some(issue) = self.get_issue_at_cursor() {
@@ -499,6 +610,20 @@ impl App {
                         }
                     }
                 }
+                // Previous issue (Shift+K)
+                KeyCode::Char('K') if !self.filtered_issues.is_empty() && self.issues_list_state > 0 => {
+                    self.issues_list_state -= 1;
+                    
+                    // Load the new issue
+                    if let Some(issue) = self.get_issue_at_cursor() {
+                        let issue_clone = issue.clone();
+                        self.loading_issue = true;
+                        self.popup_scroll = 0;
+                        self.attachment_page = 0;
+                        self.current_issue = Some(issue_clone);
+                        self.status_message = None; // Clear status to show help
+                    }
+                }
                 _ => {}
             }
             return;
@@ -509,31 +634,46 @@ impl App {
             KeyCode::Char('h') => {
                 self.focused_pane = Pane::Projects;
             }
-            KeyCode::Char('l') => {
-                // Only allow focusing Issues pane if a project is selected
-                if self.selected_project.is_some() {
-                    self.focused_pane = Pane::Issues;
-                }
+            // Only allow focusing Issues pane if a project is selected
+            KeyCode::Char('l') if self.shows_issue_list() => {
+                self.focused_pane = Pane::Issues;
             }
             // Sort cycling - works regardless of focused pane if issues are loaded
-            KeyCode::Char('s') => {
-                if !self.filtered_issues.is_empty() {
-                    self.issue_sort_order = self.issue_sort_order.next();
-                    self.apply_filters(); // Re-query from DB with new sort order
-                    self.issues_list_state = 0;
-                    self.status_message = Some(format!("Sorted by: {}", self.issue_sort_order.as_str()));
-                }
+            KeyCode::Char('s') if !self.filtered_issues.is_empty() => {
+                self.issue_sort_order = self.issue_sort_order.next();
+                self.apply_filters(); // Re-query from DB with new sort order
+                self.issues_list_state = 0;
+                self.status_message = Some(format!("Sorted by: {}", self.issue_sort_order.as_str()));
             }
-            // Refresh data with Shift+P and Shift+I
+            // Start/stop timer on the issue under the cursor
+            KeyCode::Char('S') => {
+                let issue = if self.focused_pane == Pane::Issues {
+                    self.get_issue_at_cursor().map(|i| (i.id, i.subject.clone()))
+                } else {
+                    None
+                };
+                self.toggle_timer(issue);
+            }
+            // Refresh data with Shift+P and Shift+I (Shift+R re-downloads every issue)
             KeyCode::Char('P') => {
                 self.refresh_projects = true;
                 self.status_message = Some("Refreshing projects...".to_string());
             }
-            KeyCode::Char('I') => {
-                if self.selected_project.is_some() {
+            KeyCode::Char('I') | KeyCode::Char('R') => {
+                if let Some(query) = self.active_saved_query.clone() {
+                    // Re-run the saved query instead of syncing the project
+                    self.status_message = Some(format!("Running query '{}'...", query.name));
+                    self.run_saved_query_flag = Some(query);
+                } else if self.selected_project.is_some() {
                     self.refresh_issues = true;
                     self.loading = true;
-                    self.status_message = Some("Refreshing issues (this may take a moment for large projects)...".to_string());
+                    if key.code == KeyCode::Char('R') {
+                        self.issues_full_sync = true;
+                        self.status_message =
+                            Some("Re-downloading all issues (this may take a moment for large projects)...".to_string());
+                    } else {
+                        self.status_message = Some("Syncing issues...".to_string());
+                    }
                 } else {
                     self.error_message = Some("No project selected".to_string());
                 }
@@ -553,22 +693,22 @@ impl App {
                     self.error_message = Some("Loading user info...".to_string());
                 }
             }
+            // Apply the filter preset bound to a number key
+            KeyCode::Char(c @ '1'..='9') => {
+                self.apply_filter_preset_slot(c as u8 - b'0');
+            }
             // Toggle issues pane maximize
-            KeyCode::Char('z') => {
-                if self.selected_project.is_some() {
-                    self.issues_pane_maximized = !self.issues_pane_maximized;
-                    if self.issues_pane_maximized {
-                        self.status_message = Some("Issues pane maximized (press 'z' to restore)".to_string());
-                    } else {
-                        self.status_message = Some("Split view restored".to_string());
-                    }
+            KeyCode::Char('z') if self.shows_issue_list() => {
+                self.issues_pane_maximized = !self.issues_pane_maximized;
+                if self.issues_pane_maximized {
+                    self.status_message = Some("Issues pane maximized (press 'z' to restore)".to_string());
+                } else {
+                    self.status_message = Some("Split view restored".to_string());
                 }
             }
             // Toggle bulk operation mode
-            KeyCode::Char('b') | KeyCode::Char('B') => {
-                if self.focused_pane == Pane::Issues && !self.filtered_issues.is_empty() {
-                    self.toggle_bulk_mode();
-                }
+            KeyCode::Char('b') | KeyCode::Char('B') if self.focused_pane == Pane::Issues && !self.filtered_issues.is_empty() => {
+                self.toggle_bulk_mode();
             }
             // Select all issues (in bulk mode)
             KeyCode::Char('a') if self.bulk_operation_mode => {
@@ -583,17 +723,31 @@ impl App {
                 self.show_bulk_edit_form();
             }
             // Toggle status grouping/folding
-            KeyCode::Char('g') => {
-                if !self.filtered_issues.is_empty() {
-                    self.group_issues_by_status = !self.group_issues_by_status;
-                    self.issues_list_state = 0;
-                    if self.group_issues_by_status {
-                        self.status_message = Some("Issues grouped by status (Space to collapse/expand)".to_string());
-                    } else {
-                        self.status_message = Some("Status grouping disabled".to_string());
-                    }
+            KeyCode::Char('g') if !self.filtered_issues.is_empty() => {
+                self.group_issues_by_status = !self.group_issues_by_status;
+                self.issues_list_state = 0;
+                if self.issue_tree_view {
+                    // The tree only applies to the flat list
+                    self.apply_filters();
+                }
+                if self.group_issues_by_status {
+                    self.status_message = Some("Issues grouped by status (Space to collapse/expand)".to_string());
+                } else {
+                    self.status_message = Some("Status grouping disabled".to_string());
                 }
             }
+            // Toggle parent/subtask tree in the issue list
+            KeyCode::Char('H') if self.shows_issue_list() => {
+                self.issue_tree_view = !self.issue_tree_view;
+                self.apply_filters();
+                self.status_message = Some(if !self.issue_tree_view {
+                    "Tree view disabled".to_string()
+                } else if self.group_issues_by_status {
+                    "Tree view applies when status grouping is off (press 'g')".to_string()
+                } else {
+                    "Subtasks listed under their parent".to_string()
+                });
+            }
             // List navigation
             KeyCode::Down | KeyCode::Char('j') => {
                 match self.focused_pane {
@@ -603,6 +757,7 @@ impl App {
                             self.projects_list_state += 1;
                         }
                     }
+                    Pane::Queries => self.move_saved_query_cursor(1),
                     Pane::Issues => {
                         // Calculate max based on visible items (respecting collapsed groups)
                         let max = if self.group_issues_by_status {
@@ -622,6 +777,7 @@ impl App {
                         self.projects_list_state -= 1;
                     }
                 }
+                Pane::Queries => self.move_saved_query_cursor(-1),
                 Pane::Issues => {
                     if self.issues_list_state > 0 {
                         self.issues_list_state -= 1;
@@ -666,11 +822,13 @@ impl App {
                 match self.focused_pane {
                     Pane::Projects => {
                         if let Some(project) = self.get_project_at_cursor().cloned() {
+                            self.clear_saved_query();
                             self.selected_project = Some(project);
                             self.focused_pane = Pane::Issues;
                             self.issues_list_state = 0;
                         }
                     }
+                    Pane::Queries => self.run_selected_saved_query(),
                     Pane::Issues => {
                         // In grouped mode, Space toggles collapse - Enter still opens issue
                         if let Some(issue) = self.get_issue_at_cursor() {
@@ -690,18 +848,117 @@ impl App {
         }
     }
 
+    fn handle_dashboard_key(&mut self, key: KeyEvent) {
+        if self.show_issue_popup {
+            // Next/previous issue of the section; everything else works as in the main screen
+            match key.code {
+                KeyCode::Char('J') => self.step_listed_issue(IssueList::Dashboard, 1),
+                KeyCode::Char('K') => self.step_listed_issue(IssueList::Dashboard, -1),
+                _ => self.handle_main_screen_key(key),
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Tab | KeyCode::Char('l') | KeyCode::Right => self.cycle_dashboard_section(1),
+            KeyCode::BackTab | KeyCode::Char('h') | KeyCode::Left => self.cycle_dashboard_section(-1),
+            KeyCode::Char(c @ '1'..='4') => {
+                self.dashboard_section = DashboardSection::ALL[(c as u8 - b'1') as usize];
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.move_dashboard_cursor(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_dashboard_cursor(-1),
+            KeyCode::Char('g') => self.dashboard_list_states[self.dashboard_section.index()] = 0,
+            KeyCode::Char('G') => {
+                let i = self.dashboard_section.index();
+                self.dashboard_list_states[i] = self.dashboard_issues[i].len().saturating_sub(1);
+            }
+            KeyCode::Char('s') => self.cycle_dashboard_sort(),
+            KeyCode::Enter => self.open_listed_issue(IssueList::Dashboard),
+            KeyCode::Char('p') => self.jump_to_dashboard_issue_project(),
+            KeyCode::Char('I') | KeyCode::Char('R') if self.client.is_some() => {
+                self.refresh_dashboard_flag = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_roadmap_key(&mut self, key: KeyEvent) {
+        if self.show_issue_popup {
+            // Next/previous issue of the version; everything else works as in the main screen
+            match key.code {
+                KeyCode::Char('J') => self.step_listed_issue(IssueList::Roadmap, 1),
+                KeyCode::Char('K') => self.step_listed_issue(IssueList::Roadmap, -1),
+                _ => self.handle_main_screen_key(key),
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => self.move_roadmap_cursor(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_roadmap_cursor(-1),
+            KeyCode::Char('g') => self.move_roadmap_cursor(isize::MIN / 2),
+            KeyCode::Char('G') => self.move_roadmap_cursor(isize::MAX / 2),
+            KeyCode::Enter if self.roadmap_focus_issues => self.open_listed_issue(IssueList::Roadmap),
+            KeyCode::Enter | KeyCode::Tab | KeyCode::Char('l') | KeyCode::Right => self.open_roadmap_version(),
+            KeyCode::BackTab | KeyCode::Char('h') | KeyCode::Left => self.roadmap_focus_issues = false,
+            KeyCode::Char('s') => self.cycle_roadmap_sort(),
+            KeyCode::Char('V') => self.screen = Screen::Main,
+            KeyCode::Char('I') | KeyCode::Char('R') if self.client.is_some() => {
+                self.fetch_versions_project_id = self.roadmap_project_id;
+                if self.roadmap_focus_issues {
+                    self.fetch_version_issues_id = self.selected_roadmap_version().map(|v| v.id);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_board_key(&mut self, key: KeyEvent) {
+        if self.show_issue_popup {
+            // Next/previous card of the column; everything else works as in the main screen
+            match key.code {
+                KeyCode::Char('J') => self.step_listed_issue(IssueList::Board, 1),
+                KeyCode::Char('K') => self.step_listed_issue(IssueList::Board, -1),
+                _ => self.handle_main_screen_key(key),
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => self.move_board_column(1),
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => self.move_board_column(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_board_card(1),
+            KeyCode::Up | KeyCode::Char('k') => self.move_board_card(-1),
+            KeyCode::Char('g') => self.move_board_card(isize::MIN / 2),
+            KeyCode::Char('G') => self.move_board_card(isize::MAX / 2),
+            KeyCode::Char('L') => self.request_move_board_card(1),
+            KeyCode::Char('H') => self.request_move_board_card(-1),
+            KeyCode::Enter => self.open_listed_issue(IssueList::Board),
+            KeyCode::Char('C') => self.screen = Screen::Main,
+            // Sorting, "My Issues" and syncing work as in the issue list
+            KeyCode::Char('s') | KeyCode::Char('m') | KeyCode::Char('I') | KeyCode::Char('R') => {
+                let selected = self.selected_board_issue().map(|i| i.id);
+                self.handle_main_screen_key(key);
+                if let Some(issue_id) = selected {
+                    self.select_board_issue(issue_id);
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn handle_config_key(&mut self, key: KeyEvent) {
         use crate::theme::{Theme, ThemeName};
 
         match key.code {
             KeyCode::Tab => {
-                // Navigate forward through fields: URL -> API Key -> Theme -> Exclude Subprojects
-                self.config_focused_field = (self.config_focused_field + 1) % 4;
+                // Navigate forward through fields: Profile -> URL -> API Key -> Theme -> Exclude Subprojects
+                self.config_focused_field = (self.config_focused_field + 1) % 5;
             }
             KeyCode::BackTab => {
                 // Navigate backward through fields
                 self.config_focused_field = if self.config_focused_field == 0 {
-                    3
+                    4
                 } else {
                     self.config_focused_field - 1
                 };
@@ -709,16 +966,34 @@ impl App {
             KeyCode::Enter => {
                 match self.config_focused_field {
                     0 => {
-                        // Start editing URL
-                        self.input_mode = InputMode::EditingUrl;
-                        self.url_input = self.config.redmine_url.clone();
+                        // Switch to the selected profile
+                        if let Some(name) = self.config.profile_names().get(self.profile_selector_index).cloned() {
+                            self.switch_profile(&name);
+                        }
                     }
                     1 => {
-                        // Start editing API Key
-                        self.input_mode = InputMode::EditingApiKey;
-                        self.api_key_input = self.config.api_key.clone();
+                        // Start editing URL
+                        self.input_mode = InputMode::EditingUrl;
+                        self.url_input = self.config.redmine_url().to_string();
                     }
                     2 => {
+                        // Start editing API Key, unless it comes from outside the config file
+                        match self.config.api_key_source() {
+                            Some(ApiKeySource::Environment) => {
+                                self.error_message =
+                                    Some(format!("API Key is set by {}; unset it to edit the stored key", API_KEY_ENV));
+                            }
+                            Some(ApiKeySource::Command) => {
+                                self.error_message =
+                                    Some("API Key comes from api_key_command; edit it in the config file".to_string());
+                            }
+                            _ => {
+                                self.input_mode = InputMode::EditingApiKey;
+                                self.api_key_input.clear();
+                            }
+                        }
+                    }
+                    3 => {
                         // Apply selected theme and save config
                         let themes = ThemeName::all();
                         if let Some(&selected_theme) = themes.get(self.theme_selector_index) {
@@ -728,7 +1003,7 @@ impl App {
                         }
                         self.save_config();
                     }
-                    3 => {
+                    4 => {
                         // Toggle exclude_subprojects checkbox
                         self.config.exclude_subprojects = !self.config.exclude_subprojects;
                         self.save_config();
@@ -742,44 +1017,54 @@ impl App {
                     _ => {}
                 }
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                // Only work when theme field is focused
-                if self.config_focused_field == 2 && self.theme_selector_index > 0 {
-                    self.theme_selector_index -= 1;
+            // Profile selector
+            KeyCode::Left | KeyCode::Up | KeyCode::Char('h') | KeyCode::Char('k') if self.config_focused_field == 0 => {
+                self.select_profile(-1);
+            }
+            KeyCode::Right | KeyCode::Down | KeyCode::Char('l') | KeyCode::Char('j') if self.config_focused_field == 0 => {
+                self.select_profile(1);
+            }
+            KeyCode::Char('a') if self.config_focused_field == 0 => {
+                self.input_mode = InputMode::EditingProfileName;
+                self.profile_name_input.clear();
+            }
+            KeyCode::Char('d') if self.config_focused_field == 0 => {
+                self.remove_selected_profile();
+            }
+            // Only work when theme field is focused
+            KeyCode::Up | KeyCode::Char('k') if self.config_focused_field == 3 && self.theme_selector_index > 0 => {
+                self.theme_selector_index -= 1;
+                // Live preview: apply theme immediately
+                let themes = ThemeName::all();
+                if let Some(&selected_theme) = themes.get(self.theme_selector_index) {
+                    self.config.theme = selected_theme;
+                    self.theme = Theme::from_name(selected_theme);
+                }
+            }
+            // Only work when theme field is focused
+            KeyCode::Down | KeyCode::Char('j') if self.config_focused_field == 3 => {
+                let themes = ThemeName::all();
+                if self.theme_selector_index < themes.len() - 1 {
+                    self.theme_selector_index += 1;
                     // Live preview: apply theme immediately
-                    let themes = ThemeName::all();
                     if let Some(&selected_theme) = themes.get(self.theme_selector_index) {
                         self.config.theme = selected_theme;
                         self.theme = Theme::from_name(selected_theme);
                     }
                 }
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                // Only work when theme field is focused
-                if self.config_focused_field == 2 {
-                    let themes = ThemeName::all();
-                    if self.theme_selector_index < themes.len() - 1 {
-                        self.theme_selector_index += 1;
-                        // Live preview: apply theme immediately
-                        if let Some(&selected_theme) = themes.get(self.theme_selector_index) {
-                            self.config.theme = selected_theme;
-                            self.theme = Theme::from_name(selected_theme);
-                        }
-                    }
-                }
-            }
-            KeyCode::Char(' ') => {
-                // Toggle checkbox when on exclude_subprojects field
-                if self.config_focused_field == 3 {
-                    self.config.exclude_subprojects = !self.config.exclude_subprojects;
-                    self.save_config();
-                    let status = if self.config.exclude_subprojects {
-                        "Subproject issues will be excluded"
-                    } else {
-                        "Subproject issues will be included"
-                    };
-                    self.status_message = Some(status.to_string());
-                }
+            // Toggle checkbox when on exclude_subprojects field
+            KeyCode::Char(' ') if self.config_focused_field == 4 => {
+                self.config.exclude_subprojects = !self.config.exclude_subprojects;
+                self.save_config();
+                // The cached issue set changes shape, so the next sync can't be a delta
+                self.issues_full_sync = true;
+                let status = if self.config.exclude_subprojects {
+                    "Subproject issues will be excluded"
+                } else {
+                    "Subproject issues will be included"
+                };
+                self.status_message = Some(status.to_string());
             }
             _ => {}
         }
@@ -787,23 +1072,23 @@ impl App {
 
     fn handle_reply_key(&mut self, key: KeyEvent) {
         if key.modifiers.contains(KeyModifiers::CONTROL) {
-            match key.code {
-                KeyCode::Char('s') => {
-                    // Submit the update
-                    if let Some(issue) = &self.current_issue {
-                        self.add_comment_flag = true;
-                        self.comment_issue_id = Some(issue.id);
-                        self.input_mode = InputMode::Normal;
-                        self.status_message = Some("Updating issue...".to_string());
-                    }
-                    return;
+            if let KeyCode::Char('s') = key.code {
+                // Submit the update
+                if let Some(issue) = &self.current_issue {
+                    self.add_comment_flag = true;
+                    self.comment_issue_id = Some(issue.id);
+                    self.input_mode = InputMode::Normal;
+                    self.status_message = Some("Updating issue...".to_string());
                 }
-                _ => {}
+                return;
             }
         }
 
+        // Typed into a dropdown search, '@' and '#' are search text (e.g. "#123")
+        let searching = self.update_issue_form.as_ref().is_some_and(|form| form.is_searching());
+
         match key.code {
-            KeyCode::Char('@') => {
+            KeyCode::Char('@') if !searching => {
                 // Open file explorer for attachment
                 self.previous_input_mode = InputMode::ReplyingToIssue;
                 self.input_mode = InputMode::AddingAttachment;
@@ -814,20 +1099,19 @@ impl App {
                     }
                     Err(e) => self.error_message = Some(format!("Failed to open file explorer: {}", e)),
                 }
-                return;
             }
-            KeyCode::Char('#') => {
+            KeyCode::Char('#') if !searching => {
                 // Show attachment manager
                 if !self.pending_attachments.is_empty() {
                     self.previous_input_mode = InputMode::ReplyingToIssue;
                     self.input_mode = InputMode::ManagingAttachments;
                     self.attachment_list_state = 0;
                 }
-                return;
             }
             KeyCode::Esc => {
                 self.input_mode = InputMode::Normal;
                 self.update_issue_form = None;
+                self.update_form_opened_at = None;
                 self.clear_attachments();
             }
             KeyCode::Tab => {
@@ -840,7 +1124,7 @@ impl App {
 
                     if is_in_search {
                         // Let the searchable dropdown handler deal with it
-                        self.handle_form_input(key, true);
+                        self.handle_form_input(key, FormTarget::Update);
                     } else {
                         form.next_field();
                     }
@@ -856,32 +1140,32 @@ impl App {
 
                     if is_in_search {
                         // Let the searchable dropdown handler deal with it
-                        self.handle_form_input(key, true);
+                        self.handle_form_input(key, FormTarget::Update);
                     } else {
                         form.prev_field();
                     }
                 }
             }
             _ => {
-                self.handle_form_input(key, true);
+                self.handle_form_input(key, FormTarget::Update);
             }
         }
     }
 
     fn handle_create_issue_key(&mut self, key: KeyEvent) {
         if key.modifiers.contains(KeyModifiers::CONTROL) {
-            match key.code {
-                KeyCode::Char('s') => {
-                    // Submit the form
-                    self.submit_new_issue();
-                    return;
-                }
-                _ => {}
+            if let KeyCode::Char('s') = key.code {
+                // Submit the form
+                self.submit_new_issue();
+                return;
             }
         }
 
+        // Typed into a dropdown search, '@' and '#' are search text (e.g. "#123")
+        let searching = self.create_issue_form.as_ref().is_some_and(|form| form.is_searching());
+
         match key.code {
-            KeyCode::Char('@') => {
+            KeyCode::Char('@') if !searching => {
                 // Open file explorer for attachment
                 self.previous_input_mode = InputMode::CreatingIssue;
                 self.input_mode = InputMode::AddingAttachment;
@@ -892,16 +1176,14 @@ impl App {
                     }
                     Err(e) => self.error_message = Some(format!("Failed to open file explorer: {}", e)),
                 }
-                return;
             }
-            KeyCode::Char('#') => {
+            KeyCode::Char('#') if !searching => {
                 // Show attachment manager
                 if !self.pending_attachments.is_empty() {
                     self.previous_input_mode = InputMode::CreatingIssue;
                     self.input_mode = InputMode::ManagingAttachments;
                     self.attachment_list_state = 0;
                 }
-                return;
             }
             KeyCode::Esc => {
                 self.show_create_issue_form = false;
@@ -911,15 +1193,9 @@ impl App {
             }
             KeyCode::Tab => {
                 if let Some(form) = &mut self.create_issue_form {
-                    // Check if current field is searchable dropdown in search mode
-                    let is_in_search = form.get_current_field().is_some_and(|field| {
-                        field.field_type == crate::form_field::FieldType::SearchableDropdown
-                            && form.is_search_mode(&field.key)
-                    });
-
-                    if is_in_search {
-                        // Let the searchable dropdown handler deal with it
-                        self.handle_form_input(key, false);
+                    if form.is_searching() {
+                        // Let the searchable dropdown or multi-select handler deal with it
+                        self.handle_form_input(key, FormTarget::Create);
                     } else {
                         form.next_field();
                     }
@@ -927,22 +1203,16 @@ impl App {
             }
             KeyCode::BackTab => {
                 if let Some(form) = &mut self.create_issue_form {
-                    // Check if current field is searchable dropdown in search mode
-                    let is_in_search = form.get_current_field().is_some_and(|field| {
-                        field.field_type == crate::form_field::FieldType::SearchableDropdown
-                            && form.is_search_mode(&field.key)
-                    });
-
-                    if is_in_search {
-                        // Let the searchable dropdown handler deal with it
-                        self.handle_form_input(key, false);
+                    if form.is_searching() {
+                        // Let the searchable dropdown or multi-select handler deal with it
+                        self.handle_form_input(key, FormTarget::Create);
                     } else {
                         form.prev_field();
                     }
                 }
             }
             _ => {
-                self.handle_form_input(key, false);
+                self.handle_form_input(key, FormTarget::Create);
             }
         }
     }
@@ -970,10 +1240,10 @@ impl App {
             self.error_message = Some(format!("Failed to save config: {}", e));
         } else {
             // Only create client if both URL and API key are set
-            if !self.config.redmine_url.is_empty() && !self.config.api_key.is_empty() {
+            if !self.config.redmine_url().is_empty() && !self.config.api_key().is_empty() {
                 self.client = Some(RedmineClient::new(
-                    self.config.redmine_url.clone(),
-                    self.config.api_key.clone(),
+                    self.config.redmine_url().to_string(),
+                    self.config.api_key().to_string(),
                 ));
             }
         }
@@ -1209,31 +1479,235 @@ impl App {
                 // Close attachment manager
                 self.input_mode = self.previous_input_mode;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.attachment_list_state > 0 {
+            KeyCode::Up | KeyCode::Char('k') if self.attachment_list_state > 0 => {
+                self.attachment_list_state -= 1;
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.attachment_list_state < self.pending_attachments.len().saturating_sub(1) => {
+                self.attachment_list_state += 1;
+            }
+            // Delete selected attachment
+            KeyCode::Char('d') | KeyCode::Delete if !self.pending_attachments.is_empty() && self.attachment_list_state < self.pending_attachments.len() => {
+                self.remove_attachment(self.attachment_list_state);
+                if self.attachment_list_state >= self.pending_attachments.len() && self.attachment_list_state > 0 {
                     self.attachment_list_state -= 1;
                 }
+                self.status_message = Some(format!("Removed attachment ({} remaining)", self.pending_attachments.len()));
+                
+                // Close manager if no attachments left
+                if self.pending_attachments.is_empty() {
+                    self.input_mode = self.previous_input_mode;
+                }
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.attachment_list_state < self.pending_attachments.len().saturating_sub(1) {
-                    self.attachment_list_state += 1;
+            _ => {}
+        }
+    }
+    fn handle_logging_time_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('s') {
+            self.submit_time_entry_form();
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => {
+                self.cancel_time_entry_form();
+            }
+            KeyCode::Tab => {
+                if let Some(form) = &mut self.time_entry_form {
+                    form.next_field();
                 }
             }
-            KeyCode::Char('d') | KeyCode::Delete => {
-                // Delete selected attachment
-                if !self.pending_attachments.is_empty() && self.attachment_list_state < self.pending_attachments.len() {
-                    self.remove_attachment(self.attachment_list_state);
-                    if self.attachment_list_state >= self.pending_attachments.len() && self.attachment_list_state > 0 {
-                        self.attachment_list_state -= 1;
-                    }
-                    self.status_message = Some(format!("Removed attachment ({} remaining)", self.pending_attachments.len()));
-                    
-                    // Close manager if no attachments left
-                    if self.pending_attachments.is_empty() {
-                        self.input_mode = self.previous_input_mode;
-                    }
+            KeyCode::BackTab => {
+                if let Some(form) = &mut self.time_entry_form {
+                    form.prev_field();
                 }
             }
+            _ => {
+                self.handle_form_input(key, FormTarget::TimeEntry);
+            }
+        }
+    }
+
+    fn handle_adding_relation_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('s') {
+            self.submit_relation_form();
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.cancel_relation_form(),
+            KeyCode::Tab => {
+                if let Some(form) = &mut self.relation_form {
+                    form.next_field();
+                }
+            }
+            KeyCode::BackTab => {
+                if let Some(form) = &mut self.relation_form {
+                    form.prev_field();
+                }
+            }
+            _ => self.handle_form_input(key, FormTarget::Relation),
+        }
+    }
+
+    fn handle_managing_time_entries_key(&mut self, key: KeyEvent) {
+        if self.confirm_delete_time_entry {
+            // Any key other than 'y' cancels the pending delete
+            self.confirm_delete_time_entry = false;
+            if key.code == KeyCode::Char('y') {
+                if let Some(entry) = self.time_entries.get(self.time_entries_list_state) {
+                    self.delete_time_entry_id = Some(entry.id);
+                    self.status_message = Some("Deleting time entry...".to_string());
+                }
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('T') => {
+                self.close_time_entries();
+            }
+            KeyCode::Up | KeyCode::Char('k') if self.time_entries_list_state > 0 => {
+                self.time_entries_list_state -= 1;
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.time_entries_list_state < self.time_entries.len().saturating_sub(1) => {
+                self.time_entries_list_state += 1;
+            }
+            // Log a new entry on the same issue
+            KeyCode::Char('n') | KeyCode::Char('t') => {
+                if let Some(issue_id) = self.time_entry_issue_id {
+                    self.open_time_entry_form(issue_id, None);
+                }
+            }
+            KeyCode::Char('e') | KeyCode::Enter if !self.time_entries.is_empty() => {
+                self.edit_selected_time_entry();
+            }
+            KeyCode::Char('d') | KeyCode::Delete if !self.time_entries.is_empty() => {
+                self.confirm_delete_time_entry = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_managing_pending_operations_key(&mut self, key: KeyEvent) {
+        if self.confirm_drop_pending_operation {
+            // Any key other than 'y' cancels the pending drop
+            self.confirm_drop_pending_operation = false;
+            if key.code == KeyCode::Char('y') {
+                if let Some(operation) = self.pending_operations.get(self.pending_operations_list_state) {
+                    self.drop_pending_operation_id = Some(operation.id);
+                }
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('Q') => {
+                self.close_pending_operations();
+            }
+            KeyCode::Up | KeyCode::Char('k') if self.pending_operations_list_state > 0 => {
+                self.pending_operations_list_state -= 1;
+            }
+            KeyCode::Down | KeyCode::Char('j')
+                if self.pending_operations_list_state < self.pending_operations.len().saturating_sub(1) =>
+            {
+                self.pending_operations_list_state += 1;
+            }
+            KeyCode::Char('r') if !self.pending_operations.is_empty() => {
+                self.retry_selected_pending_operation();
+            }
+            KeyCode::Char('d') | KeyCode::Delete if !self.pending_operations.is_empty() => {
+                self.confirm_drop_pending_operation = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_resolving_conflict_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('m') => {
+                self.resolve_conflict_flag = Some(ConflictResolution::Merge);
+                self.input_mode = InputMode::Normal;
+                self.status_message = Some("Updating issue...".to_string());
+            }
+            KeyCode::Char('o') => {
+                self.resolve_conflict_flag = Some(ConflictResolution::Overwrite);
+                self.input_mode = InputMode::Normal;
+                self.status_message = Some("Updating issue...".to_string());
+            }
+            KeyCode::Char('a') | KeyCode::Esc => self.abort_issue_conflict(),
+            _ => {}
+        }
+    }
+
+    fn handle_managing_filter_presets_key(&mut self, key: KeyEvent) {
+        if self.confirm_delete_filter_preset {
+            // Any key other than 'y' cancels the pending delete
+            self.confirm_delete_filter_preset = false;
+            if key.code == KeyCode::Char('y') {
+                self.delete_selected_filter_preset();
+            }
+            return;
+        }
+
+        if let Some(name) = &mut self.filter_preset_name_input {
+            match key.code {
+                KeyCode::Esc => self.filter_preset_name_input = None,
+                KeyCode::Enter => self.save_filter_preset(),
+                KeyCode::Backspace => {
+                    name.pop();
+                }
+                KeyCode::Char(c) => name.push(c),
+                _ => {}
+            }
+            return;
+        }
+
+        let has_selection = !self.filter_presets.is_empty();
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('f') => self.close_filter_presets(),
+            KeyCode::Up | KeyCode::Char('k') if self.filter_presets_list_state > 0 => {
+                self.filter_presets_list_state -= 1;
+            }
+            KeyCode::Down | KeyCode::Char('j')
+                if self.filter_presets_list_state < self.filter_presets.len().saturating_sub(1) =>
+            {
+                self.filter_presets_list_state += 1;
+            }
+            KeyCode::Enter => self.apply_selected_filter_preset(),
+            KeyCode::Char('s') => self.filter_preset_name_input = Some(String::new()),
+            KeyCode::Char('u') if has_selection => self.update_selected_filter_preset(),
+            KeyCode::Char(c @ '1'..='9') if has_selection => {
+                self.bind_selected_filter_preset(Some(c as u8 - b'0'));
+            }
+            KeyCode::Char('0') if has_selection => self.bind_selected_filter_preset(None),
+            KeyCode::Char('d') | KeyCode::Delete if has_selection => {
+                self.confirm_delete_filter_preset = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_global_search_key(&mut self, key: KeyEvent) {
+        let last = self.global_search_results.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc => self.close_global_search(),
+            KeyCode::Enter => self.open_global_search_result(),
+            KeyCode::Up => {
+                self.global_search_list_state = self.global_search_list_state.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.global_search_list_state = (self.global_search_list_state + 1).min(last);
+            }
+            KeyCode::Backspace => {
+                self.global_search_query.pop();
+                self.global_search_list_state = 0;
+                self.run_global_search();
+            }
+            KeyCode::Char(c) => {
+                self.global_search_query.push(c);
+                self.global_search_list_state = 0;
+                self.run_global_search();
+            }
             _ => {}
         }
     }
@@ -5,6 +5,18 @@ use std::collections::HashMap;
 impl App {
     /// Get the project at the current cursor position in the tree view
     pub fn get_project_at_cursor(&self) -> Option<&Project> {
+        self.project_display_list().get(self.projects_list_state).copied()
+    }
+
+    /// Put the projects pane cursor on a project, if the tree view currently shows it
+    pub fn move_project_cursor_to(&mut self, project_id: u64) {
+        if let Some(index) = self.project_display_list().iter().position(|p| p.id == project_id) {
+            self.projects_list_state = index;
+        }
+    }
+
+    /// Projects in the order the tree view lists them, without the children of collapsed ones
+    fn project_display_list(&self) -> Vec<&Project> {
         // Build the same tree structure as the UI
         let mut root_projects = Vec::new();
         let mut child_map: HashMap<u64, Vec<&Project>> = HashMap::new();
@@ -50,6 +62,6 @@ impl App {
             );
         }
 
-        display_list.get(self.projects_list_state).copied()
+        display_list
     }
 }
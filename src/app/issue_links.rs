@@ -0,0 +1,234 @@
+//! Navigation between linked issues in the issue popup.
+//!
+//! The popup lists the issue's parent chain, its relations and its subtask tree.
+//! Tab walks these links in that order, Enter opens the highlighted issue and
+//! Backspace returns along `issue_history`. New subtasks are filed from here too.
+//! The dashboard, roadmap and board open the issue under their cursor here and
+//! step through their list while the popup stays open.
+
+use super::state::App;
+use crate::form_field::FieldValue;
+use crate::redmine::{Issue, IssueChild};
+
+/// Longest parent chain followed through the cache, in case parent ids form a cycle
+const MAX_PARENT_DEPTH: usize = 10;
+
+/// An issue list outside the main screen that opens its issues in the popup
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum IssueList {
+    Dashboard,
+    Roadmap,
+    Board,
+}
+
+impl IssueList {
+    fn selected(self, app: &App) -> Option<&Issue> {
+        match self {
+            IssueList::Dashboard => app.selected_dashboard_issue(),
+            IssueList::Roadmap => app.selected_roadmap_issue(),
+            IssueList::Board => app.selected_board_issue(),
+        }
+    }
+
+    fn move_cursor(self, app: &mut App, delta: isize) {
+        match self {
+            IssueList::Dashboard => app.move_dashboard_cursor(delta),
+            IssueList::Roadmap => {
+                app.roadmap_focus_issues = true;
+                app.move_roadmap_cursor(delta);
+            }
+            IssueList::Board => app.move_board_card(delta),
+        }
+    }
+}
+
+/// An issue the popup links to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueLink {
+    /// An ancestor; the chain runs from the root down to the direct parent
+    Parent(u64),
+    /// The issue at the other end of a relation
+    Relation { relation_id: u64, issue_id: u64 },
+    /// A visible subtask; depth 0 is a direct child
+    Subtask {
+        issue_id: u64,
+        depth: usize,
+        has_children: bool,
+    },
+}
+
+impl IssueLink {
+    pub fn issue_id(&self) -> u64 {
+        match *self {
+            IssueLink::Parent(issue_id)
+            | IssueLink::Relation { issue_id, .. }
+            | IssueLink::Subtask { issue_id, .. } => issue_id,
+        }
+    }
+}
+
+impl App {
+    /// Ancestors of an issue from the root down, as far as the cache knows them
+    pub fn parent_chain(&self, issue: &Issue) -> Vec<u64> {
+        let mut chain = Vec::new();
+        let mut parent_id = issue.parent.as_ref().map(|p| p.id);
+        while let Some(id) = parent_id {
+            if chain.len() >= MAX_PARENT_DEPTH || chain.contains(&id) {
+                break;
+            }
+            chain.push(id);
+            parent_id = self
+                .db
+                .get_issue(id)
+                .ok()
+                .flatten()
+                .and_then(|parent| parent.parent.map(|p| p.id));
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Subtasks of an issue in tree order, skipping those under collapsed subtasks
+    pub fn visible_subtasks<'a>(&self, issue: &'a Issue) -> Vec<(usize, &'a IssueChild)> {
+        fn walk<'a>(app: &App, children: &'a [IssueChild], depth: usize, out: &mut Vec<(usize, &'a IssueChild)>) {
+            for child in children {
+                out.push((depth, child));
+                if !app.collapsed_subtasks.contains(&child.id) {
+                    walk(app, &child.children, depth + 1, out);
+                }
+            }
+        }
+
+        let mut subtasks = Vec::new();
+        walk(self, &issue.children, 0, &mut subtasks);
+        subtasks
+    }
+
+    /// Every issue the popup links to, in the order Tab walks them
+    pub fn issue_links(&self) -> Vec<IssueLink> {
+        let Some(issue) = &self.current_issue else {
+            return Vec::new();
+        };
+
+        let mut links: Vec<IssueLink> = self.parent_chain(issue).into_iter().map(IssueLink::Parent).collect();
+        links.extend(
+            issue
+                .related_issues()
+                .into_iter()
+                .map(|(_, relation)| IssueLink::Relation {
+                    relation_id: relation.id,
+                    issue_id: relation.other_issue_id(issue.id),
+                }),
+        );
+        links.extend(
+            self.visible_subtasks(issue)
+                .into_iter()
+                .map(|(depth, child)| IssueLink::Subtask {
+                    issue_id: child.id,
+                    depth,
+                    has_children: !child.children.is_empty(),
+                }),
+        );
+        links
+    }
+
+    /// Index of the highlighted link, clamped to the links there are
+    pub fn selected_issue_link_index(&self, link_count: usize) -> usize {
+        self.selected_issue_link.min(link_count.saturating_sub(1))
+    }
+
+    pub fn selected_issue_link(&self) -> Option<IssueLink> {
+        let links = self.issue_links();
+        links.get(self.selected_issue_link_index(links.len())).copied()
+    }
+
+    /// Move the highlight to the next (or with a negative step, previous) linked issue
+    pub fn cycle_issue_link(&mut self, step: isize) {
+        let count = self.issue_links().len() as isize;
+        if count == 0 {
+            return;
+        }
+        let current = self.selected_issue_link_index(count as usize) as isize;
+        self.selected_issue_link = (current + step).rem_euclid(count) as usize;
+    }
+
+    /// Show the highlighted linked issue in the popup
+    pub fn open_selected_issue_link(&mut self) {
+        let (Some(issue_id), Some(link)) = (self.current_issue.as_ref().map(|i| i.id), self.selected_issue_link())
+        else {
+            return;
+        };
+        self.issue_history.push(issue_id);
+        self.show_issue_in_popup(link.issue_id());
+    }
+
+    /// Fold or unfold the highlighted subtask's own subtasks
+    pub fn toggle_selected_subtask(&mut self) {
+        if let Some(IssueLink::Subtask {
+            issue_id,
+            has_children: true,
+            ..
+        }) = self.selected_issue_link()
+        {
+            if !self.collapsed_subtasks.remove(&issue_id) {
+                self.collapsed_subtasks.insert(issue_id);
+            }
+        }
+    }
+
+    /// Open the new issue form with the popup's issue as the parent
+    pub fn open_subtask_form(&mut self) {
+        let Some((parent_id, project_id)) = self.current_issue.as_ref().map(|i| (i.id, i.project.id)) else {
+            return;
+        };
+        self.open_create_issue_form(project_id);
+        if let Some(form) = &mut self.create_issue_form {
+            form.set_value("parent_issue_id".to_string(), FieldValue::OptionId(Some(parent_id)));
+        }
+    }
+
+    /// Open the issue under a list's cursor in the popup
+    pub(crate) fn open_listed_issue(&mut self, list: IssueList) {
+        let Some(issue) = list.selected(self).cloned() else {
+            return;
+        };
+        self.current_issue = Some(issue);
+        self.loading_issue = true;
+        self.popup_scroll = 0;
+        self.attachment_page = 0;
+        self.show_issue_popup = true;
+    }
+
+    /// Show the next or previous issue of a list in the open popup
+    pub(crate) fn step_listed_issue(&mut self, list: IssueList, delta: isize) {
+        list.move_cursor(self, delta);
+        self.open_listed_issue(list);
+    }
+
+    /// Go back to the issue the popup showed before the last navigation
+    pub fn open_previous_issue(&mut self) {
+        if let Some(issue_id) = self.issue_history.pop() {
+            self.show_issue_in_popup(issue_id);
+        }
+    }
+
+    /// Replace the popup's issue: the cached copy right away, then the server's
+    pub(crate) fn show_issue_in_popup(&mut self, issue_id: u64) {
+        self.popup_scroll = 0;
+        self.attachment_page = 0;
+        self.selected_issue_link = 0;
+        self.confirm_delete_relation = false;
+        match self.db.get_issue_with_journals(issue_id) {
+            Ok(Some(issue)) => {
+                self.current_issue = Some(issue);
+                self.loading_issue = true;
+            }
+            Ok(None) => {
+                // Not cached yet, the main loop fetches it
+                self.status_message = Some(format!("Loading issue #{}...", issue_id));
+                self.load_issue_id = Some(issue_id);
+            }
+            Err(e) => self.report_error("Failed to read issue", e),
+        }
+    }
+}
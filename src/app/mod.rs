@@ -1,10 +1,29 @@
 mod attachments;
+mod board;
 mod bulk_operations;
+mod conflicts;
+mod dashboard;
 mod data_loader;
 mod filters;
 mod handlers;
 mod helpers;
+mod issue_links;
+mod offline_queue;
+mod presets;
+mod profiles;
+mod relations;
+mod roadmap;
+mod saved_queries;
+mod search;
 mod state;
+mod time_tracking;
+mod watchers;
 
 // Re-export main types
-pub use state::{App, InputMode, IssueSortOrder, Pane, Screen};
+pub use board::BoardColumn;
+pub use conflicts::{field_label, ConflictResolution, FieldChange, IssueConflict};
+pub use dashboard::DashboardSection;
+pub use issue_links::IssueLink;
+pub use offline_queue::{is_local_id, PendingOperation, QueuedWrite, LOCAL_ID_BASE};
+pub use presets::FilterPreset;
+pub use state::{ActiveTimer, App, InputMode, IssueSortOrder, Pane, Screen};
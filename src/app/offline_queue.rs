@@ -0,0 +1,588 @@
+//! Offline write queue.
+//!
+//! Issue creates and updates that can't reach the server are stored in the
+//! `pending_operations` table, applied to the cached issue right away and
+//! replayed in the order they were made once the server answers again.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::state::{App, InputMode};
+use crate::redmine::{CreateIssue, IdName, Issue, Journal, UpdateIssue};
+
+/// Ids at or above this belong to issues and journals that only exist locally until their
+/// write is replayed. Far above any real Redmine id but still a valid SQLite integer.
+pub const LOCAL_ID_BASE: u64 = 1 << 53;
+
+/// How long to wait between automatic replay attempts while offline
+const REPLAY_INTERVAL_SECS: i64 = 30;
+
+pub fn is_local_id(id: u64) -> bool {
+    id >= LOCAL_ID_BASE
+}
+
+/// A write waiting in the queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueuedWrite {
+    CreateIssue(CreateIssue),
+    UpdateIssue(UpdateIssue),
+}
+
+impl QueuedWrite {
+    /// Whether sending it twice leaves a duplicate behind: a second issue or a second note
+    fn duplicates_on_retry(&self) -> bool {
+        match self {
+            Self::CreateIssue(_) => true,
+            Self::UpdateIssue(update) => update.notes.as_deref().is_some_and(|notes| !notes.trim().is_empty()),
+        }
+    }
+
+    /// Why a write whose request timed out waits for the user instead of being replayed
+    fn timed_out_message(&self) -> &'static str {
+        match self {
+            Self::CreateIssue(_) => {
+                "Timed out; the issue may have been created anyway. Check the server before retrying"
+            }
+            Self::UpdateIssue(_) => {
+                "Timed out; the change may have been saved anyway. Check the server before retrying"
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingOperation {
+    pub id: u64,
+    /// Target issue; a local id for queued creates
+    pub issue_id: u64,
+    pub write: QueuedWrite,
+    pub created_at: DateTime<Utc>,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// The server rejected it, so replay waits until it is retried or dropped
+    pub blocked: bool,
+}
+
+impl App {
+    /// Send writes behind any that are already queued so the server sees them in order
+    pub fn has_pending_operations(&self) -> bool {
+        self.pending_operations_count > 0
+    }
+
+    /// Store a write for later replay and show its effect in the cache right away
+    pub fn queue_write(&mut self, issue_id: Option<u64>, write: QueuedWrite) {
+        if self.store_write(issue_id, &write, None) {
+            self.status_message = Some(format!(
+                "Offline: change queued ({} pending, press Q to review)",
+                self.pending_operations_count
+            ));
+        }
+    }
+
+    /// Store a write whose request timed out after reaching the server. The server may have
+    /// applied it, so it is held until the user checks and retries or drops it.
+    pub fn queue_timed_out_write(&mut self, issue_id: Option<u64>, write: QueuedWrite) {
+        let message = write.timed_out_message();
+        if self.store_write(issue_id, &write, Some(message)) {
+            self.error_message = Some(format!("{} (press Q to retry or drop it)", message));
+        }
+    }
+
+    /// Queue a write, held back with the given reason if there is one; false if it couldn't be stored
+    fn store_write(&mut self, issue_id: Option<u64>, write: &QueuedWrite, held_because: Option<&str>) -> bool {
+        let operation = match self.db.queue_operation(issue_id, write) {
+            Ok(operation) => operation,
+            Err(e) => {
+                self.report_error("Failed to queue change", e);
+                return false;
+            }
+        };
+        if let Some(reason) = held_because {
+            if let Err(e) = self.db.record_pending_failure(operation.id, reason, true) {
+                tracing::warn!("Failed to hold queued change {}: {}", operation.id, e);
+            }
+        }
+        if let Err(e) = self.apply_optimistic(&operation) {
+            tracing::warn!("Failed to apply queued change to the cache: {}", e);
+        }
+        self.refresh_pending_operations();
+        self.apply_filters();
+        true
+    }
+
+    /// Reload the queue from the database (count for the status bar, list for the view)
+    pub fn refresh_pending_operations(&mut self) {
+        match self.db.get_pending_operations() {
+            Ok(operations) => {
+                self.pending_operations_count = operations.len();
+                self.pending_issue_ids = operations.iter().map(|op| op.issue_id).collect();
+                self.pending_operations = operations;
+            }
+            Err(e) => tracing::warn!("Failed to read pending operations: {}", e),
+        }
+        self.pending_operations_list_state = self
+            .pending_operations_list_state
+            .min(self.pending_operations.len().saturating_sub(1));
+    }
+
+    /// Ask the main loop to replay the queue when it has waited long enough since the last try
+    pub fn schedule_pending_replay(&mut self) {
+        if self.pending_operations_count == 0 || self.client.is_none() {
+            return;
+        }
+        let due = self
+            .last_replay_attempt
+            .is_none_or(|at| Utc::now().signed_duration_since(at).num_seconds() >= REPLAY_INTERVAL_SECS);
+        if due {
+            self.replay_pending_flag = true;
+        }
+    }
+
+    /// Send queued writes in order, stopping at the first one that fails
+    pub async fn replay_pending_operations(&mut self) {
+        let Some(client) = self.client.clone() else {
+            return;
+        };
+        self.last_replay_attempt = Some(Utc::now());
+
+        let operations = match self.db.get_pending_operations() {
+            Ok(operations) => operations,
+            Err(e) => {
+                self.report_error("Failed to read pending operations", e);
+                return;
+            }
+        };
+
+        let mut replayed = 0;
+        let mut failure = None;
+        for operation in operations {
+            if operation.blocked {
+                break;
+            }
+
+            let result = match &operation.write {
+                QueuedWrite::CreateIssue(new_issue) => {
+                    client.create_issue(new_issue.clone()).await.map(|response| Some(response.issue))
+                }
+                QueuedWrite::UpdateIssue(update) => {
+                    client.update_issue(operation.issue_id, update.clone()).await.map(|()| None)
+                }
+            };
+
+            match result {
+                Ok(created) => {
+                    replayed += 1;
+                    if let Err(e) = self.db.delete_pending_operation(operation.id) {
+                        tracing::warn!("Failed to remove replayed operation {}: {}", operation.id, e);
+                    }
+
+                    // Swap the local copy for the server's
+                    let issue = match created {
+                        Some(issue) => {
+                            if let Err(e) = self.db.delete_issue(operation.issue_id) {
+                                tracing::warn!("Failed to remove local issue: {}", e);
+                            }
+                            if let Err(e) = self.db.reassign_pending_operations(operation.issue_id, issue.id) {
+                                tracing::warn!("Failed to reassign queued changes to #{}: {}", issue.id, e);
+                            }
+                            Some(issue)
+                        }
+                        None => client.get_issue(operation.issue_id).await.ok().map(|r| r.issue),
+                    };
+                    if let Some(issue) = issue {
+                        if let Err(e) = self.db.insert_issue_with_journals(&issue) {
+                            tracing::warn!("Failed to cache issue #{}: {}", issue.id, e);
+                        }
+                    }
+                }
+                Err(e) => {
+                    // Still offline: try again later. Rejected by the server: wait for the user.
+                    // A create or note that timed out may be on the server already, and sending
+                    // it again would make a duplicate, so the user checks before retrying it.
+                    let uncertain = operation.write.duplicates_on_retry() && e.may_have_been_applied();
+                    let message = if uncertain {
+                        operation.write.timed_out_message().to_string()
+                    } else {
+                        e.user_message()
+                    };
+                    let blocked = uncertain || !e.is_offline();
+                    if let Err(db_error) = self.db.record_pending_failure(operation.id, &message, blocked) {
+                        tracing::warn!("Failed to record replay failure: {}", db_error);
+                    }
+                    if blocked {
+                        failure = Some(message);
+                    }
+                    break;
+                }
+            }
+        }
+
+        // Fresh server copies don't include the changes still waiting behind a failure
+        self.reapply_pending_operations();
+        self.refresh_pending_operations();
+        if replayed > 0 || failure.is_some() {
+            self.apply_filters();
+        }
+
+        if let Some(error) = failure {
+            self.error_message = Some(format!(
+                "A queued change was rejected: {} (press Q to retry or drop it)",
+                error
+            ));
+        } else if replayed > 0 {
+            self.status_message = Some(format!("Sent {} queued change(s) to the server", replayed));
+        }
+    }
+
+    /// Show every queued change on top of the cached issues again (after a sync overwrote them)
+    pub fn reapply_pending_operations(&mut self) {
+        if self.pending_operations_count == 0 {
+            return;
+        }
+        let operations = self.db.get_pending_operations().unwrap_or_default();
+        for operation in &operations {
+            if let Err(e) = self.apply_optimistic(operation) {
+                tracing::warn!("Failed to apply queued change {}: {}", operation.id, e);
+            }
+        }
+    }
+
+    /// Write the expected result of a queued operation into the cache
+    fn apply_optimistic(&self, operation: &PendingOperation) -> anyhow::Result<()> {
+        let me = self.current_user_ref();
+
+        let issue = match &operation.write {
+            QueuedWrite::CreateIssue(new_issue) => Issue {
+                id: operation.issue_id,
+                project: IdName {
+                    id: new_issue.project_id,
+                    name: self.db.get_project_name(new_issue.project_id)?.unwrap_or_default(),
+                },
+                tracker: self.tracker_ref(new_issue.tracker_id),
+                status: self.status_ref(new_issue.status_id),
+                priority: self.priority_ref(new_issue.priority_id),
+                author: me,
+                assigned_to: new_issue.assigned_to_id.filter(|id| *id != 0).map(|id| self.user_ref(id)),
+                parent: new_issue.parent_issue_id.map(|id| IdName { id, name: String::new() }),
+                category: None,
+                fixed_version: new_issue.fixed_version_id.map(|id| self.version_ref(id)),
+                subject: new_issue.subject.clone(),
+                description: new_issue.description.clone(),
+                start_date: new_issue.start_date.clone(),
+                due_date: new_issue.due_date.clone(),
+                done_ratio: new_issue.done_ratio,
+                is_private: None,
+                estimated_hours: new_issue.estimated_hours,
+                total_estimated_hours: None,
+                spent_hours: None,
+                total_spent_hours: None,
+                created_on: operation.created_at,
+                updated_on: operation.created_at,
+                closed_on: None,
+                journals: Vec::new(),
+                custom_fields: Vec::new(),
+                attachments: Vec::new(),
+                relations: Vec::new(),
+                children: Vec::new(),
+                watchers: Vec::new(),
+            },
+            QueuedWrite::UpdateIssue(update) => {
+                let Some(mut issue) = self.db.get_issue_with_journals(operation.issue_id)? else {
+                    return Ok(());
+                };
+
+                if let Some(subject) = &update.subject {
+                    issue.subject = subject.clone();
+                }
+                if let Some(description) = &update.description {
+                    issue.description = Some(description.clone());
+                }
+                if let Some(status_id) = update.status_id {
+                    issue.status = self.status_ref(status_id);
+                }
+                if let Some(priority_id) = update.priority_id {
+                    issue.priority = self.priority_ref(priority_id);
+                }
+                if let Some(assigned_to_id) = update.assigned_to_id {
+                    issue.assigned_to = (assigned_to_id != 0).then(|| self.user_ref(assigned_to_id));
+                }
+                if let Some(done_ratio) = update.done_ratio {
+                    issue.done_ratio = Some(done_ratio);
+                }
+                if let Some(start_date) = &update.start_date {
+                    issue.start_date = Some(start_date.clone());
+                }
+                if let Some(due_date) = &update.due_date {
+                    issue.due_date = Some(due_date.clone());
+                }
+                if let Some(estimated_hours) = update.estimated_hours {
+                    issue.estimated_hours = Some(estimated_hours);
+                }
+                if let Some(version_id) = update.fixed_version_id {
+                    issue.fixed_version = (version_id != 0).then(|| self.version_ref(version_id));
+                }
+                if let Some(parent_id) = update.parent_issue_id {
+                    issue.parent = (parent_id != 0).then(|| IdName {
+                        id: parent_id,
+                        name: String::new(),
+                    });
+                }
+
+                // The note shows up as a local journal until the server's copy replaces it
+                let journal_id = LOCAL_ID_BASE + operation.id;
+                issue.journals.retain(|j| j.id != journal_id);
+                if let Some(notes) = update.notes.as_ref().filter(|n| !n.is_empty()) {
+                    issue.journals.push(Journal {
+                        id: journal_id,
+                        user: me,
+                        notes: Some(notes.clone()),
+                        created_on: operation.created_at,
+                        private_notes: update.private_notes.unwrap_or(false),
+                        details: Vec::new(),
+                    });
+                }
+                issue
+            }
+        };
+
+        self.db.insert_issue_with_journals(&issue)
+    }
+
+    /// One-line description of a queued operation for the pending operations view
+    pub fn describe_operation(&self, operation: &PendingOperation) -> String {
+        match &operation.write {
+            QueuedWrite::CreateIssue(new_issue) => format!("Create \"{}\"", new_issue.subject),
+            QueuedWrite::UpdateIssue(update) => {
+                let mut changes = Vec::new();
+                if update.notes.as_ref().is_some_and(|n| !n.is_empty()) {
+                    changes.push("Comment".to_string());
+                }
+                if let Some(id) = update.status_id {
+                    changes.push(format!("Status: {}", self.status_ref(id).name));
+                }
+                if let Some(id) = update.priority_id {
+                    changes.push(format!("Priority: {}", self.priority_ref(id).name));
+                }
+                if let Some(id) = update.assigned_to_id {
+                    let name = if id == 0 { "(Unassigned)".to_string() } else { self.user_ref(id).name };
+                    changes.push(format!("Assignee: {}", name));
+                }
+                if let Some(done_ratio) = update.done_ratio {
+                    changes.push(format!("Done: {}%", done_ratio));
+                }
+                if let Some(due_date) = &update.due_date {
+                    changes.push(format!("Due: {}", due_date));
+                }
+                if let Some(id) = update.fixed_version_id {
+                    let name = if id == 0 { "(none)".to_string() } else { self.version_ref(id).name };
+                    changes.push(format!("Target version: {}", name));
+                }
+                if let Some(parent_id) = update.parent_issue_id {
+                    changes.push(if parent_id == 0 {
+                        "Parent: (none)".to_string()
+                    } else {
+                        format!("Parent: #{}", parent_id)
+                    });
+                }
+                if update.subject.is_some() || update.description.is_some() {
+                    changes.push("Subject/description".to_string());
+                }
+                if changes.is_empty() {
+                    changes.push("Update".to_string());
+                }
+                format!("#{} {}", operation.issue_id, changes.join(", "))
+            }
+        }
+    }
+
+    /// Open the pending operations view
+    pub fn show_pending_operations(&mut self) {
+        self.refresh_pending_operations();
+        self.pending_operations_list_state = 0;
+        self.confirm_drop_pending_operation = false;
+        self.input_mode = InputMode::ManagingPendingOperations;
+    }
+
+    pub fn close_pending_operations(&mut self) {
+        self.confirm_drop_pending_operation = false;
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Let a rejected operation go to the server again and replay right away
+    pub fn retry_selected_pending_operation(&mut self) {
+        let Some(operation) = self.pending_operations.get(self.pending_operations_list_state) else {
+            return;
+        };
+        if let Err(e) = self.db.unblock_pending_operation(operation.id) {
+            self.report_error("Failed to retry operation", e);
+            return;
+        }
+        self.replay_pending_flag = true;
+        self.status_message = Some("Retrying queued changes...".to_string());
+    }
+
+    /// Remove a queued operation and undo its effect on the cache where possible
+    pub async fn drop_pending_operation(&mut self, operation_id: u64) {
+        let Some(operation) = self.pending_operations.iter().find(|op| op.id == operation_id).cloned() else {
+            return;
+        };
+
+        let result = match operation.write {
+            // Nothing else can reach the server for an issue that was never created
+            QueuedWrite::CreateIssue(_) => self
+                .db
+                .delete_pending_operations_for_issue(operation.issue_id)
+                .and_then(|()| self.db.delete_issue(operation.issue_id)),
+            QueuedWrite::UpdateIssue(_) => self.db.delete_pending_operation(operation.id),
+        };
+        if let Err(e) = result {
+            self.report_error("Failed to drop operation", e);
+            return;
+        }
+
+        // Restore the server's copy of an updated issue, with the remaining queued changes on top
+        if let (QueuedWrite::UpdateIssue(_), Some(client)) = (&operation.write, self.client.clone()) {
+            if let Ok(response) = client.get_issue(operation.issue_id).await {
+                if let Err(e) = self.db.insert_issue_with_journals(&response.issue) {
+                    tracing::warn!("Failed to cache issue #{}: {}", operation.issue_id, e);
+                }
+            }
+        }
+        self.refresh_pending_operations();
+        self.reapply_pending_operations();
+        self.apply_filters();
+        self.status_message = Some(format!("Dropped queued change ({} pending)", self.pending_operations_count));
+    }
+
+    fn current_user_ref(&self) -> IdName {
+        match self.current_user_id {
+            Some(id) => {
+                let mut me = self.user_ref(id);
+                if me.name.starts_with('#') {
+                    me.name = "Me".to_string();
+                }
+                me
+            }
+            None => IdName {
+                id: 0,
+                name: "Me".to_string(),
+            },
+        }
+    }
+
+    pub(super) fn user_ref(&self, id: u64) -> IdName {
+        let name = self
+            .users
+            .iter()
+            .find(|u| u.id == id)
+            .map(|u| format!("{} {}", u.firstname, u.lastname).trim().to_string())
+            .unwrap_or_else(|| format!("#{}", id));
+        IdName { id, name }
+    }
+
+    pub(super) fn tracker_ref(&self, id: u64) -> IdName {
+        let name = self.trackers.iter().find(|t| t.id == id).map(|t| t.name.clone());
+        IdName {
+            id,
+            name: name.unwrap_or_else(|| format!("#{}", id)),
+        }
+    }
+
+    pub(super) fn status_ref(&self, id: u64) -> IdName {
+        let name = self.statuses.iter().find(|s| s.id == id).map(|s| s.name.clone());
+        IdName {
+            id,
+            name: name.unwrap_or_else(|| format!("#{}", id)),
+        }
+    }
+
+    pub(super) fn priority_ref(&self, id: u64) -> IdName {
+        let name = self.priorities.iter().find(|p| p.id == id).map(|p| p.name.clone());
+        IdName {
+            id,
+            name: name.unwrap_or_else(|| format!("#{}", id)),
+        }
+    }
+
+    pub(super) fn version_ref(&self, id: u64) -> IdName {
+        let name = self.db.get_version_name(id).ok().flatten();
+        IdName {
+            id,
+            name: name.unwrap_or_else(|| format!("#{}", id)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::db::Database;
+    use crate::redmine::RedmineClient;
+    use ratatui_image::picker::Picker;
+    use tempfile::TempDir;
+
+    /// An app over a fresh cache holding issue #1
+    fn queue_app() -> (App, TempDir) {
+        let temp = TempDir::new().unwrap();
+        let db = Database::new(temp.path().join("cache.db")).unwrap();
+        let issue: Issue = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "project": {"id": 1, "name": "Project"},
+            "tracker": {"id": 1, "name": "Bug"},
+            "status": {"id": 1, "name": "New"},
+            "priority": {"id": 2, "name": "Normal"},
+            "author": {"id": 1, "name": "Me"},
+            "subject": "Issue 1",
+            "created_on": "2025-01-01T10:00:00Z",
+            "updated_on": "2025-01-01T10:00:00Z",
+        }))
+        .unwrap();
+        db.insert_issues(&[issue]).unwrap();
+        (App::with_database(Config::default(), db, Picker::from_fontsize((8, 16))), temp)
+    }
+
+    #[tokio::test]
+    async fn test_replay_holds_timed_out_notes() {
+        let (mut app, _temp) = queue_app();
+        let note = UpdateIssue {
+            notes: Some("Looked into it".to_string()),
+            ..Default::default()
+        };
+        let status = UpdateIssue {
+            status_id: Some(2),
+            ..Default::default()
+        };
+
+        // Accepts connections but never answers, so every request times out
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        app.client = Some(RedmineClient::with_timeouts(
+            format!("http://{}", listener.local_addr().unwrap()),
+            "key".to_string(),
+            std::time::Duration::from_millis(200),
+            std::time::Duration::from_millis(200),
+        ));
+
+        // Sending a status change again is harmless, so it stays queued for the next replay
+        app.queue_write(Some(1), QueuedWrite::UpdateIssue(status));
+        app.replay_pending_operations().await;
+        let operations = app.db.get_pending_operations().unwrap();
+        assert_eq!(operations[0].attempts, 1);
+        assert!(!operations[0].blocked);
+        assert!(app.error_message.is_none());
+
+        // A note sent twice would be posted twice, so it waits for the user
+        app.db.delete_pending_operation(operations[0].id).unwrap();
+        app.queue_write(Some(1), QueuedWrite::UpdateIssue(note));
+        app.replay_pending_operations().await;
+        let operations = app.db.get_pending_operations().unwrap();
+        assert!(operations[0].blocked);
+        assert_eq!(
+            operations[0].last_error.as_deref(),
+            Some("Timed out; the change may have been saved anyway. Check the server before retrying")
+        );
+        assert!(app.error_message.is_some());
+        drop(listener);
+    }
+}
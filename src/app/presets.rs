@@ -0,0 +1,251 @@
+//! Saved filter presets.
+//!
+//! A preset captures the issue list's project, filter query, sort order, status
+//! grouping and "my issues" toggle in the `filter_presets` table. Presets bound
+//! to a number key are applied with `1`-`9` from the main screen.
+
+use super::state::{App, InputMode, IssueSortOrder, Pane};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterPreset {
+    pub id: u64,
+    pub name: String,
+    /// Number key (1-9) applying the preset
+    pub slot: Option<u8>,
+    /// Project to switch to; `None` keeps the selected project
+    pub project_id: Option<u64>,
+    pub issue_filter: String,
+    pub sort_order: IssueSortOrder,
+    pub group_by_status: bool,
+    pub my_issues: bool,
+}
+
+impl App {
+    pub fn show_filter_presets(&mut self) {
+        self.input_mode = InputMode::ManagingFilterPresets;
+        self.filter_preset_name_input = None;
+        self.confirm_delete_filter_preset = false;
+        self.refresh_filter_presets();
+    }
+
+    pub fn close_filter_presets(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.filter_preset_name_input = None;
+        self.confirm_delete_filter_preset = false;
+    }
+
+    pub fn refresh_filter_presets(&mut self) {
+        match self.db.get_filter_presets() {
+            Ok(presets) => self.filter_presets = presets,
+            Err(e) => self.report_error("Failed to read filter presets", e),
+        }
+        self.filter_presets_list_state = self
+            .filter_presets_list_state
+            .min(self.filter_presets.len().saturating_sub(1));
+    }
+
+    /// The issue list's current settings as a preset
+    fn current_view_preset(&self, name: String) -> FilterPreset {
+        FilterPreset {
+            id: 0,
+            name,
+            slot: None,
+            project_id: self.selected_project.as_ref().map(|p| p.id),
+            issue_filter: self.issue_filter.clone(),
+            sort_order: self.issue_sort_order,
+            group_by_status: self.group_issues_by_status,
+            my_issues: self.my_issues_filter,
+        }
+    }
+
+    /// Save the current view under the typed name (replacing a preset with that name)
+    pub fn save_filter_preset(&mut self) {
+        let Some(name) = self.filter_preset_name_input.take() else {
+            return;
+        };
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            self.error_message = Some("Preset name cannot be empty".to_string());
+            return;
+        }
+
+        match self.db.save_filter_preset(&self.current_view_preset(name.clone())) {
+            Ok(id) => {
+                self.refresh_filter_presets();
+                if let Some(index) = self.filter_presets.iter().position(|p| p.id == id) {
+                    self.filter_presets_list_state = index;
+                }
+                self.status_message = Some(format!("Saved filter preset '{}'", name));
+            }
+            Err(e) => self.report_error("Failed to save filter preset", e),
+        }
+    }
+
+    /// Overwrite the selected preset with the current view
+    pub fn update_selected_filter_preset(&mut self) {
+        let Some(name) = self
+            .filter_presets
+            .get(self.filter_presets_list_state)
+            .map(|p| p.name.clone())
+        else {
+            return;
+        };
+        match self.db.save_filter_preset(&self.current_view_preset(name.clone())) {
+            Ok(_) => {
+                self.refresh_filter_presets();
+                self.status_message = Some(format!("Updated filter preset '{}'", name));
+            }
+            Err(e) => self.report_error("Failed to update filter preset", e),
+        }
+    }
+
+    /// Bind the selected preset to a number key, or unbind it with `None`
+    pub fn bind_selected_filter_preset(&mut self, slot: Option<u8>) {
+        let Some(preset) = self.filter_presets.get(self.filter_presets_list_state) else {
+            return;
+        };
+        let (id, name) = (preset.id, preset.name.clone());
+        match self.db.set_filter_preset_slot(id, slot) {
+            Ok(()) => {
+                self.refresh_filter_presets();
+                if let Some(index) = self.filter_presets.iter().position(|p| p.id == id) {
+                    self.filter_presets_list_state = index;
+                }
+                self.status_message = Some(match slot {
+                    Some(slot) => format!("Press {} to apply '{}'", slot, name),
+                    None => format!("'{}' is no longer bound to a key", name),
+                });
+            }
+            Err(e) => self.report_error("Failed to bind filter preset", e),
+        }
+    }
+
+    pub fn delete_selected_filter_preset(&mut self) {
+        let Some(preset) = self.filter_presets.get(self.filter_presets_list_state) else {
+            return;
+        };
+        let (id, name) = (preset.id, preset.name.clone());
+        match self.db.delete_filter_preset(id) {
+            Ok(()) => {
+                self.refresh_filter_presets();
+                self.status_message = Some(format!("Deleted filter preset '{}'", name));
+            }
+            Err(e) => self.report_error("Failed to delete filter preset", e),
+        }
+    }
+
+    pub fn apply_selected_filter_preset(&mut self) {
+        if let Some(preset) = self.filter_presets.get(self.filter_presets_list_state).cloned() {
+            self.close_filter_presets();
+            self.apply_filter_preset(&preset);
+        }
+    }
+
+    /// Apply the preset bound to a number key
+    pub fn apply_filter_preset_slot(&mut self, slot: u8) {
+        self.refresh_filter_presets();
+        match self.filter_presets.iter().find(|p| p.slot == Some(slot)).cloned() {
+            Some(preset) => self.apply_filter_preset(&preset),
+            None => self.status_message = Some(format!("No filter preset on key {} (press f to bind one)", slot)),
+        }
+    }
+
+    pub fn apply_filter_preset(&mut self, preset: &FilterPreset) {
+        self.active_saved_query = None;
+        let mut switched_project = None;
+        if let Some(project_id) = preset.project_id {
+            if self.selected_project.as_ref().map(|p| p.id) != Some(project_id) {
+                let project = match self.db.get_projects(None) {
+                    Ok(projects) => projects.into_iter().find(|p| p.id == project_id),
+                    Err(e) => {
+                        self.report_error("Failed to read projects", e);
+                        return;
+                    }
+                };
+                let Some(project) = project else {
+                    self.error_message = Some(format!(
+                        "The project of preset '{}' is not in the cache (press P to sync projects)",
+                        preset.name
+                    ));
+                    return;
+                };
+                self.selected_project = Some(project);
+                switched_project = Some(project_id);
+            }
+        }
+
+        self.issue_filter = preset.issue_filter.clone();
+        self.issue_sort_order = preset.sort_order;
+        self.group_issues_by_status = preset.group_by_status;
+        self.my_issues_filter = preset.my_issues;
+        if self.shows_issue_list() {
+            self.focused_pane = Pane::Issues;
+        }
+        self.issues_list_state = 0;
+        self.apply_filters();
+        self.status_message = Some(format!("Applied filter preset '{}'", preset.name));
+
+        // Like picking the project in the projects pane: highlight it and sync its issues
+        if let Some(project_id) = switched_project {
+            self.move_project_cursor_to(project_id);
+            if self.client.is_some() {
+                self.refresh_issues = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::db::Database;
+    use crate::redmine::{Project, RedmineClient};
+    use ratatui_image::picker::Picker;
+    use tempfile::TempDir;
+
+    fn project(id: u64, name: &str) -> Project {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "identifier": name.to_lowercase(),
+            "status": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_preset_selects_its_project() {
+        let temp = TempDir::new().unwrap();
+        let db = Database::new(temp.path().join("cache.db")).unwrap();
+        db.insert_projects(&[project(1, "Alpha"), project(2, "Beta"), project(3, "Gamma")])
+            .unwrap();
+        let mut app = App::with_database(Config::default(), db, Picker::from_fontsize((8, 16)));
+        app.client = Some(RedmineClient::new("http://127.0.0.1:1".to_string(), "key".to_string()));
+        app.apply_filters();
+        app.selected_project = app.get_project_at_cursor().cloned();
+        assert_eq!(app.selected_project.as_ref().map(|p| p.id), Some(1));
+
+        let preset = FilterPreset {
+            id: 1,
+            name: "Gamma bugs".to_string(),
+            slot: None,
+            project_id: Some(3),
+            issue_filter: "tracker:Bug".to_string(),
+            sort_order: IssueSortOrder::UpdatedDesc,
+            group_by_status: false,
+            my_issues: false,
+        };
+        app.apply_filter_preset(&preset);
+
+        // The projects pane follows the preset, and the new project's issues get synced
+        assert_eq!(app.selected_project.as_ref().map(|p| p.id), Some(3));
+        assert_eq!(app.get_project_at_cursor().map(|p| p.id), Some(3));
+        assert!(app.refresh_issues);
+
+        // Applying it again keeps the project and doesn't sync again
+        app.refresh_issues = false;
+        app.apply_filter_preset(&preset);
+        assert!(!app.refresh_issues);
+    }
+}
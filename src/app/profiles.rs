@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use super::state::{restore_current_user, ActiveTimer, App, InputMode, Screen};
+use crate::db::Database;
+use crate::redmine::RedmineClient;
+
+impl App {
+    /// Switch to another server profile: reopen its cache, rebuild the client and reload data
+    pub fn switch_profile(&mut self, name: &str) {
+        if name == self.config.profile_name() {
+            return;
+        }
+
+        let db = match Database::new(Database::profile_path(name)) {
+            Ok(db) => db,
+            Err(e) => {
+                self.error_message = Some(format!("Failed to open cache for profile '{}': {}", name, e));
+                return;
+            }
+        };
+        if let Err(e) = self.config.switch_profile(name) {
+            self.error_message = Some(e.to_string());
+            return;
+        }
+        if let Err(e) = self.config.save() {
+            self.error_message = Some(format!("Failed to save config: {}", e));
+        }
+        // Only the profile's own key is used, so a key meant for one server never reaches another
+        if let Err(e) = self.config.resolve_api_key() {
+            self.error_message = Some(format!("Failed to get API key: {:#}", e));
+        } else if self.config.api_key().is_empty() {
+            self.error_message = Some(format!(
+                "Profile '{}' has no API key. Set one in the configuration to connect",
+                name
+            ));
+        }
+
+        self.db = db;
+        self.client = if self.config.is_configured() {
+            Some(RedmineClient::new(
+                self.config.redmine_url().to_string(),
+                self.config.api_key().to_string(),
+            ))
+        } else {
+            None
+        };
+        self.reset_server_state();
+
+        // Show cached data right away, then refresh from the new server in the main loop
+        self.apply_filters();
+        if let Err(e) = self.load_users_from_cache() {
+            tracing::warn!("Failed to load users from cache: {}", e);
+        }
+        self.reload_profile_flag = self.client.is_some();
+        self.status_message = Some(format!("Switched to profile '{}'", name));
+    }
+
+    /// Forget everything that belongs to the previous server
+    fn reset_server_state(&mut self) {
+        self.filtered_projects.clear();
+        self.total_projects = 0;
+        self.filtered_issues.clear();
+        self.total_issues = 0;
+        self.current_issue = None;
+        self.show_issue_popup = false;
+        self.selected_project = None;
+        self.projects_list_state = 0;
+        self.issues_list_state = 0;
+        self.trackers.clear();
+        self.statuses.clear();
+        self.priorities.clear();
+        self.users.clear();
+        self.categories.clear();
+        self.tracker_custom_fields.clear();
+        self.tracker_custom_fields_cache = HashMap::new();
+        self.time_entry_activities.clear();
+        self.current_user_id = restore_current_user(&self.db);
+        self.my_issues_filter = false;
+        self.saved_queries.clear();
+        self.active_saved_query = None;
+        self.dashboard_issues = Default::default();
+        self.roadmap_project_id = None;
+        self.roadmap_versions.clear();
+        self.version_progress.clear();
+        self.roadmap_list_state = 0;
+        self.roadmap_issues.clear();
+        self.roadmap_issues_list_state = 0;
+        self.roadmap_focus_issues = false;
+        self.fetch_versions_project_id = None;
+        self.fetch_version_issues_id = None;
+        self.board_column = 0;
+        self.board_cards.clear();
+        self.move_board_card_to = None;
+        // The roadmap and board belong to the selected project, which is gone
+        if matches!(self.config_return_screen, Screen::Roadmap | Screen::Board) {
+            self.config_return_screen = Screen::Main;
+        }
+        self.selected_issues.clear();
+        self.issues_loading_in_progress = false;
+        self.issues_temp_buffer.clear();
+        self.issues_full_sync = false;
+        self.issues_sync_since = None;
+        self.issues_sync_started = None;
+        self.loading = false;
+        self.last_projects_sync = None;
+        self.attachment_images.clear();
+        self.image_dimensions.clear();
+        self.active_timer = ActiveTimer::restore(&self.db);
+        self.stopped_timer = None;
+        self.last_replay_attempt = None;
+        self.refresh_pending_operations();
+    }
+
+    /// Move the profile selector in the config screen
+    pub fn select_profile(&mut self, delta: isize) {
+        let count = self.config.profile_names().len();
+        self.profile_selector_index = (self.profile_selector_index as isize + delta).rem_euclid(count as isize) as usize;
+    }
+
+    /// Create a profile from the name typed in the config screen and switch to it
+    pub fn add_profile_from_input(&mut self) {
+        let name = self.profile_name_input.trim().to_string();
+        if let Err(e) = self.config.add_profile(&name) {
+            self.error_message = Some(e.to_string());
+            return;
+        }
+
+        self.input_mode = InputMode::Normal;
+        self.profile_name_input.clear();
+        self.profile_selector_index = self
+            .config
+            .profile_names()
+            .iter()
+            .position(|n| *n == name)
+            .unwrap_or(0);
+        self.switch_profile(&name);
+        self.config_focused_field = 1;
+        self.status_message = Some(format!("Created profile '{}', set its URL and API key", name));
+    }
+
+    /// Remove the profile selected in the config screen
+    pub fn remove_selected_profile(&mut self) {
+        let Some(name) = self.config.profile_names().get(self.profile_selector_index).cloned() else {
+            return;
+        };
+
+        match self.config.remove_profile(&name) {
+            Ok(()) => {
+                if let Err(e) = self.config.save() {
+                    self.error_message = Some(format!("Failed to save config: {}", e));
+                }
+                self.profile_selector_index = self
+                    .config
+                    .profile_names()
+                    .iter()
+                    .position(|n| n == self.config.profile_name())
+                    .unwrap_or(0);
+                self.status_message = Some(format!("Removed profile '{}'", name));
+            }
+            Err(e) => self.error_message = Some(e.to_string()),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Relations between issues, shown in the issue popup.
+//!
+//! Related issues are opened like the popup's other links (see `issue_links`).
+//! Relations are added through a small form and removed after confirmation,
+//! both online only.
+
+use anyhow::Result;
+
+use super::handlers::FormTarget;
+use super::issue_links::IssueLink;
+use super::state::{App, InputMode};
+use crate::issue_form::IssueForm;
+use crate::redmine::{CreateRelation, IssueRelation, RELATION_TYPES};
+
+impl App {
+    /// Relation highlighted in the popup, if the highlight is on a relation
+    pub fn selected_relation(&self) -> Option<&IssueRelation> {
+        let Some(IssueLink::Relation { relation_id, .. }) = self.selected_issue_link() else {
+            return None;
+        };
+        self.current_issue
+            .as_ref()?
+            .relations
+            .iter()
+            .find(|relation| relation.id == relation_id)
+    }
+
+    pub fn open_relation_form(&mut self, issue_id: u64) {
+        self.relation_form = Some(IssueForm::relation_form());
+        self.relation_issue_id = Some(issue_id);
+        self.previous_input_mode = self.input_mode;
+        self.input_mode = InputMode::AddingRelation;
+    }
+
+    pub fn cancel_relation_form(&mut self) {
+        self.relation_form = None;
+        self.relation_issue_id = None;
+        self.input_mode = self.previous_input_mode;
+    }
+
+    /// Validate the relation form and hand it to the main loop
+    pub fn submit_relation_form(&mut self) {
+        let Some(form) = &self.relation_form else {
+            self.error_message = Some("Form not initialized".to_string());
+            return;
+        };
+        if let Err(e) = form.validate().and_then(|()| relation_from_form(form).map(|_| ())) {
+            self.error_message = Some(e);
+            return;
+        }
+
+        self.submit_relation_flag = true;
+        self.status_message = Some("Adding relation...".to_string());
+        self.input_mode = self.previous_input_mode;
+    }
+
+    /// Create the relation described by the form and reload the issue
+    pub async fn submit_relation(&mut self) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+        let (Some(form), Some(issue_id)) = (&self.relation_form, self.relation_issue_id) else {
+            return Ok(());
+        };
+        let relation = relation_from_form(form).map_err(anyhow::Error::msg)?;
+        let other_id = relation.issue_to_id;
+
+        match client.create_relation(issue_id, relation).await {
+            Ok(_) => {
+                self.relation_form = None;
+                self.relation_issue_id = None;
+                self.refresh_after_relation_change(issue_id).await;
+                self.status_message = Some(format!("Related #{} to #{}", issue_id, other_id));
+            }
+            Err(e) if !e.validation_errors().is_empty() => {
+                self.show_form_errors(FormTarget::Relation, e.validation_errors());
+            }
+            Err(e) => self.report_error("Failed to add relation", e),
+        }
+        Ok(())
+    }
+
+    /// Ask for confirmation before deleting the highlighted relation
+    pub fn request_delete_selected_relation(&mut self) {
+        if self.selected_relation().is_some() {
+            self.confirm_delete_relation = true;
+        }
+    }
+
+    pub async fn delete_relation(&mut self, relation_id: u64) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+        client.delete_relation(relation_id).await?;
+
+        if let Some(issue_id) = self.current_issue.as_ref().map(|i| i.id) {
+            self.refresh_after_relation_change(issue_id).await;
+        }
+        self.status_message = Some("Relation removed".to_string());
+        Ok(())
+    }
+
+    /// Reload the popup's issue so its relations (and the cache) match the server
+    async fn refresh_after_relation_change(&mut self, issue_id: u64) {
+        if self.current_issue.as_ref().is_some_and(|i| i.id == issue_id) {
+            if let Err(e) = self.load_issue_detail(issue_id).await {
+                tracing::warn!("Failed to reload issue #{}: {}", issue_id, e);
+            }
+        }
+    }
+}
+
+/// Read the relation form into an API request
+fn relation_from_form(form: &IssueForm) -> Result<CreateRelation, String> {
+    let relation_type = form
+        .get_value("relation_type")
+        .and_then(|v| v.as_option_id())
+        .and_then(|i| RELATION_TYPES.get(i as usize))
+        .map(|(key, _)| key.to_string())
+        .ok_or("Relation is required")?;
+
+    let issue_to_id = form
+        .get_value("issue_to_id")
+        .and_then(|v| v.as_text())
+        .map(|s| s.trim().trim_start_matches('#'))
+        .and_then(|s| s.parse::<u64>().ok())
+        .ok_or("Issue must be an issue number")?;
+
+    let delay = match form.get_value("delay").and_then(|v| v.as_text()).map(str::trim) {
+        None | Some("") => None,
+        Some(text) => Some(
+            text.parse::<i32>()
+                .map_err(|_| "Delay must be a whole number of days")?,
+        ),
+    };
+
+    Ok(CreateRelation {
+        issue_to_id,
+        relation_type,
+        delay,
+    })
+}
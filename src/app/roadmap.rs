@@ -0,0 +1,198 @@
+//! Roadmap of the selected project's versions.
+//!
+//! Versions are cached per project and their completion is computed from the
+//! cached issues targeting them, so the roadmap works offline but is only as
+//! current as the last sync. Drilling into a version lists its issues and asks
+//! the server for all of them, closed ones included.
+
+use anyhow::Result;
+
+use super::state::{App, Screen};
+use crate::issue_query::{Comparison, FilterQuery, Term, VersionValue};
+use crate::redmine::{Issue, IssueQuery, Version};
+
+/// Issues requested per page when fetching a version's issues
+const VERSION_ISSUES_PAGE_SIZE: u32 = 100;
+
+impl App {
+    /// Switch to the roadmap of the selected project and refresh its versions from the server
+    pub fn show_roadmap(&mut self) {
+        let Some(project_id) = self.selected_project.as_ref().map(|p| p.id) else {
+            self.error_message = Some("Please select a project first".to_string());
+            return;
+        };
+        if self.roadmap_project_id != Some(project_id) {
+            self.roadmap_project_id = Some(project_id);
+            self.roadmap_list_state = 0;
+            self.roadmap_issues_list_state = 0;
+        }
+        self.screen = Screen::Roadmap;
+        self.roadmap_focus_issues = false;
+        self.reload_roadmap();
+        if self.client.is_some() {
+            self.fetch_versions_project_id = Some(project_id);
+        }
+    }
+
+    /// Re-read the versions, their progress and the highlighted version's issues from the cache
+    pub fn reload_roadmap(&mut self) {
+        let Some(project_id) = self.roadmap_project_id else {
+            return;
+        };
+        let context = self.issue_query_context();
+        match self.db.get_project_versions(project_id) {
+            Ok(versions) => self.roadmap_versions = versions,
+            Err(e) => {
+                self.report_error("Failed to read versions", e);
+                return;
+            }
+        }
+        match self.db.get_version_progress(&context) {
+            Ok(progress) => self.version_progress = progress,
+            Err(e) => self.report_error("Failed to read version progress", e),
+        }
+        self.roadmap_list_state = self
+            .roadmap_list_state
+            .min(self.roadmap_versions.len().saturating_sub(1));
+        self.reload_roadmap_issues();
+    }
+
+    /// Re-read the issues of the highlighted version
+    fn reload_roadmap_issues(&mut self) {
+        let Some(version_id) = self.selected_roadmap_version().map(|v| v.id) else {
+            self.roadmap_issues.clear();
+            return;
+        };
+        let query = FilterQuery {
+            terms: vec![Term::Version(Comparison::Eq, VersionValue::Id(version_id))],
+        };
+        match self
+            .db
+            .get_issues(None, self.roadmap_sort_order, &query, &self.issue_query_context())
+        {
+            Ok(issues) => self.roadmap_issues = issues,
+            Err(e) => self.report_error("Failed to read version issues", e),
+        }
+        self.roadmap_issues_list_state = self
+            .roadmap_issues_list_state
+            .min(self.roadmap_issues.len().saturating_sub(1));
+    }
+
+    /// Download the versions a project can use and cache them
+    pub async fn fetch_project_versions(&mut self, project_id: u64) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+        let versions = match client.get_project_versions(project_id).await {
+            Ok(response) => response.versions,
+            Err(e) if e.is_offline() => {
+                if self.screen == Screen::Roadmap {
+                    self.status_message = Some("Offline: showing cached versions".to_string());
+                }
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+        self.db.replace_project_versions(project_id, &versions)?;
+
+        if self.screen == Screen::Roadmap && self.roadmap_project_id == Some(project_id) {
+            self.reload_roadmap();
+        }
+        Ok(())
+    }
+
+    /// Fetch every issue of the project targeting a version, open or closed, into the cache
+    pub async fn fetch_version_issues(&mut self, version_id: u64) -> Result<()> {
+        let (Some(client), Some(project_id)) = (self.client.clone(), self.roadmap_project_id) else {
+            return Ok(());
+        };
+        self.loading = true;
+
+        let query = IssueQuery {
+            project: Some(project_id.to_string()),
+            status: Some("*".to_string()),
+            fixed_version: Some(version_id),
+            ..Default::default()
+        };
+        let mut issues: Vec<Issue> = Vec::new();
+        let fetched = loop {
+            match client
+                .query_issues(&query, VERSION_ISSUES_PAGE_SIZE, issues.len() as u32)
+                .await
+            {
+                Ok(response) => {
+                    let count = response.issues.len();
+                    issues.extend(response.issues);
+                    if count < VERSION_ISSUES_PAGE_SIZE as usize
+                        || response.total_count.is_some_and(|total| issues.len() >= total as usize)
+                    {
+                        break Ok(());
+                    }
+                }
+                Err(e) => break Err(e),
+            }
+        };
+        self.loading = false;
+
+        match fetched {
+            Ok(()) => {
+                self.db.insert_issues(&issues)?;
+                self.reapply_pending_operations();
+            }
+            Err(e) if e.is_offline() => {
+                self.status_message = Some("Offline: showing cached issues".to_string());
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        if self.screen == Screen::Roadmap {
+            self.reload_roadmap();
+        }
+        Ok(())
+    }
+
+    pub fn selected_roadmap_version(&self) -> Option<&Version> {
+        self.roadmap_versions.get(self.roadmap_list_state)
+    }
+
+    pub fn selected_roadmap_issue(&self) -> Option<&Issue> {
+        self.roadmap_issues.get(self.roadmap_issues_list_state)
+    }
+
+    /// Move the cursor in the focused pane, clamped to its entries
+    pub fn move_roadmap_cursor(&mut self, delta: isize) {
+        if self.roadmap_focus_issues {
+            let last = self.roadmap_issues.len().saturating_sub(1) as isize;
+            self.roadmap_issues_list_state = (self.roadmap_issues_list_state as isize + delta).clamp(0, last) as usize;
+        } else {
+            let last = self.roadmap_versions.len().saturating_sub(1) as isize;
+            let index = (self.roadmap_list_state as isize + delta).clamp(0, last) as usize;
+            if index != self.roadmap_list_state {
+                self.roadmap_list_state = index;
+                self.roadmap_issues_list_state = 0;
+                self.reload_roadmap_issues();
+            }
+        }
+    }
+
+    /// Focus the highlighted version's issues and fetch all of them from the server
+    pub fn open_roadmap_version(&mut self) {
+        let Some(version_id) = self.selected_roadmap_version().map(|v| v.id) else {
+            return;
+        };
+        self.roadmap_focus_issues = true;
+        if self.client.is_some() {
+            self.fetch_version_issues_id = Some(version_id);
+        }
+    }
+
+    pub fn cycle_roadmap_sort(&mut self) {
+        self.roadmap_sort_order = self.roadmap_sort_order.next();
+        self.roadmap_issues_list_state = 0;
+        self.reload_roadmap_issues();
+        self.status_message = Some(format!(
+            "Version issues sorted by: {}",
+            self.roadmap_sort_order.as_str()
+        ));
+    }
+}
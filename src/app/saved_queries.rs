@@ -0,0 +1,191 @@
+//! Issue queries saved in the Redmine web UI, listed under the projects.
+//!
+//! The list of queries and the issue ids each run returned are cached, so the
+//! issue list shows a query's last results (with local sorting and grouping)
+//! while offline and refreshes them whenever the query runs again.
+
+use anyhow::Result;
+
+use super::state::{App, Pane};
+use crate::redmine::{Issue, IssueQuery, SavedQuery};
+
+/// Queries or issues requested per page
+const SAVED_QUERY_PAGE_SIZE: u32 = 100;
+
+impl App {
+    /// Focus the saved queries under the projects and ask the main loop to refresh them
+    pub fn show_saved_queries(&mut self) {
+        self.focused_pane = Pane::Queries;
+        self.issues_pane_maximized = false;
+        self.refresh_saved_queries();
+        self.fetch_saved_queries_flag = self.client.is_some();
+    }
+
+    /// Queries listed under the projects: global ones and those of the selected project
+    pub fn visible_saved_queries(&self) -> Vec<&SavedQuery> {
+        let project_id = self.selected_project.as_ref().map(|p| p.id);
+        self.saved_queries
+            .iter()
+            .filter(|query| query.project_id.is_none() || query.project_id == project_id)
+            .collect()
+    }
+
+    /// Rows the saved queries take under the projects, 0 while there are none to list
+    pub fn saved_queries_pane_height(&self, column_height: u16) -> u16 {
+        let count = self.visible_saved_queries().len();
+        if count == 0 && self.focused_pane != Pane::Queries {
+            return 0;
+        }
+        // One row per query between the borders, up to a third of the column
+        (count.max(1) as u16 + 2).min((column_height / 3).max(3))
+    }
+
+    pub fn refresh_saved_queries(&mut self) {
+        match self.db.get_saved_queries() {
+            Ok(queries) => self.saved_queries = queries,
+            Err(e) => self.report_error("Failed to read saved queries", e),
+        }
+        self.saved_queries_list_state = self
+            .saved_queries_list_state
+            .min(self.visible_saved_queries().len().saturating_sub(1));
+    }
+
+    /// Download the saved queries the user can see and cache them
+    pub async fn fetch_saved_queries(&mut self) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+
+        let mut queries = Vec::new();
+        loop {
+            let response = client.get_queries(SAVED_QUERY_PAGE_SIZE, queries.len() as u32).await?;
+            let fetched = response.queries.len();
+            queries.extend(response.queries);
+            if fetched < SAVED_QUERY_PAGE_SIZE as usize
+                || response
+                    .total_count
+                    .is_some_and(|total| queries.len() >= total as usize)
+            {
+                break;
+            }
+        }
+
+        self.db.replace_saved_queries(&queries)?;
+        self.refresh_saved_queries();
+        Ok(())
+    }
+
+    /// Move the cursor in the saved queries pane, clamped to the listed queries
+    pub fn move_saved_query_cursor(&mut self, delta: isize) {
+        let last = self.visible_saved_queries().len().saturating_sub(1) as isize;
+        self.saved_queries_list_state = (self.saved_queries_list_state as isize + delta).clamp(0, last) as usize;
+    }
+
+    /// Run the query under the cursor in the saved queries pane
+    pub fn run_selected_saved_query(&mut self) {
+        if let Some(query) = self
+            .visible_saved_queries()
+            .get(self.saved_queries_list_state)
+            .copied()
+            .cloned()
+        {
+            self.status_message = Some(format!("Running query '{}'...", query.name));
+            self.run_saved_query_flag = Some(query);
+        }
+    }
+
+    /// Fetch every issue the saved query returns and show them in the issue list. When the server
+    /// can't be reached, the results of the query's last run are shown instead.
+    pub async fn run_saved_query(&mut self, query: SavedQuery) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            return Ok(());
+        };
+        self.loading = true;
+
+        let request = IssueQuery {
+            project: query.project_id.map(|id| id.to_string()),
+            query_id: Some(query.id),
+            ..Default::default()
+        };
+        let mut issues: Vec<Issue> = Vec::new();
+        let fetched = loop {
+            match client
+                .query_issues(&request, SAVED_QUERY_PAGE_SIZE, issues.len() as u32)
+                .await
+            {
+                Ok(response) => {
+                    let count = response.issues.len();
+                    issues.extend(response.issues);
+                    self.status_message = Some(format!(
+                        "Running query '{}'... {}/{}",
+                        query.name,
+                        issues.len(),
+                        response.total_count.unwrap_or(0)
+                    ));
+                    if count < SAVED_QUERY_PAGE_SIZE as usize
+                        || response.total_count.is_some_and(|total| issues.len() >= total as usize)
+                    {
+                        break Ok(());
+                    }
+                }
+                Err(e) => break Err(e),
+            }
+        };
+        self.loading = false;
+
+        match fetched {
+            Ok(()) => {
+                let ids: Vec<u64> = issues.iter().map(|issue| issue.id).collect();
+                self.db.insert_issues(&issues)?;
+                self.reapply_pending_operations();
+                self.db.set_saved_query_issues(query.id, &ids)?;
+                self.status_message = Some(format!("Query '{}': {} issues", query.name, ids.len()));
+            }
+            Err(e) if e.is_offline() && self.db.has_saved_query_issues(query.id)? => {
+                self.status_message = Some(format!("Offline: showing the last results of '{}'", query.name));
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        self.show_saved_query_results(query);
+        Ok(())
+    }
+
+    /// Switch the issue list to a saved query's cached results
+    fn show_saved_query_results(&mut self, query: SavedQuery) {
+        // A project query also selects its project so new issues land there
+        if let Some(project_id) = query.project_id {
+            if self.selected_project.as_ref().map(|p| p.id) != Some(project_id) {
+                let project = self
+                    .db
+                    .get_projects(None)
+                    .ok()
+                    .and_then(|projects| projects.into_iter().find(|p| p.id == project_id));
+                if project.is_some() {
+                    self.selected_project = project;
+                }
+            }
+        }
+
+        self.active_saved_query = Some(query);
+        self.focused_pane = Pane::Issues;
+        self.issues_list_state = 0;
+        self.apply_filters();
+    }
+
+    /// Go back to the selected project's issues
+    pub fn clear_saved_query(&mut self) {
+        if self.active_saved_query.take().is_some() {
+            if self.selected_project.is_none() && self.focused_pane == Pane::Issues {
+                self.focused_pane = Pane::Projects;
+            }
+            self.issues_list_state = 0;
+            self.apply_filters();
+        }
+    }
+
+    /// Whether the issues pane has something to list
+    pub fn shows_issue_list(&self) -> bool {
+        self.selected_project.is_some() || self.active_saved_query.is_some()
+    }
+}
@@ -0,0 +1,46 @@
+//! Global full-text search over every cached project's issues.
+
+use super::state::{App, InputMode};
+
+/// Most results the search view lists
+const GLOBAL_SEARCH_LIMIT: usize = 100;
+
+impl App {
+    /// Open the global search view, keeping the previous query and its results
+    pub fn show_global_search(&mut self) {
+        self.input_mode = InputMode::GlobalSearch;
+        self.run_global_search();
+    }
+
+    pub fn close_global_search(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Search the cache for the current query (fast enough to run on every keystroke)
+    pub fn run_global_search(&mut self) {
+        match self.db.search_issues(&self.global_search_query, GLOBAL_SEARCH_LIMIT) {
+            Ok(results) => self.global_search_results = results,
+            Err(e) => {
+                self.global_search_results.clear();
+                self.report_error("Search failed", e);
+            }
+        }
+        self.global_search_list_state = self
+            .global_search_list_state
+            .min(self.global_search_results.len().saturating_sub(1));
+    }
+
+    /// Open the selected result in the issue popup
+    pub fn open_global_search_result(&mut self) {
+        let Some(hit) = self.global_search_results.get(self.global_search_list_state) else {
+            return;
+        };
+
+        self.current_issue = Some(hit.issue.clone());
+        self.loading_issue = true;
+        self.popup_scroll = 0;
+        self.attachment_page = 0;
+        self.show_issue_popup = true;
+        self.input_mode = InputMode::Normal;
+    }
+}
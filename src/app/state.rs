@@ -1,25 +1,43 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use ratatui_image::picker::Picker;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
+use super::conflicts::{ConflictResolution, IssueConflict};
+use super::dashboard::DashboardSection;
+use super::offline_queue::PendingOperation;
+use super::presets::FilterPreset;
 use crate::config::Config;
-use crate::db::Database;
+use crate::db::{Database, SearchHit, VersionProgress};
+use crate::error::RedmineError;
 use crate::issue_form::IssueForm;
+use crate::issue_query::QueryError;
 use crate::redmine::{
-    Issue, IssueCategory, IssueCustomField, IssueStatus, Priority, Project, RedmineClient, Tracker, User,
+    Issue, IssueCategory, IssueCustomField, IssueStatus, Priority, Project, RedmineClient, SavedQuery,
+    TimeEntry, TimeEntryActivity, Tracker, User, Version,
 };
 use crate::theme::Theme;
 
+/// Metadata key the running timer is stored under
+pub(crate) const ACTIVE_TIMER_KEY: &str = "active_timer";
+
+/// Metadata key holding the id of the user the API key belongs to, so "me" works offline
+pub(crate) const CURRENT_USER_KEY: &str = "current_user_id";
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Screen {
-    Main, // Two-pane view with projects and issues
+    Main,      // Two-pane view with projects and issues
+    Dashboard, // My work across all projects (landing screen)
+    Roadmap,   // Versions of the selected project
+    Board,     // Issue list as one column per status
     Config,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Pane {
     Projects,
+    Queries, // Saved queries, listed under the projects
     Issues,
 }
 
@@ -30,12 +48,20 @@ pub enum InputMode {
     Editing,
     EditingUrl,
     EditingApiKey,
+    EditingProfileName,
     Searching,
     CreatingIssue,
     ReplyingToIssue,
     BulkEditing,
     AddingAttachment,
     ManagingAttachments,
+    LoggingTime,
+    ManagingTimeEntries,
+    ManagingPendingOperations,
+    ResolvingConflict,
+    GlobalSearch,
+    ManagingFilterPresets,
+    AddingRelation,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -67,6 +93,61 @@ impl IssueSortOrder {
             IssueSortOrder::PriorityDesc => "Priority ↓",
         }
     }
+
+    /// Stable name used when storing the sort order
+    pub fn key(&self) -> &'static str {
+        match self {
+            IssueSortOrder::UpdatedDesc => "updated_desc",
+            IssueSortOrder::StatusAsc => "status_asc",
+            IssueSortOrder::StatusDesc => "status_desc",
+            IssueSortOrder::PriorityAsc => "priority_asc",
+            IssueSortOrder::PriorityDesc => "priority_desc",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "updated_desc" => Some(IssueSortOrder::UpdatedDesc),
+            "status_asc" => Some(IssueSortOrder::StatusAsc),
+            "status_desc" => Some(IssueSortOrder::StatusDesc),
+            "priority_asc" => Some(IssueSortOrder::PriorityAsc),
+            "priority_desc" => Some(IssueSortOrder::PriorityDesc),
+            _ => None,
+        }
+    }
+}
+
+/// Running stopwatch booking time to an issue (persisted in the metadata table)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveTimer {
+    pub issue_id: u64,
+    pub subject: String,
+    pub started_at: DateTime<Utc>,
+}
+
+/// User id stored by the last metadata load, if any
+pub(crate) fn restore_current_user(db: &Database) -> Option<u64> {
+    db.get_metadata(CURRENT_USER_KEY).ok().flatten().and_then(|id| id.parse().ok())
+}
+
+impl ActiveTimer {
+    /// Timer left running in a previous session, if any
+    pub fn restore(db: &Database) -> Option<Self> {
+        db.get_metadata(ACTIVE_TIMER_KEY)
+            .ok()
+            .flatten()
+            .and_then(|json| serde_json::from_str(&json).ok())
+    }
+
+    pub fn elapsed(&self) -> chrono::Duration {
+        Utc::now().signed_duration_since(self.started_at)
+    }
+
+    /// Elapsed time in hours, rounded to the nearest quarter hour (never less than 0.25h)
+    pub fn rounded_hours(&self) -> f32 {
+        let quarters = (self.elapsed().num_seconds().max(0) as f32 / 900.0).round();
+        quarters.max(1.0) * 0.25
+    }
 }
 
 pub struct App {
@@ -112,6 +193,8 @@ pub struct App {
     pub status_message: Option<String>,
     pub status_message_time: Option<std::time::Instant>,
     pub error_message: Option<String>,
+    pub error_detail: Option<(String, String)>, // (error_message it belongs to, technical detail)
+    pub show_error_detail: bool,                // Expand the technical detail in the error popup
     pub refresh_projects: bool,
     pub refresh_issues: bool,
     pub create_new_issue_flag: bool,
@@ -126,9 +209,13 @@ pub struct App {
     pub issues_loaded_count: usize,
     pub issues_total_count: usize,
     pub issues_temp_buffer: Vec<crate::redmine::Issue>,
+    pub issues_full_sync: bool,                    // Next sync re-downloads every issue of the project
+    pub issues_sync_since: Option<DateTime<Utc>>,  // `updated_on` lower bound of the running sync
+    pub issues_sync_started: Option<DateTime<Utc>>, // Recorded as the project's sync time when done
 
     // Search/Filter
     pub search_query: String,
+    pub search_error: Option<QueryError>, // Problem with the typed or applied issue filter
     pub project_filter: String,
     pub issue_filter: String,
     pub issue_sort_order: IssueSortOrder,
@@ -138,6 +225,8 @@ pub struct App {
     // Status grouping/folding
     pub status_groups_collapsed: HashMap<String, bool>, // Track collapsed status groups
     pub group_issues_by_status: bool,                   // Enable/disable status grouping
+    pub issue_tree_view: bool,                          // List subtasks indented under their parent
+    pub issue_depths: HashMap<u64, usize>,              // Tree depth of each listed issue in tree view
 
     // Project tree folding
     pub projects_collapsed: HashMap<u64, bool>, // Track collapsed projects (project_id -> collapsed)
@@ -154,15 +243,24 @@ pub struct App {
     pub api_key_input: String,
     pub url_input: String,
     pub attachment_input: String,
+    pub profile_name_input: String,
 
     // Theme selection
     pub theme_selector_index: usize, // Selected theme in config screen
-    pub config_focused_field: usize, // 0=URL, 1=API Key, 2=Theme, 3=Exclude Subprojects
+    pub config_focused_field: usize, // 0=Profile, 1=URL, 2=API Key, 3=Theme, 4=Exclude Subprojects
+
+    // Server profiles
+    pub profile_selector_index: usize, // Selected profile in config screen
+    pub reload_profile_flag: bool,     // Reload server data after switching profile
 
     // Issue creation form (data-driven)
     pub show_create_issue_form: bool,
     pub create_issue_form: Option<IssueForm>,
+    pub create_issue_project_id: Option<u64>, // Project the create form files the issue in
     pub update_issue_form: Option<IssueForm>,
+    pub update_form_opened_at: Option<DateTime<Utc>>, // Issue's updated_on when the update form opened
+    pub issue_conflict: Option<IssueConflict>,         // Update waiting on the user after a conflict
+    pub resolve_conflict_flag: Option<ConflictResolution>,
 
     // File attachments for create/update
     pub pending_attachments: Vec<String>, // File paths to upload
@@ -170,6 +268,86 @@ pub struct App {
     pub attachment_list_state: usize, // Selected index in attachment list
     pub file_explorer: Option<ratatui_explorer::FileExplorer>, // File explorer widget
 
+    // Time tracking
+    pub time_entry_activities: Vec<TimeEntryActivity>,
+    pub time_entry_form: Option<IssueForm>,
+    pub time_entry_issue_id: Option<u64>,    // Issue the time entry form/list belongs to
+    pub editing_time_entry_id: Option<u64>,  // Set when the form edits an existing entry
+    pub submit_time_entry_flag: bool,
+    pub show_time_entries_popup: bool,
+    pub time_entries: Vec<TimeEntry>,        // Entries of time_entry_issue_id, newest first
+    pub time_entries_list_state: usize,
+    pub load_time_entries_flag: bool,
+    pub confirm_delete_time_entry: bool,     // Waiting for 'y' before deleting the selected entry
+    pub delete_time_entry_id: Option<u64>,   // Entry to delete in main loop
+    pub active_timer: Option<ActiveTimer>,
+    pub stopped_timer: Option<ActiveTimer>,  // Timer stopped for the open form, saved until its entry is logged
+
+    // Linked issues (parents, relations, subtasks) in the issue popup
+    pub selected_issue_link: usize,           // Index into issue_links()
+    pub issue_history: Vec<u64>,              // Issues the popup navigated away from, newest last
+    pub load_issue_id: Option<u64>,           // Issue to fetch into the popup in main loop
+    pub collapsed_subtasks: HashSet<u64>,     // Subtasks whose own subtasks are folded away
+    pub relation_form: Option<IssueForm>,
+    pub relation_issue_id: Option<u64>,       // Issue the relation form adds to
+    pub submit_relation_flag: bool,
+    pub confirm_delete_relation: bool,        // Waiting for 'y' before deleting the selected relation
+    pub delete_relation_id: Option<u64>,      // Relation to delete in main loop
+    pub toggle_watch_issue_id: Option<u64>,   // Issue to watch or unwatch in main loop
+
+    // Offline write queue
+    pub pending_operations_count: usize,
+    pub pending_operations: Vec<PendingOperation>, // Queued writes in replay order
+    pub pending_issue_ids: HashSet<u64>,           // Issues with queued writes
+    pub pending_operations_list_state: usize,
+    pub replay_pending_flag: bool,
+    pub last_replay_attempt: Option<DateTime<Utc>>,
+    pub confirm_drop_pending_operation: bool, // Waiting for 'y' before dropping the selected operation
+    pub drop_pending_operation_id: Option<u64>, // Operation to drop in main loop
+
+    // Full-text search across all cached projects
+    pub global_search_query: String,
+    pub global_search_results: Vec<SearchHit>,
+    pub global_search_list_state: usize,
+
+    // Saved filter presets
+    pub filter_presets: Vec<FilterPreset>,
+    pub filter_presets_list_state: usize,
+    pub filter_preset_name_input: Option<String>, // Name being typed when saving the current view
+    pub confirm_delete_filter_preset: bool,       // Waiting for 'y' before deleting the selected preset
+
+    // "My Work" dashboard
+    pub dashboard_section: DashboardSection,
+    pub dashboard_issues: [Vec<Issue>; 4], // Indexed by DashboardSection::index()
+    pub dashboard_list_states: [usize; 4],
+    pub dashboard_sort_orders: [IssueSortOrder; 4],
+    pub refresh_dashboard_flag: bool,
+    pub config_return_screen: Screen, // Screen ESC goes back to from the config screen
+
+    // Roadmap of the selected project's versions
+    pub roadmap_project_id: Option<u64>,
+    pub roadmap_versions: Vec<Version>,
+    pub version_progress: HashMap<u64, VersionProgress>, // By version id, from cached issues
+    pub roadmap_list_state: usize,
+    pub roadmap_issues: Vec<Issue>, // Issues of the highlighted version
+    pub roadmap_issues_list_state: usize,
+    pub roadmap_focus_issues: bool,
+    pub roadmap_sort_order: IssueSortOrder,
+    pub fetch_versions_project_id: Option<u64>, // Project whose versions to fetch in main loop
+    pub fetch_version_issues_id: Option<u64>,   // Version whose issues to fetch in main loop
+
+    // Kanban board of the issue list
+    pub board_column: usize,
+    pub board_cards: HashMap<u64, usize>, // Highlighted card per column, by status id
+    pub move_board_card_to: Option<(u64, u64)>, // (issue id, status id) to update in main loop
+
+    // Redmine saved queries
+    pub saved_queries: Vec<SavedQuery>,
+    pub saved_queries_list_state: usize,
+    pub active_saved_query: Option<SavedQuery>, // Issue list shows this query's results instead of the project's
+    pub fetch_saved_queries_flag: bool,
+    pub run_saved_query_flag: Option<SavedQuery>, // Query to run in main loop
+
     // Last sync timestamps
     pub last_projects_sync: Option<DateTime<Utc>>,
 
@@ -180,8 +358,19 @@ pub struct App {
 }
 
 impl App {
-    pub fn new() -> Result<Self> {
-        let config = Config::load().unwrap_or_default();
+    pub fn new(config: Config) -> Result<Self> {
+        // Each profile has its own cache database
+        let db = Database::new(Database::profile_path(config.profile_name()))?;
+
+        // Initialize image picker - use from_query_stdio() to detect terminal capabilities
+        // Fallback to a default font size if detection fails
+        let image_picker = Picker::from_query_stdio().unwrap_or_else(|_| Picker::from_fontsize((8, 16)));
+
+        Ok(Self::with_database(config, db, image_picker))
+    }
+
+    /// App state on an already opened cache database, without touching the terminal
+    pub fn with_database(config: Config, db: Database, image_picker: Picker) -> Self {
         let theme = Theme::from_name(config.theme);
 
         // Find index of current theme for selector
@@ -190,26 +379,34 @@ impl App {
             .position(|&t| t == config.theme)
             .unwrap_or(0);
 
+        let profile_selector_index = config
+            .profile_names()
+            .iter()
+            .position(|name| name == config.profile_name())
+            .unwrap_or(0);
+
         let client = if config.is_configured() {
-            Some(RedmineClient::new(config.redmine_url.clone(), config.api_key.clone()))
+            Some(RedmineClient::new(
+                config.redmine_url().to_string(),
+                config.api_key().to_string(),
+            ))
         } else {
             None
         };
 
-        // Initialize database
-        let db_path = directories::ProjectDirs::from("com", "minecli", "minecli")
-            .map(|dirs| dirs.data_dir().join("cache.db"))
-            .unwrap_or_else(|| std::path::PathBuf::from("redmine-cache.db"));
-        let db = Database::new(db_path)?;
+        // Restore a timer left running in a previous session
+        let active_timer = ActiveTimer::restore(&db);
 
-        // Initialize image picker - use from_query_stdio() to detect terminal capabilities
-        // Fallback to a default font size if detection fails
-        let image_picker = Picker::from_query_stdio().unwrap_or_else(|_| Picker::from_fontsize((8, 16)));
+        // Known from a previous session so "my issues" work before the server answers
+        let current_user_id = restore_current_user(&db);
 
-        Ok(Self {
+        // Writes queued while offline in a previous session are replayed on startup
+        let pending_operations = db.get_pending_operations().unwrap_or_default();
+
+        Self {
             running: true,
             screen: if config.is_configured() {
-                Screen::Main
+                Screen::Dashboard
             } else {
                 Screen::Config
             },
@@ -249,6 +446,8 @@ impl App {
             status_message: None,
             status_message_time: None,
             error_message: None,
+            error_detail: None,
+            show_error_detail: false,
             refresh_projects: false,
             refresh_issues: false,
             create_new_issue_flag: false,
@@ -258,13 +457,16 @@ impl App {
             comment_issue_id: None,
             load_custom_fields_flag: false,
             search_query: String::new(),
+            search_error: None,
             project_filter: String::new(),
             issue_filter: String::new(),
             issue_sort_order: IssueSortOrder::UpdatedDesc,
             my_issues_filter: false,
-            current_user_id: None,
+            current_user_id,
             status_groups_collapsed: HashMap::new(),
             group_issues_by_status: false, // Disabled by default
+            issue_tree_view: false,
+            issue_depths: HashMap::new(),
             projects_collapsed: HashMap::new(),
             selected_issues: HashSet::new(),
             bulk_operation_mode: false,
@@ -275,15 +477,84 @@ impl App {
             api_key_input: String::new(),
             url_input: String::new(),
             attachment_input: String::new(),
+            profile_name_input: String::new(),
             theme_selector_index,
-            config_focused_field: 1, // Start focused on API Key (most important)
+            config_focused_field: 2, // Start focused on API Key (most important)
+            profile_selector_index,
+            reload_profile_flag: false,
             show_create_issue_form: false,
             create_issue_form: None,
+            create_issue_project_id: None,
             update_issue_form: None,
+            update_form_opened_at: None,
+            issue_conflict: None,
+            resolve_conflict_flag: None,
             pending_attachments: Vec::new(),
             previous_input_mode: InputMode::Normal,
             attachment_list_state: 0,
             file_explorer: None,
+            time_entry_activities: Vec::new(),
+            time_entry_form: None,
+            time_entry_issue_id: None,
+            editing_time_entry_id: None,
+            submit_time_entry_flag: false,
+            show_time_entries_popup: false,
+            time_entries: Vec::new(),
+            time_entries_list_state: 0,
+            load_time_entries_flag: false,
+            confirm_delete_time_entry: false,
+            delete_time_entry_id: None,
+            active_timer,
+            stopped_timer: None,
+            selected_issue_link: 0,
+            issue_history: Vec::new(),
+            load_issue_id: None,
+            collapsed_subtasks: HashSet::new(),
+            relation_form: None,
+            relation_issue_id: None,
+            submit_relation_flag: false,
+            confirm_delete_relation: false,
+            delete_relation_id: None,
+            toggle_watch_issue_id: None,
+            pending_operations_count: pending_operations.len(),
+            pending_issue_ids: pending_operations.iter().map(|op| op.issue_id).collect(),
+            pending_operations,
+            pending_operations_list_state: 0,
+            replay_pending_flag: false,
+            last_replay_attempt: None,
+            confirm_drop_pending_operation: false,
+            drop_pending_operation_id: None,
+            global_search_query: String::new(),
+            global_search_results: Vec::new(),
+            global_search_list_state: 0,
+            filter_presets: Vec::new(),
+            filter_presets_list_state: 0,
+            filter_preset_name_input: None,
+            confirm_delete_filter_preset: false,
+            dashboard_section: DashboardSection::Assigned,
+            dashboard_issues: Default::default(),
+            dashboard_list_states: [0; 4],
+            dashboard_sort_orders: [IssueSortOrder::UpdatedDesc; 4],
+            refresh_dashboard_flag: false,
+            config_return_screen: Screen::Dashboard,
+            roadmap_project_id: None,
+            roadmap_versions: Vec::new(),
+            version_progress: HashMap::new(),
+            roadmap_list_state: 0,
+            roadmap_issues: Vec::new(),
+            roadmap_issues_list_state: 0,
+            roadmap_focus_issues: false,
+            roadmap_sort_order: IssueSortOrder::UpdatedDesc,
+            fetch_versions_project_id: None,
+            fetch_version_issues_id: None,
+            board_column: 0,
+            board_cards: HashMap::new(),
+            move_board_card_to: None,
+            saved_queries: Vec::new(),
+            saved_queries_list_state: 0,
+            active_saved_query: None,
+            fetch_saved_queries_flag: false,
+            run_saved_query_flag: None,
             last_projects_sync: None,
             image_picker,
             attachment_images: HashMap::new(),
@@ -292,9 +563,40 @@ impl App {
             issues_loaded_count: 0,
             issues_total_count: 0,
             issues_temp_buffer: Vec::new(),
-        })
+            issues_full_sync: false,
+            issues_sync_since: None,
+            issues_sync_started: None,
+        }
     }
     
+    /// Show an error in the status bar and error popup. API errors are explained with
+    /// `user_message()`, keeping the technical message as detail the popup can expand.
+    pub fn report_error(&mut self, context: &str, error: impl Into<anyhow::Error>) {
+        let error = error.into();
+        tracing::error!("{}: {:#}", context, error);
+
+        match error.downcast_ref::<RedmineError>() {
+            Some(redmine_error) => {
+                let message = format!("{}: {}", context, redmine_error.user_message());
+                self.error_detail = Some((message.clone(), format!("{:#}", error)));
+                self.error_message = Some(message);
+            }
+            None => {
+                self.error_detail = None;
+                self.error_message = Some(format!("{}: {}", context, error));
+            }
+        }
+        self.show_error_detail = false;
+    }
+
+    /// Technical detail of the current error, if it was reported with one
+    pub fn current_error_detail(&self) -> Option<&str> {
+        match (&self.error_message, &self.error_detail) {
+            (Some(message), Some((owner, detail))) if owner == message => Some(detail),
+            _ => None,
+        }
+    }
+
     /// Set status message with timestamp for auto-clear
     pub fn set_status_message(&mut self, message: String) {
         self.status_message = Some(message);
@@ -0,0 +1,272 @@
+use anyhow::Result;
+
+use super::handlers::FormTarget;
+use super::state::{ActiveTimer, App, InputMode, ACTIVE_TIMER_KEY};
+use crate::issue_form::IssueForm;
+use crate::redmine::{CreateTimeEntry, UpdateTimeEntry};
+
+impl App {
+    /// Open the log time form for an issue, optionally prefilled with hours
+    pub fn open_time_entry_form(&mut self, issue_id: u64, hours: Option<f32>) {
+        let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+        self.time_entry_form = Some(IssueForm::time_entry_form(
+            &self.time_entry_activities,
+            hours,
+            None,
+            &today,
+            "",
+        ));
+        self.time_entry_issue_id = Some(issue_id);
+        self.editing_time_entry_id = None;
+        self.previous_input_mode = self.input_mode;
+        self.input_mode = InputMode::LoggingTime;
+    }
+
+    /// Open the form prefilled with the time entry selected in the list
+    pub fn edit_selected_time_entry(&mut self) {
+        if let Some(entry) = self.time_entries.get(self.time_entries_list_state).cloned() {
+            self.time_entry_form = Some(IssueForm::time_entry_form(
+                &self.time_entry_activities,
+                Some(entry.hours),
+                Some(entry.activity.id),
+                &entry.spent_on,
+                entry.comments.as_deref().unwrap_or(""),
+            ));
+            self.editing_time_entry_id = Some(entry.id);
+            self.previous_input_mode = self.input_mode;
+            self.input_mode = InputMode::LoggingTime;
+        }
+    }
+
+    /// Start a timer on the given issue, or stop the running one and open a prefilled time entry form.
+    /// A stopped timer stays saved until its entry is logged, so cancelling the form keeps it running.
+    pub fn toggle_timer(&mut self, issue: Option<(u64, String)>) {
+        if let Some(timer) = self.active_timer.take() {
+            let elapsed = timer.elapsed();
+            let hours = timer.rounded_hours();
+            self.open_time_entry_form(timer.issue_id, Some(hours));
+            self.status_message = Some(format!(
+                "Timer stopped on #{} after {}h {:02}m, logging {:.2}h",
+                timer.issue_id,
+                elapsed.num_hours(),
+                elapsed.num_minutes() % 60,
+                hours
+            ));
+            self.stopped_timer = Some(timer);
+            return;
+        }
+
+        if let Some(timer) = &self.stopped_timer {
+            self.status_message = Some(format!("Still logging the time of the timer on #{}", timer.issue_id));
+            return;
+        }
+
+        let Some((issue_id, subject)) = issue else {
+            self.error_message = Some("Select an issue to start the timer".to_string());
+            return;
+        };
+
+        let timer = ActiveTimer {
+            issue_id,
+            subject,
+            started_at: chrono::Utc::now(),
+        };
+        match serde_json::to_string(&timer) {
+            Ok(json) => {
+                if let Err(e) = self.db.set_metadata(ACTIVE_TIMER_KEY, &json) {
+                    tracing::warn!("Failed to save timer: {}", e);
+                }
+            }
+            Err(e) => tracing::warn!("Failed to serialize timer: {}", e),
+        }
+        self.status_message = Some(format!("Timer started on #{}", issue_id));
+        self.active_timer = Some(timer);
+    }
+
+    /// Validate the time entry form and hand it to the main loop for saving
+    pub fn submit_time_entry_form(&mut self) {
+        if let Some(form) = &self.time_entry_form {
+            if let Err(e) = form.validate() {
+                self.error_message = Some(e);
+                return;
+            }
+
+            self.submit_time_entry_flag = true;
+            self.status_message = Some("Saving time entry...".to_string());
+            self.input_mode = self.previous_input_mode;
+        } else {
+            self.error_message = Some("Form not initialized".to_string());
+        }
+    }
+
+    pub fn cancel_time_entry_form(&mut self) {
+        self.time_entry_form = None;
+        self.editing_time_entry_id = None;
+        self.input_mode = self.previous_input_mode;
+        // Nothing was logged, so the stopped timer picks up where it was
+        if let Some(timer) = self.stopped_timer.take() {
+            self.status_message = Some(format!("Timer still running on #{}", timer.issue_id));
+            self.active_timer = Some(timer);
+        }
+    }
+
+    /// Show the form again after its entry failed to save, with what was typed in it
+    fn reopen_time_entry_form(&mut self) {
+        if self.time_entry_form.is_some() {
+            self.input_mode = InputMode::LoggingTime;
+        }
+    }
+
+    /// Show the time entries list for an issue (cached entries first, then refreshed from the API)
+    pub fn show_time_entries(&mut self, issue_id: u64) {
+        self.time_entries = self.db.get_time_entries(issue_id).unwrap_or_default();
+        self.time_entries_list_state = 0;
+        self.time_entry_issue_id = Some(issue_id);
+        self.confirm_delete_time_entry = false;
+        self.show_time_entries_popup = true;
+        self.load_time_entries_flag = true;
+        self.input_mode = InputMode::ManagingTimeEntries;
+    }
+
+    pub fn close_time_entries(&mut self) {
+        self.show_time_entries_popup = false;
+        self.confirm_delete_time_entry = false;
+        self.time_entries.clear();
+        self.input_mode = InputMode::Normal;
+    }
+
+    pub async fn load_time_entries(&mut self, issue_id: u64) -> Result<()> {
+        if let Some(client) = &self.client {
+            let response = client.get_time_entries(issue_id).await?;
+
+            if let Err(e) = self.db.insert_time_entries(issue_id, &response.time_entries) {
+                tracing::warn!("Failed to cache time entries for issue {}: {}", issue_id, e);
+            }
+
+            // The list may have been closed or switched while the request was running
+            if self.time_entry_issue_id == Some(issue_id) {
+                self.time_entries = self.db.get_time_entries(issue_id)?;
+                self.time_entries_list_state = self
+                    .time_entries_list_state
+                    .min(self.time_entries.len().saturating_sub(1));
+            }
+        }
+        Ok(())
+    }
+
+    /// Create or update the time entry described by the form
+    pub async fn submit_time_entry(&mut self) -> Result<()> {
+        let Some(client) = self.client.clone() else {
+            self.reopen_time_entry_form();
+            anyhow::bail!("Not connected to a Redmine server");
+        };
+        let (Some(form), Some(issue_id)) = (&self.time_entry_form, self.time_entry_issue_id) else {
+            return Ok(());
+        };
+
+        let Some(hours) = form.get_value("hours").and_then(|v| v.as_float()) else {
+            self.reopen_time_entry_form();
+            anyhow::bail!("Hours is required");
+        };
+
+        let activity_id = form.get_value("activity_id").and_then(|v| v.as_option_id());
+
+        let spent_on = form
+            .get_value("spent_on")
+            .and_then(|v| v.as_text())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string());
+
+        let comments = form.get_value("comments").and_then(|v| v.as_text()).unwrap_or("").to_string();
+
+        let result = if let Some(time_entry_id) = self.editing_time_entry_id {
+            let update = UpdateTimeEntry {
+                hours: Some(hours),
+                activity_id,
+                spent_on,
+                comments: Some(comments),
+            };
+            client
+                .update_time_entry(time_entry_id, update)
+                .await
+                .map(|_| format!("Updated time entry on #{}", issue_id))
+        } else {
+            let new_entry = CreateTimeEntry {
+                issue_id,
+                hours,
+                activity_id,
+                spent_on,
+                comments: Some(comments).filter(|c| !c.is_empty()),
+            };
+            match client.create_time_entry(new_entry).await {
+                Ok(response) => {
+                    if let Err(e) = self.db.insert_time_entry(&response.time_entry) {
+                        tracing::warn!("Failed to cache time entry: {}", e);
+                    }
+                    Ok(format!("Logged {:.2}h on #{}", hours, issue_id))
+                }
+                Err(e) => Err(e),
+            }
+        };
+
+        match result {
+            Ok(message) => {
+                self.time_entry_form = None;
+                self.editing_time_entry_id = None;
+                if self.stopped_timer.take().is_some() {
+                    if let Err(e) = self.db.delete_metadata(ACTIVE_TIMER_KEY) {
+                        tracing::warn!("Failed to clear saved timer: {}", e);
+                    }
+                }
+                self.refresh_after_time_entry_change(issue_id).await;
+                self.status_message = Some(message);
+            }
+            Err(e) if !e.validation_errors().is_empty() => {
+                self.show_form_errors(FormTarget::TimeEntry, e.validation_errors());
+            }
+            Err(e) => {
+                self.report_error("Failed to save time entry", e);
+                self.reopen_time_entry_form();
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn delete_time_entry(&mut self, time_entry_id: u64) -> Result<()> {
+        let client = match &self.client {
+            Some(c) => c.clone(),
+            None => return Ok(()),
+        };
+
+        client.delete_time_entry(time_entry_id).await?;
+
+        if let Err(e) = self.db.delete_time_entry(time_entry_id) {
+            tracing::warn!("Failed to remove time entry {} from cache: {}", time_entry_id, e);
+        }
+        self.time_entries.retain(|e| e.id != time_entry_id);
+        self.time_entries_list_state = self
+            .time_entries_list_state
+            .min(self.time_entries.len().saturating_sub(1));
+
+        if let Some(issue_id) = self.time_entry_issue_id {
+            self.refresh_after_time_entry_change(issue_id).await;
+        }
+        self.status_message = Some("Time entry deleted".to_string());
+        Ok(())
+    }
+
+    /// Reload the entries list and the issue's spent time after a change
+    async fn refresh_after_time_entry_change(&mut self, issue_id: u64) {
+        if self.show_time_entries_popup {
+            if let Err(e) = self.load_time_entries(issue_id).await {
+                tracing::warn!("Failed to reload time entries: {}", e);
+            }
+        }
+
+        if self.current_issue.as_ref().is_some_and(|i| i.id == issue_id) {
+            if let Err(e) = self.load_issue_detail(issue_id).await {
+                tracing::warn!("Failed to reload issue #{}: {}", issue_id, e);
+            }
+        }
+    }
+}
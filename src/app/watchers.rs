@@ -0,0 +1,80 @@
+//! Watchers of the issue shown in the popup.
+//!
+//! The popup lists the watchers the server returned with the issue. The current
+//! user can start or stop watching it with one key (online only), which also keeps
+//! the `watched_issues` table behind the dashboard and `watcher:me` up to date.
+
+use anyhow::Result;
+
+use super::state::{App, Screen};
+use crate::redmine::Issue;
+
+impl App {
+    /// Whether the current user watches the issue, as far as the cache knows
+    pub fn is_watching(&self, issue_id: u64) -> bool {
+        self.db.is_issue_watched(issue_id).unwrap_or(false)
+    }
+
+    /// Ask the main loop to watch or unwatch the popup's issue
+    pub fn request_toggle_watch(&mut self) {
+        let Some(issue_id) = self.current_issue.as_ref().map(|i| i.id) else {
+            return;
+        };
+        if self.client.is_none() || self.current_user_id.is_none() {
+            self.error_message = Some("Watching issues needs a connection to Redmine".to_string());
+            return;
+        }
+        self.status_message = Some(if self.is_watching(issue_id) {
+            format!("Unwatching #{}...", issue_id)
+        } else {
+            format!("Watching #{}...", issue_id)
+        });
+        self.toggle_watch_issue_id = Some(issue_id);
+    }
+
+    /// Add or remove the current user as a watcher and reload the issue
+    pub async fn toggle_watch(&mut self, issue_id: u64) -> Result<()> {
+        let (Some(client), Some(user_id)) = (self.client.clone(), self.current_user_id) else {
+            return Ok(());
+        };
+        let watch = !self.is_watching(issue_id);
+        if watch {
+            client.add_watcher(issue_id, user_id).await?;
+        } else {
+            client.remove_watcher(issue_id, user_id).await?;
+        }
+        self.db.set_issue_watched(issue_id, watch)?;
+
+        if self.current_issue.as_ref().is_some_and(|i| i.id == issue_id) {
+            if let Err(e) = self.load_issue_detail(issue_id).await {
+                tracing::warn!("Failed to reload issue #{}: {}", issue_id, e);
+            }
+        }
+        // The issue may join or leave `watcher:me` and the dashboard's Watched section
+        self.apply_filters();
+        if self.screen == Screen::Dashboard {
+            self.reload_dashboard();
+        }
+        self.status_message = Some(if watch {
+            format!("Watching #{}", issue_id)
+        } else {
+            format!("Stopped watching #{}", issue_id)
+        });
+        Ok(())
+    }
+
+    /// Bring `watched_issues` in line with the watchers of a freshly fetched issue. The server
+    /// leaves watchers out for users who may not see them, so an empty list changes nothing.
+    pub(crate) fn sync_watched_issue(&mut self, issue: &Issue) {
+        let Some(user_id) = self.current_user_id else {
+            return;
+        };
+        if issue.watchers.is_empty() {
+            return;
+        }
+        let watched = issue.watchers.iter().any(|w| w.id == user_id);
+        if let Err(e) = self.db.set_issue_watched(issue.id, watched) {
+            tracing::warn!("Failed to record watching of #{}: {}", issue.id, e);
+        }
+    }
+}
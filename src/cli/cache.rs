@@ -0,0 +1,52 @@
+use anyhow::Result;
+
+use super::CliError;
+use crate::config::Config;
+use crate::db::{Database, SCHEMA_VERSION};
+
+pub(crate) fn migrate(config: &Config) -> Result<()> {
+    let path = Database::profile_path(config.profile_name());
+    let db = Database::open(path.clone())?;
+    let from = db.migrate()?;
+
+    if from == SCHEMA_VERSION {
+        println!(
+            "Cache {} is up to date (schema version {})",
+            path.display(),
+            SCHEMA_VERSION
+        );
+    } else {
+        println!(
+            "Migrated cache {} from schema version {} to {}",
+            path.display(),
+            from,
+            SCHEMA_VERSION
+        );
+    }
+    Ok(())
+}
+
+pub(crate) fn reset(config: &Config, force: bool) -> Result<()> {
+    let path = Database::profile_path(config.profile_name());
+
+    // Queued offline writes only live in the cache, so don't drop them by accident.
+    // A cache too new or too broken to read them is reset regardless.
+    if !force && path.exists() {
+        let pending = Database::open(path.clone())
+            .and_then(|db| db.count_pending_operations())
+            .unwrap_or(0);
+        if pending > 0 {
+            return Err(CliError::InvalidInput(format!(
+                "The cache holds {} offline change(s) not yet sent to the server. \
+                 Start minecli to send them, or pass --force to discard them",
+                pending
+            ))
+            .into());
+        }
+    }
+
+    Database::reset(&path)?;
+    Database::new(path.clone())?;
+    println!("Reset cache {} (schema version {})", path.display(), SCHEMA_VERSION);
+    Ok(())
+}
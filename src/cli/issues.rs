@@ -0,0 +1,245 @@
+use std::io::{self, Write};
+
+use anyhow::Result;
+
+use super::output::{print_listing, OutputArgs, OutputFormat};
+use super::{projects, read_arg_or_stdin, CliError, Context, IssueCreateArgs, IssueListArgs};
+use crate::redmine::{CreateIssue, Issue, IssueQuery, UpdateIssue};
+
+pub(crate) async fn list(ctx: &Context, args: IssueListArgs) -> Result<()> {
+    let status = match args.status.as_str() {
+        "all" => "*".to_string(),
+        other => other.to_string(),
+    };
+    let query = IssueQuery {
+        project: args.project,
+        assigned_to: args.assignee,
+        status: Some(status),
+        ..Default::default()
+    };
+
+    let page_size = 100;
+    let mut offset = 0;
+    let mut issues: Vec<Issue> = Vec::new();
+
+    loop {
+        let remaining = if args.limit == 0 {
+            page_size
+        } else {
+            page_size.min(args.limit - issues.len() as u32)
+        };
+        let response = ctx.client.query_issues(&query, remaining, offset).await?;
+        let count = response.issues.len();
+        issues.extend(response.issues);
+
+        let reached_limit = args.limit != 0 && issues.len() as u32 >= args.limit;
+        if count < remaining as usize || reached_limit {
+            break;
+        }
+        offset += count as u32;
+    }
+
+    print_listing(&issues, &args.output)
+}
+
+/// Fetch a single issue with its journals and refresh the cached copy
+async fn fetch_issue(ctx: &Context, id: u64) -> Result<Issue> {
+    let issue = ctx.client.get_issue(id).await?.issue;
+
+    if let Err(e) = ctx.db.insert_issue_with_journals(&issue) {
+        tracing::warn!("Failed to cache issue #{}: {}", id, e);
+    }
+    Ok(issue)
+}
+
+pub(crate) async fn show(ctx: &Context, id: u64, output: &OutputArgs) -> Result<()> {
+    let issue = fetch_issue(ctx, id).await?;
+
+    // Selected columns or CSV print the issue as a one-row listing
+    match output.format {
+        OutputFormat::Json if output.columns.is_empty() => {
+            let mut out = io::stdout().lock();
+            serde_json::to_writer_pretty(&mut out, &issue)?;
+            writeln!(out)?;
+            return Ok(());
+        }
+        OutputFormat::Table if output.columns.is_empty() => {}
+        _ => return print_listing(std::slice::from_ref(&issue), output),
+    }
+
+    let mut out = io::stdout().lock();
+
+    writeln!(out, "#{} {}", issue.id, issue.subject)?;
+    writeln!(out)?;
+    writeln!(out, "Project:   {}", issue.project.name)?;
+    writeln!(out, "Tracker:   {}", issue.tracker.name)?;
+    writeln!(out, "Status:    {}", issue.status.name)?;
+    writeln!(out, "Priority:  {}", issue.priority.name)?;
+    writeln!(out, "Author:    {}", issue.author.name)?;
+    if let Some(assignee) = &issue.assigned_to {
+        writeln!(out, "Assignee:  {}", assignee.name)?;
+    }
+    if let Some(version) = &issue.fixed_version {
+        writeln!(out, "Version:   {}", version.name)?;
+    }
+    if let Some(start_date) = &issue.start_date {
+        writeln!(out, "Start:     {}", start_date)?;
+    }
+    if let Some(due_date) = &issue.due_date {
+        writeln!(out, "Due:       {}", due_date)?;
+    }
+    if let Some(done_ratio) = issue.done_ratio {
+        writeln!(out, "Done:      {}%", done_ratio)?;
+    }
+    if let Some(spent) = issue.spent_hours.filter(|h| *h > 0.0) {
+        writeln!(out, "Spent:     {:.2}h", spent)?;
+    }
+    writeln!(out, "Created:   {}", issue.created_on.format("%Y-%m-%d %H:%M"))?;
+    writeln!(out, "Updated:   {}", issue.updated_on.format("%Y-%m-%d %H:%M"))?;
+
+    for field in issue.custom_fields.iter().filter(|f| !f.value.is_empty()) {
+        writeln!(out, "{}: {}", field.name, field.value)?;
+    }
+
+    if let Some(description) = issue.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        writeln!(out)?;
+        writeln!(out, "{}", description.trim_end())?;
+    }
+
+    let notes: Vec<_> = issue
+        .journals
+        .iter()
+        .filter_map(|j| j.notes.as_deref().filter(|n| !n.trim().is_empty()).map(|n| (j, n)))
+        .collect();
+    if !notes.is_empty() {
+        writeln!(out)?;
+        writeln!(out, "Notes:")?;
+        for (journal, text) in notes {
+            writeln!(out)?;
+            writeln!(out, "  {} {}", journal.created_on.format("%Y-%m-%d %H:%M"), journal.user.name)?;
+            for line in text.trim_end().lines() {
+                writeln!(out, "    {}", line)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn history(ctx: &Context, id: u64, output: &OutputArgs) -> Result<()> {
+    let issue = fetch_issue(ctx, id).await?;
+    print_listing(&issue.journals, output)
+}
+
+pub(crate) async fn create(ctx: &Context, args: IssueCreateArgs) -> Result<()> {
+    let project_id = projects::resolve_id(ctx, &args.project).await?;
+    let project = ctx.client.get_project_detail(project_id).await?.project;
+
+    let tracker_id = match &args.tracker {
+        Some(tracker) => project
+            .trackers
+            .iter()
+            .find(|t| matches_name_or_id(tracker, t.id, &t.name))
+            .map(|t| t.id)
+            .ok_or_else(|| CliError::NotFound(format!("Tracker '{}' not enabled in {}", tracker, project.name)))?,
+        None => project
+            .trackers
+            .first()
+            .map(|t| t.id)
+            .ok_or_else(|| CliError::InvalidInput(format!("Project {} has no trackers", project.name)))?,
+    };
+
+    let status_id = ctx
+        .client
+        .get_issue_statuses()
+        .await?
+        .issue_statuses
+        .first()
+        .map(|s| s.id)
+        .ok_or_else(|| anyhow::anyhow!("Server returned no issue statuses"))?;
+
+    let priorities = ctx.client.get_priorities().await?.issue_priorities;
+    let priority = match &args.priority {
+        Some(priority) => priorities
+            .iter()
+            .find(|p| matches_name_or_id(priority, p.id, &p.name))
+            .ok_or_else(|| CliError::NotFound(format!("Priority '{}' not found", priority)))?,
+        None => priorities
+            .iter()
+            .find(|p| p.is_default)
+            .or_else(|| priorities.first())
+            .ok_or_else(|| anyhow::anyhow!("Server returned no issue priorities"))?,
+    };
+
+    let assigned_to_id = match &args.assignee {
+        Some(assignee) => Some(resolve_user_id(ctx, assignee).await?),
+        None => None,
+    };
+
+    let description = args.description.as_deref().map(read_arg_or_stdin).transpose()?;
+
+    let new_issue = CreateIssue {
+        project_id,
+        tracker_id,
+        status_id,
+        priority_id: priority.id,
+        subject: args.subject,
+        description,
+        assigned_to_id,
+        category_id: None,
+        start_date: None,
+        due_date: None,
+        estimated_hours: None,
+        done_ratio: None,
+        fixed_version_id: None,
+        parent_issue_id: None,
+        watcher_user_ids: None,
+        uploads: None,
+    };
+
+    let issue = ctx.client.create_issue(new_issue).await?.issue;
+    if let Err(e) = ctx.db.insert_issue_with_journals(&issue) {
+        tracing::warn!("Failed to cache issue #{}: {}", issue.id, e);
+    }
+
+    println!("{}", issue.id);
+    Ok(())
+}
+
+pub(crate) async fn comment(ctx: &Context, id: u64, message: &str, private: bool) -> Result<()> {
+    let notes = read_arg_or_stdin(message)?;
+    if notes.trim().is_empty() {
+        return Err(CliError::InvalidInput("Comment is empty".to_string()).into());
+    }
+
+    let update = UpdateIssue {
+        notes: Some(notes),
+        private_notes: private.then_some(true),
+        ..Default::default()
+    };
+    ctx.client.update_issue_with_comment(id, update).await?;
+
+    eprintln!("Added note to #{}", id);
+    Ok(())
+}
+
+fn matches_name_or_id(value: &str, id: u64, name: &str) -> bool {
+    value.parse::<u64>().ok() == Some(id) || name.eq_ignore_ascii_case(value)
+}
+
+/// Resolve `me`, a numeric id or a cached login to a user id
+async fn resolve_user_id(ctx: &Context, user: &str) -> Result<u64> {
+    if user == "me" {
+        return Ok(ctx.client.get_current_user().await?.user.id);
+    }
+    if let Ok(id) = user.parse::<u64>() {
+        return Ok(id);
+    }
+
+    ctx.db
+        .get_users()?
+        .into_iter()
+        .find(|u| u.login.eq_ignore_ascii_case(user))
+        .map(|u| u.id)
+        .ok_or_else(|| CliError::NotFound(format!("User '{}' not found in cache", user)).into())
+}
@@ -0,0 +1,402 @@
+//! Non-interactive subcommands for scripting.
+//!
+//! Running `minecli` without a subcommand starts the TUI. With a subcommand the
+//! result is printed to stdout, errors go to stderr and the process exit code
+//! tells scripts what happened (see the `EXIT_*` constants).
+
+mod cache;
+mod issues;
+mod output;
+mod projects;
+mod users;
+
+pub use output::{OutputArgs, OutputFormat};
+
+use std::io::Read;
+use std::process::ExitCode;
+
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand};
+
+use crate::config::Config;
+use crate::db::Database;
+use crate::error::RedmineError;
+use crate::redmine::RedmineClient;
+
+/// The command completed successfully
+pub const EXIT_SUCCESS: u8 = 0;
+/// The request failed (network, server or cache error)
+pub const EXIT_FAILURE: u8 = 1;
+/// Invalid arguments, or the server rejected the input (also used by clap for parse errors)
+pub const EXIT_USAGE: u8 = 2;
+/// No Redmine URL or API key configured, or the server rejected the key
+pub const EXIT_NOT_CONFIGURED: u8 = 3;
+/// The requested issue, project or user does not exist
+pub const EXIT_NOT_FOUND: u8 = 4;
+
+#[derive(Debug, Parser)]
+#[command(name = "minecli", version, about = "A fast terminal client for Redmine")]
+pub struct Cli {
+    /// Server profile to use instead of the saved one
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Work with issue lists
+    Issues {
+        #[command(subcommand)]
+        command: IssuesCommand,
+    },
+    /// Show, create or comment on a single issue
+    Issue {
+        #[command(subcommand)]
+        command: IssueCommand,
+    },
+    /// Work with projects
+    Projects {
+        #[command(subcommand)]
+        command: ProjectsCommand,
+    },
+    /// Work with users
+    Users {
+        #[command(subcommand)]
+        command: UsersCommand,
+    },
+    /// Manage the local cache database
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+}
+
+impl Command {
+    /// Whether the command talks to the Redmine server (and so needs the URL and API key)
+    pub fn needs_server(&self) -> bool {
+        !matches!(self, Command::Cache { .. })
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum IssuesCommand {
+    /// List issues, most recently updated first
+    List(IssueListArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct IssueListArgs {
+    /// Project id or identifier
+    #[arg(short, long)]
+    pub project: Option<String>,
+    /// Assignee user id, or `me`
+    #[arg(short, long)]
+    pub assignee: Option<String>,
+    /// `open`, `closed`, `all` or a status id
+    #[arg(short, long, default_value = "open")]
+    pub status: String,
+    /// Maximum number of issues to print (0 for all)
+    #[arg(short, long, default_value_t = 100)]
+    pub limit: u32,
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum IssueCommand {
+    /// Print an issue with its description and notes
+    Show {
+        /// Issue id
+        id: u64,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// List an issue's journals (notes and field changes)
+    History {
+        /// Issue id
+        id: u64,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Create an issue and print its id
+    Create(IssueCreateArgs),
+    /// Add a note to an issue
+    Comment {
+        /// Issue id
+        id: u64,
+        /// Note text, or `-` to read it from stdin
+        #[arg(short, long)]
+        message: String,
+        /// Make the note private
+        #[arg(long)]
+        private: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct IssueCreateArgs {
+    /// Project id or identifier
+    #[arg(short, long)]
+    pub project: String,
+    /// Issue subject
+    #[arg(short, long)]
+    pub subject: String,
+    /// Description, or `-` to read it from stdin
+    #[arg(short, long)]
+    pub description: Option<String>,
+    /// Tracker name or id (defaults to the project's first tracker)
+    #[arg(short, long)]
+    pub tracker: Option<String>,
+    /// Priority name or id (defaults to the server's default priority)
+    #[arg(long)]
+    pub priority: Option<String>,
+    /// Assignee user id, login, or `me`
+    #[arg(short, long)]
+    pub assignee: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ProjectsCommand {
+    /// List all projects visible to the configured user
+    List(OutputArgs),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UsersCommand {
+    /// List users (requires admin rights on the server)
+    List(OutputArgs),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CacheCommand {
+    /// Upgrade the cache to the schema this version of minecli uses
+    Migrate,
+    /// Delete the cache and start over with an empty one
+    Reset {
+        /// Also discard offline changes that haven't been sent yet
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Errors that map to a specific exit code
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error("Redmine is not configured for this profile. Run `minecli` to set the server URL and API key")]
+    NotConfigured,
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    InvalidInput(String),
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            CliError::NotConfigured => EXIT_NOT_CONFIGURED,
+            CliError::NotFound(_) => EXIT_NOT_FOUND,
+            CliError::InvalidInput(_) => EXIT_USAGE,
+        }
+    }
+}
+
+/// Shared handles for a single command run
+pub(crate) struct Context {
+    pub client: RedmineClient,
+    pub db: Database,
+}
+
+impl Context {
+    fn new(config: &Config) -> Result<Self> {
+        if !config.is_configured() {
+            return Err(CliError::NotConfigured.into());
+        }
+
+        Ok(Self {
+            client: RedmineClient::new(config.redmine_url().to_string(), config.api_key().to_string()),
+            db: Database::new(Database::profile_path(config.profile_name()))?,
+        })
+    }
+}
+
+/// Run a subcommand, reporting errors on stderr and returning the exit code
+pub async fn run(config: Config, command: Command) -> ExitCode {
+    match execute(&config, command).await {
+        Ok(()) => ExitCode::from(EXIT_SUCCESS),
+        // A closed pipe (e.g. `| head`) is not a failure of the command
+        Err(e) if is_broken_pipe(&e) => ExitCode::from(EXIT_SUCCESS),
+        Err(e) => {
+            tracing::error!("Command failed: {:#}", e);
+            eprintln!("Error: {:#}", e);
+            ExitCode::from(exit_code(&e))
+        }
+    }
+}
+
+async fn execute(config: &Config, command: Command) -> Result<()> {
+    // Cache maintenance works without a server
+    if let Command::Cache { command } = command {
+        return match command {
+            CacheCommand::Migrate => cache::migrate(config),
+            CacheCommand::Reset { force } => cache::reset(config, force),
+        };
+    }
+
+    let ctx = Context::new(config)?;
+
+    match command {
+        Command::Issues {
+            command: IssuesCommand::List(args),
+        } => issues::list(&ctx, args).await,
+        Command::Issue { command } => match command {
+            IssueCommand::Show { id, output } => issues::show(&ctx, id, &output).await,
+            IssueCommand::History { id, output } => issues::history(&ctx, id, &output).await,
+            IssueCommand::Create(args) => issues::create(&ctx, args).await,
+            IssueCommand::Comment { id, message, private } => issues::comment(&ctx, id, &message, private).await,
+        },
+        Command::Projects {
+            command: ProjectsCommand::List(output),
+        } => projects::list(&ctx, &output).await,
+        Command::Users {
+            command: UsersCommand::List(output),
+        } => users::list(&ctx, &output).await,
+        Command::Cache { .. } => unreachable!("handled above"),
+    }
+}
+
+/// Pick the exit code for a failed command from its error type
+fn exit_code(e: &anyhow::Error) -> u8 {
+    if let Some(e) = e.downcast_ref::<CliError>() {
+        return e.exit_code();
+    }
+    match e.downcast_ref::<RedmineError>() {
+        Some(RedmineError::Auth { .. }) => EXIT_NOT_CONFIGURED,
+        Some(RedmineError::Api { status: 404, .. }) => EXIT_NOT_FOUND,
+        Some(RedmineError::Validation(_)) => EXIT_USAGE,
+        _ => EXIT_FAILURE,
+    }
+}
+
+fn is_broken_pipe(e: &anyhow::Error) -> bool {
+    e.chain().any(|cause| {
+        let kind = if let Some(e) = cause.downcast_ref::<std::io::Error>() {
+            Some(e.kind())
+        } else if let Some(e) = cause.downcast_ref::<serde_json::Error>() {
+            e.io_error_kind()
+        } else if let Some(csv::ErrorKind::Io(e)) = cause.downcast_ref::<csv::Error>().map(|e| e.kind()) {
+            Some(e.kind())
+        } else {
+            None
+        };
+        kind == Some(std::io::ErrorKind::BrokenPipe)
+    })
+}
+
+/// Return the argument, or read it from stdin when it is `-`
+fn read_arg_or_stdin(value: &str) -> Result<String> {
+    if value != "-" {
+        return Ok(value.to_string());
+    }
+
+    let mut text = String::new();
+    std::io::stdin().read_to_string(&mut text)?;
+    Ok(text.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_no_subcommand_starts_tui() {
+        let cli = Cli::try_parse_from(["minecli"]).unwrap();
+        assert!(cli.command.is_none());
+    }
+
+    #[test]
+    fn test_parse_issue_comment() {
+        let cli = Cli::try_parse_from(["minecli", "issue", "comment", "123", "-m", "Deployed"]).unwrap();
+        match cli.command {
+            Some(Command::Issue {
+                command: IssueCommand::Comment { id, message, private },
+            }) => {
+                assert_eq!(id, 123);
+                assert_eq!(message, "Deployed");
+                assert!(!private);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_issues_list_defaults() {
+        let cli = Cli::try_parse_from(["minecli", "issues", "list", "--assignee", "me"]).unwrap();
+        match cli.command {
+            Some(Command::Issues {
+                command: IssuesCommand::List(args),
+            }) => {
+                assert_eq!(args.assignee.as_deref(), Some("me"));
+                assert_eq!(args.status, "open");
+                assert_eq!(args.limit, 100);
+                assert!(args.project.is_none());
+                assert_eq!(args.output.format, OutputFormat::Table);
+                assert!(args.output.columns.is_empty());
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_output_args() {
+        let cli = Cli::try_parse_from(["minecli", "projects", "list", "--format", "csv", "--columns", "id,name"]).unwrap();
+        match cli.command {
+            Some(Command::Projects {
+                command: ProjectsCommand::List(output),
+            }) => {
+                assert_eq!(output.format, OutputFormat::Csv);
+                assert_eq!(output.columns, vec!["id", "name"]);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_cache_reset() {
+        let cli = Cli::try_parse_from(["minecli", "cache", "reset", "--force"]).unwrap();
+        let command = cli.command.unwrap();
+        assert!(!command.needs_server());
+        assert!(matches!(
+            command,
+            Command::Cache {
+                command: CacheCommand::Reset { force: true }
+            }
+        ));
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(CliError::NotConfigured.exit_code(), EXIT_NOT_CONFIGURED);
+        assert_eq!(CliError::NotFound("x".into()).exit_code(), EXIT_NOT_FOUND);
+        assert_eq!(CliError::InvalidInput("x".into()).exit_code(), EXIT_USAGE);
+    }
+
+    #[test]
+    fn test_exit_codes_for_server_errors() {
+        let code = |e: RedmineError| exit_code(&e.into());
+        assert_eq!(code(RedmineError::from_response(401, "")), EXIT_NOT_CONFIGURED);
+        assert_eq!(code(RedmineError::from_response(404, "")), EXIT_NOT_FOUND);
+        assert_eq!(
+            code(RedmineError::from_response(422, r#"{"errors":["Subject cannot be blank"]}"#)),
+            EXIT_USAGE
+        );
+        assert_eq!(code(RedmineError::from_response(500, "")), EXIT_FAILURE);
+    }
+}
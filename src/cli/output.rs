@@ -0,0 +1,314 @@
+use std::io::{self, Write};
+
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use super::CliError;
+use crate::redmine::{Issue, Journal, Project, User};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns for reading in a terminal
+    #[default]
+    Table,
+    /// JSON array (full objects unless --columns is given)
+    Json,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+#[derive(Debug, Clone, Default, Args)]
+pub struct OutputArgs {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: OutputFormat,
+    /// Comma-separated list of columns to print (`--columns help` lists them)
+    #[arg(short, long, value_delimiter = ',')]
+    pub columns: Vec<String>,
+}
+
+/// A model that can be printed as rows of named columns
+pub(crate) trait Listing: Serialize {
+    /// Every column this type provides
+    const COLUMNS: &'static [&'static str];
+    /// Columns printed when none are selected
+    const DEFAULT_COLUMNS: &'static [&'static str];
+
+    fn column(&self, name: &str) -> Value;
+}
+
+/// Print items in the requested format and columns
+pub(crate) fn print_listing<T: Listing>(items: &[T], args: &OutputArgs) -> Result<()> {
+    let columns = selected_columns::<T>(&args.columns)?;
+    let mut out = io::stdout().lock();
+
+    match args.format {
+        OutputFormat::Json if args.columns.is_empty() => {
+            serde_json::to_writer_pretty(&mut out, items)?;
+            writeln!(out)?;
+        }
+        OutputFormat::Json => {
+            let rows: Vec<Map<String, Value>> = items
+                .iter()
+                .map(|item| columns.iter().map(|c| (c.to_string(), item.column(c))).collect())
+                .collect();
+            serde_json::to_writer_pretty(&mut out, &rows)?;
+            writeln!(out)?;
+        }
+        OutputFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            writer.write_record(&columns)?;
+            for item in items {
+                writer.write_record(columns.iter().map(|c| cell_text(&item.column(c))))?;
+            }
+            writer.flush()?;
+        }
+        OutputFormat::Table => {
+            let headers: Vec<String> = columns.iter().map(|c| c.to_uppercase()).collect();
+            let rows: Vec<Vec<String>> = items
+                .iter()
+                .map(|item| {
+                    columns
+                        .iter()
+                        .map(|c| cell_text(&item.column(c)).replace(['\r', '\n'], " "))
+                        .collect()
+                })
+                .collect();
+            write_table(&mut out, &headers, &rows)?;
+        }
+    }
+    Ok(())
+}
+
+/// Validate the requested columns, falling back to the defaults
+fn selected_columns<T: Listing>(requested: &[String]) -> Result<Vec<&'static str>> {
+    if requested.is_empty() {
+        return Ok(T::DEFAULT_COLUMNS.to_vec());
+    }
+
+    requested
+        .iter()
+        .map(|name| {
+            let name = name.trim().to_lowercase();
+            T::COLUMNS.iter().copied().find(|c| *c == name).ok_or_else(|| {
+                let prefix = if name == "help" {
+                    String::new()
+                } else {
+                    format!("Unknown column '{}'. ", name)
+                };
+                CliError::InvalidInput(format!("{}Available columns: {}", prefix, T::COLUMNS.join(", "))).into()
+            })
+        })
+        .collect()
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Write rows as left-aligned columns separated by two spaces.
+/// The last column is not padded so long subjects don't leave trailing whitespace.
+fn write_table(out: &mut impl Write, headers: &[String], rows: &[Vec<String>]) -> io::Result<()> {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in std::iter::once(headers).chain(rows.iter().map(Vec::as_slice)) {
+        let last = row.len().saturating_sub(1);
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                if i == last {
+                    cell.clone()
+                } else {
+                    format!("{:<width$}", cell, width = widths[i])
+                }
+            })
+            .collect();
+        writeln!(out, "{}", line.join("  "))?;
+    }
+    Ok(())
+}
+
+impl Listing for Issue {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "project",
+        "tracker",
+        "status",
+        "priority",
+        "author",
+        "assignee",
+        "category",
+        "version",
+        "parent",
+        "subject",
+        "description",
+        "start_date",
+        "due_date",
+        "done_ratio",
+        "estimated_hours",
+        "spent_hours",
+        "created_on",
+        "updated_on",
+        "closed_on",
+    ];
+    const DEFAULT_COLUMNS: &'static [&'static str] =
+        &["id", "project", "tracker", "status", "priority", "assignee", "subject"];
+
+    fn column(&self, name: &str) -> Value {
+        match name {
+            "id" => json!(self.id),
+            "project" => json!(self.project.name),
+            "tracker" => json!(self.tracker.name),
+            "status" => json!(self.status.name),
+            "priority" => json!(self.priority.name),
+            "author" => json!(self.author.name),
+            "assignee" => json!(self.assigned_to.as_ref().map(|a| &a.name)),
+            "category" => json!(self.category.as_ref().map(|c| &c.name)),
+            "version" => json!(self.fixed_version.as_ref().map(|v| &v.name)),
+            "parent" => json!(self.parent.as_ref().map(|p| p.id)),
+            "subject" => json!(self.subject),
+            "description" => json!(self.description),
+            "start_date" => json!(self.start_date),
+            "due_date" => json!(self.due_date),
+            "done_ratio" => json!(self.done_ratio),
+            "estimated_hours" => json!(self.estimated_hours),
+            "spent_hours" => json!(self.spent_hours),
+            "created_on" => json!(self.created_on),
+            "updated_on" => json!(self.updated_on),
+            "closed_on" => json!(self.closed_on),
+            _ => Value::Null,
+        }
+    }
+}
+
+impl Listing for Project {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "identifier",
+        "name",
+        "parent",
+        "status",
+        "description",
+        "created_on",
+        "updated_on",
+    ];
+    const DEFAULT_COLUMNS: &'static [&'static str] = &["id", "identifier", "parent", "name"];
+
+    fn column(&self, name: &str) -> Value {
+        match name {
+            "id" => json!(self.id),
+            "identifier" => json!(self.identifier),
+            "name" => json!(self.name),
+            "parent" => json!(self.parent.as_ref().map(|p| &p.name)),
+            "status" => json!(self.status),
+            "description" => json!(self.description),
+            "created_on" => json!(self.created_on),
+            "updated_on" => json!(self.updated_on),
+            _ => Value::Null,
+        }
+    }
+}
+
+impl Listing for Journal {
+    const COLUMNS: &'static [&'static str] = &["id", "created_on", "user", "notes", "private_notes", "changes"];
+    const DEFAULT_COLUMNS: &'static [&'static str] = &["id", "created_on", "user", "notes"];
+
+    fn column(&self, name: &str) -> Value {
+        match name {
+            "id" => json!(self.id),
+            "created_on" => json!(self.created_on),
+            "user" => json!(self.user.name),
+            "notes" => json!(self.notes),
+            "private_notes" => json!(self.private_notes),
+            "changes" => {
+                let changes: Vec<String> = self
+                    .details
+                    .iter()
+                    .map(|d| {
+                        format!(
+                            "{}: {} -> {}",
+                            d.name,
+                            d.old_value.as_deref().unwrap_or(""),
+                            d.new_value.as_deref().unwrap_or("")
+                        )
+                    })
+                    .collect();
+                json!(changes.join("; "))
+            }
+            _ => Value::Null,
+        }
+    }
+}
+
+impl Listing for User {
+    const COLUMNS: &'static [&'static str] = &["id", "login", "name", "firstname", "lastname", "mail"];
+    const DEFAULT_COLUMNS: &'static [&'static str] = &["id", "login", "name", "mail"];
+
+    fn column(&self, name: &str) -> Value {
+        match name {
+            "id" => json!(self.id),
+            "login" => json!(self.login),
+            "name" => json!(format!("{} {}", self.firstname, self.lastname)),
+            "firstname" => json!(self.firstname),
+            "lastname" => json!(self.lastname),
+            "mail" => json!(self.mail),
+            _ => Value::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selected_columns_defaults() {
+        let columns = selected_columns::<Project>(&[]).unwrap();
+        assert_eq!(columns, Project::DEFAULT_COLUMNS);
+    }
+
+    #[test]
+    fn test_selected_columns_normalizes_names() {
+        let requested = vec!["ID".to_string(), " subject".to_string()];
+        let columns = selected_columns::<Issue>(&requested).unwrap();
+        assert_eq!(columns, vec!["id", "subject"]);
+    }
+
+    #[test]
+    fn test_selected_columns_rejects_unknown() {
+        let err = selected_columns::<User>(&["nope".to_string()]).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("Unknown column 'nope'"));
+        assert!(message.contains("login"));
+    }
+
+    #[test]
+    fn test_every_column_is_known() {
+        let user = User {
+            id: 3,
+            login: "jdoe".to_string(),
+            firstname: "Jane".to_string(),
+            lastname: "Doe".to_string(),
+            mail: None,
+        };
+        for column in User::COLUMNS {
+            if *column != "mail" {
+                assert_ne!(user.column(column), Value::Null, "column {}", column);
+            }
+        }
+        assert_eq!(cell_text(&user.column("name")), "Jane Doe");
+        assert_eq!(cell_text(&user.column("mail")), "");
+    }
+}
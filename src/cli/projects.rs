@@ -0,0 +1,55 @@
+use anyhow::Result;
+
+use super::output::{print_listing, OutputArgs};
+use super::{CliError, Context};
+use crate::redmine::Project;
+
+/// Fetch every project from the server, following pagination
+pub(crate) async fn fetch_all(ctx: &Context) -> Result<Vec<Project>> {
+    let limit = 100;
+    let mut offset = 0;
+    let mut projects = Vec::new();
+
+    loop {
+        let response = ctx.client.get_projects(limit, offset).await?;
+        let count = response.projects.len();
+        projects.extend(response.projects);
+
+        if count < limit as usize {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(projects)
+}
+
+pub(crate) async fn list(ctx: &Context, output: &OutputArgs) -> Result<()> {
+    let projects = fetch_all(ctx).await?;
+
+    if let Err(e) = ctx.db.insert_projects(&projects) {
+        tracing::warn!("Failed to cache projects: {}", e);
+    }
+
+    print_listing(&projects, output)
+}
+
+/// Resolve a project id or identifier to a numeric id, checking the cache before the server
+pub(crate) async fn resolve_id(ctx: &Context, project: &str) -> Result<u64> {
+    if let Ok(id) = project.parse::<u64>() {
+        return Ok(id);
+    }
+
+    let matches = |p: &Project| p.identifier == project;
+
+    if let Some(p) = ctx.db.get_projects(None)?.into_iter().find(matches) {
+        return Ok(p.id);
+    }
+
+    fetch_all(ctx)
+        .await?
+        .into_iter()
+        .find(matches)
+        .map(|p| p.id)
+        .ok_or_else(|| CliError::NotFound(format!("Project '{}' not found", project)).into())
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+
+use super::output::{print_listing, OutputArgs};
+use super::Context;
+
+pub(crate) async fn list(ctx: &Context, output: &OutputArgs) -> Result<()> {
+    let limit = 100;
+    let mut offset = 0;
+    let mut users = Vec::new();
+
+    loop {
+        let response = ctx.client.get_users(limit, offset).await?;
+        let count = response.users.len();
+        users.extend(response.users);
+
+        if count < limit as usize {
+            break;
+        }
+        offset += limit;
+    }
+
+    if let Err(e) = ctx.db.insert_users(&users) {
+        tracing::warn!("Failed to cache users: {}", e);
+    }
+
+    print_listing(&users, output)
+}
@@ -1,16 +1,66 @@
+use std::collections::BTreeMap;
+
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
 use crate::theme::ThemeName;
 
+/// Name of the profile stored in the top-level `redmine_url`/`api_key` keys
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Environment variable that overrides the API key of the profile minecli starts with
+pub const API_KEY_ENV: &str = "MINECLI_API_KEY";
+
+/// Where the API key in use came from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ApiKeySource {
+    /// The `MINECLI_API_KEY` environment variable
+    Environment,
+    /// Output of the profile's `api_key_command`
+    Command,
+    /// The `api_key` stored in the config file
+    ConfigFile,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// Server of the default profile
     pub redmine_url: String,
+    #[serde(default)]
     pub api_key: String,
+    /// Shell command printing the API key, used instead of `api_key` (e.g. `pass show redmine`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key_command: Option<String>,
     #[serde(default)]
     pub theme: ThemeName,
     #[serde(default)]
     pub exclude_subprojects: bool,
+    /// Profile used on startup (the default profile when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
+    /// Additional named servers
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+    /// Profile selected with `--profile` for this session only
+    #[serde(skip)]
+    session_profile: Option<String>,
+    /// Key obtained from the environment or `api_key_command`, never saved
+    #[serde(skip)]
+    resolved_api_key: Option<(ApiKeySource, String)>,
+    /// Profile `MINECLI_API_KEY` belongs to: the one in use when the key was first resolved
+    #[serde(skip)]
+    environment_key_profile: Option<String>,
+}
+
+/// Connection settings of a named server profile
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Profile {
+    #[serde(default)]
+    pub redmine_url: String,
+    #[serde(default)]
+    pub api_key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key_command: Option<String>,
 }
 
 impl Default for Config {
@@ -18,8 +68,14 @@ impl Default for Config {
         Self {
             redmine_url: String::new(),
             api_key: String::new(),
+            api_key_command: None,
             theme: ThemeName::default(),
             exclude_subprojects: true,
+            active_profile: None,
+            profiles: BTreeMap::new(),
+            session_profile: None,
+            resolved_api_key: None,
+            environment_key_profile: None,
         }
     }
 }
@@ -34,8 +90,185 @@ impl Config {
     }
 
     pub fn is_configured(&self) -> bool {
-        !self.api_key.is_empty() && !self.redmine_url.is_empty()
+        !self.api_key().is_empty() && !self.redmine_url().is_empty()
+    }
+
+    /// Name of the profile in use
+    pub fn profile_name(&self) -> &str {
+        self.session_profile
+            .as_deref()
+            .or(self.active_profile.as_deref())
+            .filter(|name| self.profiles.contains_key(*name))
+            .unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// All profile names, default first
+    pub fn profile_names(&self) -> Vec<String> {
+        std::iter::once(DEFAULT_PROFILE.to_string())
+            .chain(self.profiles.keys().cloned())
+            .collect()
+    }
+
+    fn active(&self) -> Option<&Profile> {
+        self.profiles.get(self.profile_name())
+    }
+
+    pub fn redmine_url(&self) -> &str {
+        self.active().map_or(&self.redmine_url, |p| &p.redmine_url)
+    }
+
+    /// API key of the active profile, preferring a key resolved by `resolve_api_key`
+    pub fn api_key(&self) -> &str {
+        match &self.resolved_api_key {
+            Some((_, key)) => key,
+            None => self.stored_api_key(),
+        }
+    }
+
+    fn stored_api_key(&self) -> &str {
+        self.active().map_or(&self.api_key, |p| &p.api_key)
+    }
+
+    pub fn api_key_command(&self) -> Option<&str> {
+        self.active()
+            .map_or(self.api_key_command.as_deref(), |p| p.api_key_command.as_deref())
+            .filter(|c| !c.trim().is_empty())
+    }
+
+    /// Where the API key in use comes from, or `None` if there is no key
+    pub fn api_key_source(&self) -> Option<ApiKeySource> {
+        match &self.resolved_api_key {
+            Some((source, _)) => Some(*source),
+            None if !self.stored_api_key().is_empty() => Some(ApiKeySource::ConfigFile),
+            None => None,
+        }
+    }
+
+    /// Look up the API key of the active profile: `MINECLI_API_KEY` first, then `api_key_command`,
+    /// falling back to the key stored in the config file
+    pub fn resolve_api_key(&mut self) -> Result<()> {
+        self.resolve_api_key_from(std::env::var(API_KEY_ENV).ok())
+    }
+
+    fn resolve_api_key_from(&mut self, environment_key: Option<String>) -> Result<()> {
+        self.resolved_api_key = None;
+
+        // The environment's key is for the server minecli started with. Profiles switched to
+        // later use their own key, so it is never sent to a different server.
+        let profile = self.profile_name().to_string();
+        let key_profile = self.environment_key_profile.get_or_insert_with(|| profile.clone());
+        let environment_key = environment_key.filter(|k| !k.trim().is_empty() && *key_profile == profile);
+
+        if let Some(key) = environment_key {
+            self.resolved_api_key = Some((ApiKeySource::Environment, key.trim().to_string()));
+        } else if let Some(command) = self.api_key_command() {
+            let key = run_api_key_command(command)?;
+            self.resolved_api_key = Some((ApiKeySource::Command, key));
+        }
+        Ok(())
+    }
+
+    pub fn set_redmine_url(&mut self, url: String) {
+        let name = self.profile_name().to_string();
+        match self.profiles.get_mut(&name) {
+            Some(profile) => profile.redmine_url = url,
+            None => self.redmine_url = url,
+        }
+    }
+
+    /// Store the API key in the config file. Has no effect on the key in use while
+    /// it comes from the environment or a command.
+    pub fn set_api_key(&mut self, api_key: String) {
+        let name = self.profile_name().to_string();
+        match self.profiles.get_mut(&name) {
+            Some(profile) => profile.api_key = api_key,
+            None => self.api_key = api_key,
+        }
+    }
+
+    /// Use a profile for this session without changing the saved startup profile
+    pub fn use_profile(&mut self, name: &str) -> Result<()> {
+        self.ensure_profile_exists(name)?;
+        self.session_profile = Some(name.to_string());
+        self.resolved_api_key = None;
+        Ok(())
+    }
+
+    /// Make a profile active and remember it as the startup profile
+    pub fn switch_profile(&mut self, name: &str) -> Result<()> {
+        self.ensure_profile_exists(name)?;
+        self.session_profile = None;
+        self.active_profile = (name != DEFAULT_PROFILE).then(|| name.to_string());
+        self.resolved_api_key = None;
+        Ok(())
+    }
+
+    /// Add an empty profile. Names may contain letters, digits, `-` and `_`
+    /// since they are also used in the cache file name.
+    pub fn add_profile(&mut self, name: &str) -> Result<()> {
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            anyhow::bail!("Profile names may only contain letters, digits, '-' and '_'");
+        }
+        if name == DEFAULT_PROFILE || self.profiles.contains_key(name) {
+            anyhow::bail!("Profile '{}' already exists", name);
+        }
+        self.profiles.insert(name.to_string(), Profile::default());
+        Ok(())
     }
+
+    /// Remove a named profile. The default profile and the profile in use can't be removed.
+    pub fn remove_profile(&mut self, name: &str) -> Result<()> {
+        if name == DEFAULT_PROFILE {
+            anyhow::bail!("The default profile can't be removed");
+        }
+        if name == self.profile_name() {
+            anyhow::bail!("Switch to another profile before removing '{}'", name);
+        }
+        self.ensure_profile_exists(name)?;
+        self.profiles.remove(name);
+        if self.active_profile.as_deref() == Some(name) {
+            self.active_profile = None;
+        }
+        Ok(())
+    }
+
+    fn ensure_profile_exists(&self, name: &str) -> Result<()> {
+        if name != DEFAULT_PROFILE && !self.profiles.contains_key(name) {
+            anyhow::bail!(
+                "Unknown profile '{}' (available: {})",
+                name,
+                self.profile_names().join(", ")
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Run `api_key_command` through the shell and return its trimmed output
+fn run_api_key_command(command: &str) -> Result<String> {
+    let output = if cfg!(windows) {
+        std::process::Command::new("cmd").args(["/C", command]).output()
+    } else {
+        std::process::Command::new("sh").args(["-c", command]).output()
+    }
+    .with_context(|| format!("Failed to run api_key_command '{}'", command))?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "api_key_command '{}' failed ({}): {}",
+            command,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    // Only the first line counts, so `pass show` entries with extra metadata work
+    let stdout = String::from_utf8(output.stdout).context("api_key_command printed invalid UTF-8")?;
+    let key = stdout.lines().next().unwrap_or("").trim().to_string();
+    if key.is_empty() {
+        anyhow::bail!("api_key_command '{}' printed nothing", command);
+    }
+    Ok(key)
 }
 
 #[cfg(test)]
@@ -56,7 +289,7 @@ mod tests {
         assert!(!config.is_configured());
 
         config.api_key = "test_key".to_string();
-        config.redmine_url = "https://example.com/redmine";
+        config.redmine_url = "https://example.com/redmine".to_string();
         assert!(config.is_configured());
 
         config.api_key = String::new();
@@ -71,8 +304,121 @@ mod tests {
             api_key: "my_api_key".to_string(),
             theme: ThemeName::default(),
             exclude_subprojects: true,
+            ..Default::default()
         };
         assert!(config.is_configured());
         assert_eq!(config.redmine_url, "https://example.com/redmine");
     }
+
+    #[test]
+    fn test_profiles() {
+        let mut config = Config {
+            redmine_url: "https://prod.example.com".to_string(),
+            api_key: "prod_key".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.profile_name(), DEFAULT_PROFILE);
+
+        config.add_profile("client").unwrap();
+        assert!(config.add_profile("client").is_err());
+        assert!(config.add_profile("bad name").is_err());
+        assert_eq!(config.profile_names(), vec!["default", "client"]);
+
+        config.switch_profile("client").unwrap();
+        assert_eq!(config.active_profile.as_deref(), Some("client"));
+        assert!(!config.is_configured());
+
+        config.set_redmine_url("https://client.example.com".to_string());
+        config.set_api_key("client_key".to_string());
+        assert_eq!(config.redmine_url(), "https://client.example.com");
+        assert_eq!(config.api_key(), "client_key");
+        // The default profile is untouched
+        assert_eq!(config.redmine_url, "https://prod.example.com");
+
+        assert!(config.remove_profile("client").is_err());
+        config.switch_profile(DEFAULT_PROFILE).unwrap();
+        assert_eq!(config.active_profile, None);
+        assert_eq!(config.api_key(), "prod_key");
+        config.remove_profile("client").unwrap();
+        assert_eq!(config.profile_names(), vec!["default"]);
+    }
+
+    #[test]
+    fn test_session_profile_is_not_saved() {
+        let mut config = Config::default();
+        config.add_profile("client").unwrap();
+        config.use_profile("client").unwrap();
+        assert_eq!(config.profile_name(), "client");
+        assert_eq!(config.active_profile, None);
+        assert!(config.use_profile("missing").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_api_key_command() {
+        let mut config = Config {
+            redmine_url: "https://example.com".to_string(),
+            api_key_command: Some("printf 'secret_key\\nlogin: me\\n'".to_string()),
+            ..Default::default()
+        };
+        assert!(!config.is_configured());
+        assert_eq!(config.api_key_source(), None);
+
+        // Only resolve through the command when the environment doesn't override it
+        if std::env::var(API_KEY_ENV).is_err() {
+            config.resolve_api_key().unwrap();
+            assert_eq!(config.api_key(), "secret_key");
+            assert_eq!(config.api_key_source(), Some(ApiKeySource::Command));
+            assert!(config.is_configured());
+        }
+
+        config.api_key_command = Some("exit 3".to_string());
+        if std::env::var(API_KEY_ENV).is_err() {
+            assert!(config.resolve_api_key().is_err());
+        }
+    }
+
+    #[test]
+    fn test_environment_key_stays_with_startup_profile() {
+        let mut config = Config {
+            redmine_url: "https://prod.example.com".to_string(),
+            ..Default::default()
+        };
+        config.add_profile("client").unwrap();
+        config.resolve_api_key_from(Some("env_key".to_string())).unwrap();
+        assert_eq!(config.api_key(), "env_key");
+        assert_eq!(config.api_key_source(), Some(ApiKeySource::Environment));
+
+        // Another profile only uses its own key, and has none
+        config.switch_profile("client").unwrap();
+        config.set_redmine_url("https://client.example.com".to_string());
+        config.resolve_api_key_from(Some("env_key".to_string())).unwrap();
+        assert_eq!(config.api_key(), "");
+        assert_eq!(config.api_key_source(), None);
+        assert!(!config.is_configured());
+
+        config.set_api_key("client_key".to_string());
+        assert_eq!(config.api_key(), "client_key");
+
+        // Back on the startup profile the environment's key applies again
+        config.switch_profile(DEFAULT_PROFILE).unwrap();
+        config.resolve_api_key_from(Some("env_key".to_string())).unwrap();
+        assert_eq!(config.api_key(), "env_key");
+    }
+
+    #[test]
+    fn test_resolved_key_is_not_saved() {
+        let mut config = Config {
+            api_key: "stored".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.api_key_source(), Some(ApiKeySource::ConfigFile));
+
+        config.resolved_api_key = Some((ApiKeySource::Environment, "from_env".to_string()));
+        assert_eq!(config.api_key(), "from_env");
+
+        let saved = serde_json::to_string(&config).unwrap();
+        assert!(saved.contains("stored"));
+        assert!(!saved.contains("from_env"));
+    }
 }
@@ -1,10 +1,17 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection, OptionalExtension};
-use std::path::PathBuf;
-
-use crate::app::IssueSortOrder;
-use crate::redmine::{Issue, Journal, Project};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::app::{FilterPreset, IssueSortOrder, PendingOperation, QueuedWrite, LOCAL_ID_BASE};
+use crate::issue_query::{
+    Comparison, DateField, FilterQuery, QueryContext, QueryError, StatusValue, Term, UserValue, VersionValue,
+};
+use crate::redmine::{
+    Attachment, IdName, Issue, IssueChild, IssueCustomField, IssueRelation, Journal, Project, SavedQuery, TimeEntry,
+    Version,
+};
 
 /// Helper function to safely parse datetime from database
 /// Returns a proper error instead of panicking
@@ -14,193 +21,899 @@ fn parse_datetime_from_db(s: &str) -> Result<DateTime<Utc>, rusqlite::Error> {
         .map_err(|e| rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e)))
 }
 
-pub struct Database {
-    conn: Connection,
+/// Delete an issue with its journals, custom fields and attachments (children first for the foreign keys)
+fn delete_issue_rows(conn: &Connection, issue_id: u64) -> rusqlite::Result<()> {
+    conn.execute(
+        "DELETE FROM journal_details WHERE journal_id IN (SELECT id FROM journals WHERE issue_id = ?1)",
+        params![issue_id],
+    )?;
+    conn.execute("DELETE FROM journals WHERE issue_id = ?1", params![issue_id])?;
+    conn.execute("DELETE FROM issue_custom_fields WHERE issue_id = ?1", params![issue_id])?;
+    conn.execute("DELETE FROM issue_attachments WHERE issue_id = ?1", params![issue_id])?;
+    conn.execute(
+        "DELETE FROM issue_relations WHERE issue_id = ?1 OR issue_to_id = ?1",
+        params![issue_id],
+    )?;
+    conn.execute("DELETE FROM issue_watchers WHERE issue_id = ?1", params![issue_id])?;
+    conn.execute("DELETE FROM issue_search WHERE rowid = ?1", params![issue_id])?;
+    conn.execute("DELETE FROM issues WHERE id = ?1", params![issue_id])?;
+    Ok(())
 }
 
-impl Database {
-    pub fn new(db_path: PathBuf) -> Result<Self> {
-        // Ensure parent directory exists
-        if let Some(parent) = db_path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
+/// Marks the start of a matched term in `SearchHit::snippet`
+pub const SNIPPET_MATCH_START: char = '\u{2}';
+/// Marks the end of a matched term in `SearchHit::snippet`
+pub const SNIPPET_MATCH_END: char = '\u{3}';
+
+/// An issue matching a full-text search
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub issue: Issue,
+    /// The best matching part of the subject, description or notes, with matched terms
+    /// between `SNIPPET_MATCH_START` and `SNIPPET_MATCH_END`
+    pub snippet: String,
+}
 
-        let conn = Connection::open(db_path).context("Failed to open database")?;
+/// How far a version has come, counted over its cached issues
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VersionProgress {
+    pub total: usize,
+    pub closed: usize,
+    /// Sum of `done_ratio` over the issues, closed issues counting as 100
+    pub done_ratio_sum: u64,
+}
 
-        let db = Self { conn };
-        db.init_schema()?;
-        Ok(db)
+impl VersionProgress {
+    /// Average completion of the issues in percent, as Redmine's roadmap shows it
+    pub fn percent_done(&self) -> u64 {
+        if self.total == 0 {
+            0
+        } else {
+            self.done_ratio_sum / self.total as u64
+        }
     }
+}
 
-    fn init_schema(&self) -> Result<()> {
-        // Projects table
-        self.conn.execute(
-            "CREATE TABLE IF NOT EXISTS projects (
-                id INTEGER PRIMARY KEY,
-                name TEXT NOT NULL,
-                identifier TEXT NOT NULL,
-                description TEXT,
-                status INTEGER,
-                parent_id INTEGER,
-                parent_name TEXT,
-                created_on TEXT,
-                updated_on TEXT,
-                last_issue_activity TEXT,
-                last_issues_sync TEXT
-            )",
-            [],
-        )?;
-
-        // Migration: Add last_issue_activity column if it doesn't exist
-        let _ = self
-            .conn
-            .execute("ALTER TABLE projects ADD COLUMN last_issue_activity TEXT", []);
-
-        // Migration: Add last_issues_sync column if it doesn't exist
-        let _ = self
-            .conn
-            .execute("ALTER TABLE projects ADD COLUMN last_issues_sync TEXT", []);
+/// Condition matching closed issues: by status when the closed statuses are known,
+/// otherwise by close date
+fn closed_condition(context: &QueryContext) -> String {
+    if context.closed_status_ids.is_empty() {
+        "closed_on IS NOT NULL".to_string()
+    } else {
+        let ids: Vec<String> = context.closed_status_ids.iter().map(u64::to_string).collect();
+        format!("status_id IN ({})", ids.join(", "))
+    }
+}
 
-        // Migration: Add parent_id column if it doesn't exist
-        let _ = self
-            .conn
-            .execute("ALTER TABLE projects ADD COLUMN parent_id INTEGER", []);
+/// Turn user input into an FTS5 query: every word must match as a prefix, and FTS5 syntax
+/// characters are taken literally. `None` when nothing searchable is left.
+fn fts_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
 
-        // Migration: Add parent_name column if it doesn't exist
-        let _ = self
-            .conn
-            .execute("ALTER TABLE projects ADD COLUMN parent_name TEXT", []);
+/// Rebuild the search index entry of an issue from its cached row and journal notes
+fn index_issue(conn: &Connection, issue_id: u64) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM issue_search WHERE rowid = ?1", params![issue_id])?;
+    conn.execute(
+        "INSERT INTO issue_search (rowid, subject, description, notes)
+        SELECT id, subject, COALESCE(description, ''),
+            COALESCE((SELECT group_concat(notes, char(10)) FROM journals
+                      WHERE journals.issue_id = issues.id AND notes <> ''), '')
+        FROM issues WHERE id = ?1",
+        params![issue_id],
+    )?;
+    Ok(())
+}
 
-        // Issues table
-        self.conn.execute(
-            "CREATE TABLE IF NOT EXISTS issues (
-                id INTEGER PRIMARY KEY,
-                project_id INTEGER NOT NULL,
-                tracker_id INTEGER NOT NULL,
-                tracker_name TEXT NOT NULL,
-                status_id INTEGER NOT NULL,
-                status_name TEXT NOT NULL,
-                priority_id INTEGER NOT NULL,
-                priority_name TEXT NOT NULL,
-                author_id INTEGER NOT NULL,
-                author_name TEXT NOT NULL,
-                assigned_to_id INTEGER,
-                assigned_to_name TEXT,
-                subject TEXT NOT NULL,
-                description TEXT,
-                created_on TEXT NOT NULL,
-                updated_on TEXT NOT NULL,
-                due_date TEXT,
-                done_ratio INTEGER
-            )",
-            [],
+/// Columns of the issues table, in the order `upsert_issue` binds them
+const ISSUE_COLUMNS: &str = "id, project_id, project_name, tracker_id, tracker_name, status_id, status_name,
+    priority_id, priority_name, author_id, author_name, assigned_to_id, assigned_to_name,
+    parent_id, parent_name, category_id, category_name, fixed_version_id, fixed_version_name,
+    subject, description, start_date, due_date, done_ratio, is_private,
+    estimated_hours, total_estimated_hours, spent_hours, total_spent_hours,
+    created_on, updated_on, closed_on";
+
+/// Insert or replace an issue row and its custom fields. Attachments are left alone because
+/// issue lists don't include them; `replace_attachments` writes them from a full issue fetch.
+fn upsert_issue(conn: &Connection, issue: &Issue) -> rusqlite::Result<()> {
+    let id = |r: &Option<IdName>| r.as_ref().map(|r| r.id);
+    let name = |r: &Option<IdName>| r.as_ref().map(|r| r.name.clone());
+
+    conn.execute(
+        &format!(
+            "INSERT OR REPLACE INTO issues ({}) VALUES
+            (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+             ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32)",
+            ISSUE_COLUMNS
+        ),
+        params![
+            issue.id,
+            issue.project.id,
+            &issue.project.name,
+            issue.tracker.id,
+            &issue.tracker.name,
+            issue.status.id,
+            &issue.status.name,
+            issue.priority.id,
+            &issue.priority.name,
+            issue.author.id,
+            &issue.author.name,
+            id(&issue.assigned_to),
+            name(&issue.assigned_to),
+            id(&issue.parent),
+            name(&issue.parent),
+            id(&issue.category),
+            name(&issue.category),
+            id(&issue.fixed_version),
+            name(&issue.fixed_version),
+            &issue.subject,
+            &issue.description,
+            &issue.start_date,
+            &issue.due_date,
+            issue.done_ratio,
+            issue.is_private,
+            issue.estimated_hours,
+            issue.total_estimated_hours,
+            issue.spent_hours,
+            issue.total_spent_hours,
+            issue.created_on.to_rfc3339(),
+            issue.updated_on.to_rfc3339(),
+            issue.closed_on.map(|d| d.to_rfc3339()),
+        ],
+    )?;
+
+    conn.execute("DELETE FROM issue_custom_fields WHERE issue_id = ?1", params![issue.id])?;
+    for (position, field) in issue.custom_fields.iter().enumerate() {
+        conn.execute(
+            "INSERT INTO issue_custom_fields (issue_id, position, custom_field_id, name, value)
+            VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![issue.id, position, field.id, &field.name, &field.value],
         )?;
+    }
+    Ok(())
+}
 
-        // Users table
-        self.conn.execute(
-            "CREATE TABLE IF NOT EXISTS users (
-                id INTEGER PRIMARY KEY,
-                login TEXT NOT NULL,
-                firstname TEXT NOT NULL,
-                lastname TEXT NOT NULL,
-                mail TEXT,
-                cached_at TEXT NOT NULL
-            )",
-            [],
+/// Replace the cached attachment metadata of an issue
+fn replace_attachments(conn: &Connection, issue: &Issue) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM issue_attachments WHERE issue_id = ?1", params![issue.id])?;
+    for attachment in &issue.attachments {
+        conn.execute(
+            "INSERT OR REPLACE INTO issue_attachments
+            (id, issue_id, filename, filesize, content_type, description, content_url, thumbnail_url,
+             author_id, author_name, created_on)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                attachment.id,
+                issue.id,
+                &attachment.filename,
+                attachment.filesize,
+                &attachment.content_type,
+                &attachment.description,
+                &attachment.content_url,
+                &attachment.thumbnail_url,
+                attachment.author.id,
+                &attachment.author.name,
+                attachment.created_on.to_rfc3339(),
+            ],
         )?;
+    }
+    Ok(())
+}
 
-        // Journals table
-        self.conn.execute(
-            "CREATE TABLE IF NOT EXISTS journals (
-                id INTEGER PRIMARY KEY,
-                issue_id INTEGER NOT NULL,
-                user_id INTEGER NOT NULL,
-                user_name TEXT NOT NULL,
-                notes TEXT,
-                created_on TEXT NOT NULL,
-                FOREIGN KEY(issue_id) REFERENCES issues(id)
-            )",
-            [],
+/// Replace the cached relations of an issue, in either direction, with those of a full issue fetch
+fn replace_relations(conn: &Connection, issue: &Issue) -> rusqlite::Result<()> {
+    conn.execute(
+        "DELETE FROM issue_relations WHERE issue_id = ?1 OR issue_to_id = ?1",
+        params![issue.id],
+    )?;
+    for relation in &issue.relations {
+        conn.execute(
+            "INSERT OR REPLACE INTO issue_relations (id, issue_id, issue_to_id, relation_type, delay)
+            VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                relation.id,
+                relation.issue_id,
+                relation.issue_to_id,
+                &relation.relation_type,
+                relation.delay,
+            ],
         )?;
+    }
+    Ok(())
+}
 
-        // Journal details table
-        self.conn.execute(
-            "CREATE TABLE IF NOT EXISTS journal_details (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                journal_id INTEGER NOT NULL,
-                property TEXT NOT NULL,
-                name TEXT NOT NULL,
-                old_value TEXT,
-                new_value TEXT,
-                FOREIGN KEY(journal_id) REFERENCES journals(id)
-            )",
-            [],
+/// Replace the cached watchers of an issue with those of a full issue fetch
+fn replace_watchers(conn: &Connection, issue: &Issue) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM issue_watchers WHERE issue_id = ?1", params![issue.id])?;
+    for watcher in &issue.watchers {
+        conn.execute(
+            "INSERT OR REPLACE INTO issue_watchers (issue_id, user_id, user_name) VALUES (?1, ?2, ?3)",
+            params![issue.id, watcher.id, &watcher.name],
         )?;
+    }
+    Ok(())
+}
 
-        // Metadata table for tracking last sync times
-        self.conn.execute(
-            "CREATE TABLE IF NOT EXISTS metadata (
-                key TEXT PRIMARY KEY,
-                value TEXT NOT NULL
-            )",
-            [],
-        )?;
+/// Read a reference stored as an id column and a name column
+fn id_name_from_row(row: &rusqlite::Row, id_column: &str, name_column: &str) -> rusqlite::Result<Option<IdName>> {
+    let id: Option<u64> = row.get(id_column)?;
+    let name: Option<String> = row.get(name_column)?;
+    Ok(id.map(|id| IdName {
+        id,
+        name: name.unwrap_or_default(),
+    }))
+}
 
-        // Create indexes for performance
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_projects_updated ON projects(updated_on DESC)",
-            [],
-        )?;
-        self.conn
-            .execute("CREATE INDEX IF NOT EXISTS idx_projects_name ON projects(name)", [])?;
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_issues_project ON issues(project_id)",
-            [],
-        )?;
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_issues_updated ON issues(updated_on DESC)",
-            [],
-        )?;
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_issues_status ON issues(status_name)",
-            [],
-        )?;
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_issues_priority ON issues(priority_id)",
-            [],
-        )?;
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_issues_assigned ON issues(assigned_to_id)",
-            [],
-        )?;
-        self.conn
-            .execute("CREATE INDEX IF NOT EXISTS idx_issues_subject ON issues(subject)", [])?;
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_issues_created ON issues(created_on DESC)",
-            [],
-        )?;
-        // Composite index for common query pattern (project + updated)
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_issues_project_updated ON issues(project_id, updated_on DESC)",
-            [],
-        )?;
-        // Composite index for filtering by assignee within project
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_issues_project_assigned ON issues(project_id, assigned_to_id)",
-            [],
-        )?;
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_journals_issue ON journals(issue_id)",
+/// Build an issue from a row selected with `ISSUE_COLUMNS`. Journals, custom fields,
+/// attachments and relations live in child tables and are filled in by `load_issue_children`.
+fn issue_from_row(row: &rusqlite::Row) -> rusqlite::Result<Issue> {
+    let required = |id_column: &str, name_column: &str| -> rusqlite::Result<IdName> {
+        Ok(IdName {
+            id: row.get(id_column)?,
+            name: row.get(name_column)?,
+        })
+    };
+
+    Ok(Issue {
+        id: row.get("id")?,
+        project: IdName {
+            id: row.get("project_id")?,
+            name: row.get::<_, Option<String>>("project_name")?.unwrap_or_default(),
+        },
+        tracker: required("tracker_id", "tracker_name")?,
+        status: required("status_id", "status_name")?,
+        priority: required("priority_id", "priority_name")?,
+        author: required("author_id", "author_name")?,
+        assigned_to: id_name_from_row(row, "assigned_to_id", "assigned_to_name")?,
+        parent: id_name_from_row(row, "parent_id", "parent_name")?,
+        category: id_name_from_row(row, "category_id", "category_name")?,
+        fixed_version: id_name_from_row(row, "fixed_version_id", "fixed_version_name")?,
+        subject: row.get("subject")?,
+        description: row.get("description")?,
+        start_date: row.get("start_date")?,
+        due_date: row.get("due_date")?,
+        done_ratio: row.get("done_ratio")?,
+        is_private: row.get("is_private")?,
+        estimated_hours: row.get("estimated_hours")?,
+        total_estimated_hours: row.get("total_estimated_hours")?,
+        spent_hours: row.get("spent_hours")?,
+        total_spent_hours: row.get("total_spent_hours")?,
+        created_on: parse_datetime_from_db(&row.get::<_, String>("created_on")?)?,
+        updated_on: parse_datetime_from_db(&row.get::<_, String>("updated_on")?)?,
+        closed_on: row
+            .get::<_, Option<String>>("closed_on")?
+            .map(|s| parse_datetime_from_db(&s))
+            .transpose()?,
+        journals: Vec::new(),
+        custom_fields: Vec::new(),
+        attachments: Vec::new(),
+        relations: Vec::new(),
+        children: Vec::new(),
+        watchers: Vec::new(),
+    })
+}
+
+/// Issues whose child rows are read with one query per table
+const CHILDREN_BATCH_SIZE: usize = 500;
+
+/// Fill in the issues' custom fields, attachment metadata, relations and watchers from their
+/// child tables, with one query per table for every `CHILDREN_BATCH_SIZE` issues
+fn load_issue_children(conn: &Connection, issues: &mut [Issue]) -> rusqlite::Result<()> {
+    for batch in issues.chunks_mut(CHILDREN_BATCH_SIZE) {
+        load_issue_children_batch(conn, batch)?;
+    }
+    Ok(())
+}
+
+fn load_issue_children_batch(conn: &Connection, issues: &mut [Issue]) -> rusqlite::Result<()> {
+    if issues.is_empty() {
+        return Ok(());
+    }
+    let positions: HashMap<u64, usize> = issues.iter().enumerate().map(|(i, issue)| (issue.id, i)).collect();
+    let ids: Vec<u64> = issues.iter().map(|issue| issue.id).collect();
+    // Numbered placeholders so a query can use the id list twice
+    let placeholders = (1..=ids.len()).map(|i| format!("?{}", i)).collect::<Vec<_>>().join(",");
+    let ids = rusqlite::params_from_iter(ids);
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT issue_id, custom_field_id, name, value FROM issue_custom_fields
+         WHERE issue_id IN ({}) ORDER BY issue_id, position",
+        placeholders
+    ))?;
+    let mut rows = stmt.query(ids.clone())?;
+    while let Some(row) = rows.next()? {
+        if let Some(&i) = positions.get(&row.get(0)?) {
+            issues[i].custom_fields.push(IssueCustomField {
+                id: row.get(1)?,
+                name: row.get(2)?,
+                value: row.get(3)?,
+            });
+        }
+    }
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT issue_id, id, filename, filesize, content_type, description, content_url, thumbnail_url,
+         author_id, author_name, created_on
+         FROM issue_attachments WHERE issue_id IN ({}) ORDER BY issue_id, created_on, id",
+        placeholders
+    ))?;
+    let mut rows = stmt.query(ids.clone())?;
+    while let Some(row) = rows.next()? {
+        if let Some(&i) = positions.get(&row.get(0)?) {
+            issues[i].attachments.push(Attachment {
+                id: row.get(1)?,
+                filename: row.get(2)?,
+                filesize: row.get(3)?,
+                content_type: row.get(4)?,
+                description: row.get(5)?,
+                content_url: row.get(6)?,
+                thumbnail_url: row.get(7)?,
+                author: IdName {
+                    id: row.get(8)?,
+                    name: row.get(9)?,
+                },
+                created_on: parse_datetime_from_db(&row.get::<_, String>(10)?)?,
+            });
+        }
+    }
+
+    // A relation belongs to the issues at both of its ends
+    let mut stmt = conn.prepare(&format!(
+        "SELECT id, issue_id, issue_to_id, relation_type, delay
+         FROM issue_relations WHERE issue_id IN ({0}) OR issue_to_id IN ({0}) ORDER BY id",
+        placeholders
+    ))?;
+    let mut rows = stmt.query(ids.clone())?;
+    while let Some(row) = rows.next()? {
+        let relation = IssueRelation {
+            id: row.get(0)?,
+            issue_id: row.get(1)?,
+            issue_to_id: row.get(2)?,
+            relation_type: row.get(3)?,
+            delay: row.get(4)?,
+        };
+        if let Some(&i) = positions.get(&relation.issue_to_id) {
+            if relation.issue_to_id != relation.issue_id {
+                issues[i].relations.push(relation.clone());
+            }
+        }
+        if let Some(&i) = positions.get(&relation.issue_id) {
+            issues[i].relations.push(relation);
+        }
+    }
+
+    let mut stmt = conn.prepare(&format!(
+        "SELECT issue_id, user_id, user_name FROM issue_watchers
+         WHERE issue_id IN ({}) ORDER BY issue_id, user_name",
+        placeholders
+    ))?;
+    let mut rows = stmt.query(ids)?;
+    while let Some(row) = rows.next()? {
+        if let Some(&i) = positions.get(&row.get(0)?) {
+            issues[i].watchers.push(IdName {
+                id: row.get(1)?,
+                name: row.get(2)?,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Deepest subtask level read from the cache, in case parent ids form a cycle
+const MAX_SUBTASK_DEPTH: usize = 10;
+
+/// Build an issue's subtask tree from the parent ids of the cached issues
+fn load_subtasks(conn: &Connection, parent_id: u64, depth: usize) -> rusqlite::Result<Vec<IssueChild>> {
+    if depth >= MAX_SUBTASK_DEPTH {
+        return Ok(Vec::new());
+    }
+    let children: Vec<IssueChild> = conn
+        .prepare_cached("SELECT id, tracker_id, tracker_name, subject FROM issues WHERE parent_id = ?1 ORDER BY id")?
+        .query_map(params![parent_id], |row| {
+            Ok(IssueChild {
+                id: row.get(0)?,
+                tracker: IdName {
+                    id: row.get(1)?,
+                    name: row.get(2)?,
+                },
+                subject: row.get(3)?,
+                children: Vec::new(),
+            })
+        })?
+        .collect::<Result<_, _>>()?;
+
+    children
+        .into_iter()
+        .map(|mut child| {
+            child.children = load_subtasks(conn, child.id, depth + 1)?;
+            Ok(child)
+        })
+        .collect()
+}
+
+/// A migration brings the schema from the previous version to the next one
+type Migration = fn(&Transaction) -> rusqlite::Result<()>;
+
+/// Schema migrations in order; the cache is at version N once the first N have run.
+/// Append new migrations to the end and never change one that has been released.
+const MIGRATIONS: &[Migration] = &[
+    migrate_v1, migrate_v2, migrate_v3, migrate_v4, migrate_v5, migrate_v6, migrate_v7, migrate_v8, migrate_v9,
+];
+
+/// Schema version this build creates and understands
+pub const SCHEMA_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Metadata key holding the schema version of a cache database
+const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// Metadata key prefix for the time of a project's last full issue sync, followed by the project id
+const FULL_SYNC_KEY_PREFIX: &str = "issues_full_sync:";
+
+/// The cache was written by a newer minecli whose schema this build doesn't know
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "Cache database is at schema version {found}, but this minecli only supports up to version {supported}. \
+     Upgrade minecli, or run `minecli cache reset` to start with an empty cache"
+)]
+pub struct SchemaTooNew {
+    pub found: u32,
+    pub supported: u32,
+}
+
+/// Add a column unless the table already has it
+fn add_column_if_missing(tx: &Transaction, table: &str, column: &str, definition: &str) -> rusqlite::Result<()> {
+    let exists: bool = tx.query_row(
+        &format!("SELECT COUNT(*) > 0 FROM pragma_table_info('{}') WHERE name = ?1", table),
+        params![column],
+        |row| row.get(0),
+    )?;
+    if !exists {
+        tx.execute(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition), [])?;
+    }
+    Ok(())
+}
+
+/// Version 1: the schema as it was before versioning. Caches created by older releases may
+/// already have some of it, so every step tolerates existing tables and columns.
+fn migrate_v1(tx: &Transaction) -> rusqlite::Result<()> {
+    // Projects table
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS projects (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            identifier TEXT NOT NULL,
+            description TEXT,
+            status INTEGER,
+            parent_id INTEGER,
+            parent_name TEXT,
+            created_on TEXT,
+            updated_on TEXT,
+            last_issue_activity TEXT,
+            last_issues_sync TEXT
+        )",
+        [],
+    )?;
+
+    // Columns added to projects by releases before schema versioning
+    add_column_if_missing(tx, "projects", "last_issue_activity", "TEXT")?;
+    add_column_if_missing(tx, "projects", "last_issues_sync", "TEXT")?;
+    add_column_if_missing(tx, "projects", "parent_id", "INTEGER")?;
+    add_column_if_missing(tx, "projects", "parent_name", "TEXT")?;
+
+    // Issues table
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS issues (
+            id INTEGER PRIMARY KEY,
+            project_id INTEGER NOT NULL,
+            tracker_id INTEGER NOT NULL,
+            tracker_name TEXT NOT NULL,
+            status_id INTEGER NOT NULL,
+            status_name TEXT NOT NULL,
+            priority_id INTEGER NOT NULL,
+            priority_name TEXT NOT NULL,
+            author_id INTEGER NOT NULL,
+            author_name TEXT NOT NULL,
+            assigned_to_id INTEGER,
+            assigned_to_name TEXT,
+            subject TEXT NOT NULL,
+            description TEXT,
+            created_on TEXT NOT NULL,
+            updated_on TEXT NOT NULL,
+            due_date TEXT,
+            done_ratio INTEGER
+        )",
+        [],
+    )?;
+
+    // Users table
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS users (
+            id INTEGER PRIMARY KEY,
+            login TEXT NOT NULL,
+            firstname TEXT NOT NULL,
+            lastname TEXT NOT NULL,
+            mail TEXT,
+            cached_at TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    // Journals table
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS journals (
+            id INTEGER PRIMARY KEY,
+            issue_id INTEGER NOT NULL,
+            user_id INTEGER NOT NULL,
+            user_name TEXT NOT NULL,
+            notes TEXT,
+            created_on TEXT NOT NULL,
+            FOREIGN KEY(issue_id) REFERENCES issues(id)
+        )",
+        [],
+    )?;
+
+    // Journal details table
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS journal_details (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            journal_id INTEGER NOT NULL,
+            property TEXT NOT NULL,
+            name TEXT NOT NULL,
+            old_value TEXT,
+            new_value TEXT,
+            FOREIGN KEY(journal_id) REFERENCES journals(id)
+        )",
+        [],
+    )?;
+
+    // Time entries table (no foreign key: entries outlive issue cache refreshes)
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS time_entries (
+            id INTEGER PRIMARY KEY,
+            issue_id INTEGER NOT NULL,
+            project_id INTEGER NOT NULL,
+            user_id INTEGER NOT NULL,
+            user_name TEXT NOT NULL,
+            activity_id INTEGER NOT NULL,
+            activity_name TEXT NOT NULL,
+            hours REAL NOT NULL,
+            comments TEXT,
+            spent_on TEXT NOT NULL,
+            created_on TEXT NOT NULL,
+            updated_on TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    // Writes made while offline, replayed in id order (AUTOINCREMENT so local ids are never reused)
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS pending_operations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            issue_id INTEGER NOT NULL,
+            payload TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            last_error TEXT,
+            blocked INTEGER NOT NULL DEFAULT 0
+        )",
+        [],
+    )?;
+
+    // Metadata table for tracking last sync times
+    tx.execute(
+        "CREATE TABLE IF NOT EXISTS metadata (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        )",
+        [],
+    )?;
+
+    // Create indexes for performance
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_projects_updated ON projects(updated_on DESC)",
+        [],
+    )?;
+    tx.execute("CREATE INDEX IF NOT EXISTS idx_projects_name ON projects(name)", [])?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_issues_project ON issues(project_id)",
+        [],
+    )?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_issues_updated ON issues(updated_on DESC)",
+        [],
+    )?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_issues_status ON issues(status_name)",
+        [],
+    )?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_issues_priority ON issues(priority_id)",
+        [],
+    )?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_issues_assigned ON issues(assigned_to_id)",
+        [],
+    )?;
+    tx.execute("CREATE INDEX IF NOT EXISTS idx_issues_subject ON issues(subject)", [])?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_issues_created ON issues(created_on DESC)",
+        [],
+    )?;
+    // Composite index for common query pattern (project + updated)
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_issues_project_updated ON issues(project_id, updated_on DESC)",
+        [],
+    )?;
+    // Composite index for filtering by assignee within project
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_issues_project_assigned ON issues(project_id, assigned_to_id)",
+        [],
+    )?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_journals_issue ON journals(issue_id)",
+        [],
+    )?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_journal_details_journal ON journal_details(journal_id)",
+        [],
+    )?;
+    tx.execute("CREATE INDEX IF NOT EXISTS idx_users_login ON users(login)", [])?;
+    tx.execute(
+        "CREATE INDEX IF NOT EXISTS idx_time_entries_issue ON time_entries(issue_id, spent_on DESC)",
+        [],
+    )?;
+
+    Ok(())
+}
+
+/// Version 2: store every field of an issue, with custom fields and attachment metadata in
+/// child tables. Issues cached before have none of the new data, so projects sync from scratch.
+fn migrate_v2(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "ALTER TABLE issues ADD COLUMN project_name TEXT;
+        ALTER TABLE issues ADD COLUMN parent_id INTEGER;
+        ALTER TABLE issues ADD COLUMN parent_name TEXT;
+        ALTER TABLE issues ADD COLUMN category_id INTEGER;
+        ALTER TABLE issues ADD COLUMN category_name TEXT;
+        ALTER TABLE issues ADD COLUMN fixed_version_id INTEGER;
+        ALTER TABLE issues ADD COLUMN fixed_version_name TEXT;
+        ALTER TABLE issues ADD COLUMN start_date TEXT;
+        ALTER TABLE issues ADD COLUMN is_private INTEGER;
+        ALTER TABLE issues ADD COLUMN estimated_hours REAL;
+        ALTER TABLE issues ADD COLUMN total_estimated_hours REAL;
+        ALTER TABLE issues ADD COLUMN spent_hours REAL;
+        ALTER TABLE issues ADD COLUMN total_spent_hours REAL;
+        ALTER TABLE issues ADD COLUMN closed_on TEXT;
+        ALTER TABLE journals ADD COLUMN private_notes INTEGER NOT NULL DEFAULT 0;
+
+        CREATE TABLE issue_custom_fields (
+            issue_id INTEGER NOT NULL,
+            position INTEGER NOT NULL,
+            custom_field_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            value TEXT NOT NULL,
+            PRIMARY KEY (issue_id, position),
+            FOREIGN KEY(issue_id) REFERENCES issues(id)
+        );
+
+        CREATE TABLE issue_attachments (
+            id INTEGER PRIMARY KEY,
+            issue_id INTEGER NOT NULL,
+            filename TEXT NOT NULL,
+            filesize INTEGER NOT NULL,
+            content_type TEXT,
+            description TEXT NOT NULL,
+            content_url TEXT NOT NULL,
+            thumbnail_url TEXT,
+            author_id INTEGER NOT NULL,
+            author_name TEXT NOT NULL,
+            created_on TEXT NOT NULL,
+            FOREIGN KEY(issue_id) REFERENCES issues(id)
+        );
+        CREATE INDEX idx_issue_attachments_issue ON issue_attachments(issue_id);
+
+        UPDATE projects SET last_issues_sync = NULL;",
+    )
+}
+
+/// Version 3: full-text index over issue subjects, descriptions and journal notes.
+/// The issue id is the rowid; `index_issue` keeps it in step with the issues table.
+fn migrate_v3(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE VIRTUAL TABLE issue_search USING fts5(
+            subject, description, notes,
+            tokenize = 'unicode61 remove_diacritics 2'
+        );
+
+        INSERT INTO issue_search (rowid, subject, description, notes)
+        SELECT id, subject, COALESCE(description, ''),
+            COALESCE((SELECT group_concat(notes, char(10)) FROM journals
+                      WHERE journals.issue_id = issues.id AND notes <> ''), '')
+        FROM issues;",
+    )
+}
+
+/// Version 4: named filter presets for the issue list, optionally bound to a number key
+fn migrate_v4(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE filter_presets (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            slot INTEGER UNIQUE,
+            project_id INTEGER,
+            issue_filter TEXT NOT NULL DEFAULT '',
+            sort_order TEXT NOT NULL,
+            group_by_status INTEGER NOT NULL DEFAULT 0,
+            my_issues INTEGER NOT NULL DEFAULT 0
+        );",
+    )
+}
+
+/// Version 5: saved queries from the Redmine web UI and the issues their last run returned
+fn migrate_v5(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE saved_queries (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL,
+            is_public INTEGER NOT NULL DEFAULT 0,
+            project_id INTEGER
+        );
+
+        CREATE TABLE saved_query_issues (
+            query_id INTEGER NOT NULL,
+            issue_id INTEGER NOT NULL,
+            PRIMARY KEY (query_id, issue_id)
+        );",
+    )
+}
+
+/// Version 6: issues the current user watches
+fn migrate_v6(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch("CREATE TABLE watched_issues (issue_id INTEGER PRIMARY KEY);")
+}
+
+/// Version 7: relations between issues, stored once and read from both ends
+fn migrate_v7(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE issue_relations (
+            id INTEGER PRIMARY KEY,
+            issue_id INTEGER NOT NULL,
+            issue_to_id INTEGER NOT NULL,
+            relation_type TEXT NOT NULL,
+            delay INTEGER
+        );
+        CREATE INDEX idx_issue_relations_issue ON issue_relations(issue_id);
+        CREATE INDEX idx_issue_relations_issue_to ON issue_relations(issue_to_id);",
+    )
+}
+
+/// Version 8: watchers of issues whose details were fetched
+fn migrate_v8(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE issue_watchers (
+            issue_id INTEGER NOT NULL,
+            user_id INTEGER NOT NULL,
+            user_name TEXT NOT NULL DEFAULT '',
+            PRIMARY KEY (issue_id, user_id)
+        );",
+    )
+}
+
+/// Version 9: versions each project can use; a shared version is listed under every project it shows up in
+fn migrate_v9(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE project_versions (
+            project_id INTEGER NOT NULL,
+            id INTEGER NOT NULL,
+            owner_project_id INTEGER NOT NULL,
+            owner_project_name TEXT NOT NULL DEFAULT '',
+            name TEXT NOT NULL,
+            description TEXT,
+            status TEXT NOT NULL,
+            due_date TEXT,
+            sharing TEXT,
+            PRIMARY KEY (project_id, id)
+        );",
+    )
+}
+
+pub struct Database {
+    conn: Connection,
+}
+
+impl Database {
+    /// Location of a profile's cache database in the platform data directory.
+    /// The default profile keeps the original `cache.db` name.
+    pub fn profile_path(profile: &str) -> PathBuf {
+        let file_name = if profile == crate::config::DEFAULT_PROFILE {
+            "cache.db".to_string()
+        } else {
+            format!("cache-{}.db", profile)
+        };
+        directories::ProjectDirs::from("com", "minecli", "minecli")
+            .map(|dirs| dirs.data_dir().join(&file_name))
+            .unwrap_or_else(|| PathBuf::from(format!("redmine-{}", file_name)))
+    }
+
+    /// Open a cache database, bringing its schema up to date
+    pub fn new(db_path: PathBuf) -> Result<Self> {
+        let db = Self::open(db_path)?;
+        db.migrate()?;
+        Ok(db)
+    }
+
+    /// Open a cache database without touching its schema
+    pub fn open(db_path: PathBuf) -> Result<Self> {
+        // Ensure parent directory exists
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let conn = Connection::open(db_path).context("Failed to open database")?;
+        Ok(Self { conn })
+    }
+
+    /// Delete a cache database so the next open starts from an empty schema
+    pub fn reset(db_path: &Path) -> Result<()> {
+        match std::fs::remove_file(db_path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e).with_context(|| format!("Failed to delete {}", db_path.display())),
+        }
+    }
+
+    /// Schema version the cache is at; 0 for a new database or one from before versioning
+    pub fn schema_version(&self) -> Result<u32> {
+        let has_metadata: bool = self.conn.query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = 'metadata'",
             [],
+            |row| row.get(0),
         )?;
-        self.conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_journal_details_journal ON journal_details(journal_id)",
-            [],
+        if !has_metadata {
+            return Ok(0);
+        }
+
+        match self.get_metadata(SCHEMA_VERSION_KEY)? {
+            Some(value) => value
+                .parse()
+                .with_context(|| format!("Invalid schema version '{}' in cache database", value)),
+            None => Ok(0),
+        }
+    }
+
+    /// Run the migrations the cache hasn't had yet, all in one transaction so a failure
+    /// leaves it at its previous version. Returns the version it was at before.
+    pub fn migrate(&self) -> Result<u32> {
+        let from = self.schema_version()?;
+        if from > SCHEMA_VERSION {
+            return Err(SchemaTooNew {
+                found: from,
+                supported: SCHEMA_VERSION,
+            }
+            .into());
+        }
+        if from == SCHEMA_VERSION {
+            return Ok(from);
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        for (index, migration) in MIGRATIONS.iter().enumerate().skip(from as usize) {
+            migration(&tx).with_context(|| format!("Cache migration to schema version {} failed", index + 1))?;
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO metadata (key, value) VALUES (?1, ?2)",
+            params![SCHEMA_VERSION_KEY, SCHEMA_VERSION.to_string()],
         )?;
-        self.conn
-            .execute("CREATE INDEX IF NOT EXISTS idx_users_login ON users(login)", [])?;
+        tx.commit()?;
 
-        Ok(())
+        tracing::info!("Migrated cache database from schema version {} to {}", from, SCHEMA_VERSION);
+        Ok(from)
     }
 
     // Projects
@@ -385,44 +1098,74 @@ impl Database {
             params![project_id],
         )?;
 
+        self.conn.execute(
+            "DELETE FROM issue_search WHERE rowid IN (SELECT id FROM issues WHERE project_id = ?1)",
+            params![project_id],
+        )?;
+
+        for table in ["issue_custom_fields", "issue_attachments"] {
+            self.conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE issue_id IN (SELECT id FROM issues WHERE project_id = ?1)",
+                    table
+                ),
+                params![project_id],
+            )?;
+        }
+
         self.conn
             .execute("DELETE FROM issues WHERE project_id = ?1", params![project_id])?;
 
         Ok(())
     }
 
+    /// Remove cached issues of a project that a full sync no longer returned
+    /// (deleted, moved to a project outside the sync, or made private).
+    /// With `include_subprojects` the issues cached under its subprojects are checked too.
+    /// Returns the number of issues removed.
+    pub fn remove_stale_issues(&self, project_id: u64, include_subprojects: bool, synced_ids: &[u64]) -> Result<usize> {
+        let synced: std::collections::HashSet<u64> = synced_ids.iter().copied().collect();
+
+        let cached: Vec<u64> = self
+            .conn
+            .prepare(
+                "WITH RECURSIVE scope(id) AS (
+                    SELECT ?1
+                    UNION SELECT p.id FROM projects p JOIN scope s ON p.parent_id = s.id WHERE ?2
+                )
+                SELECT id FROM issues WHERE project_id IN (SELECT id FROM scope)",
+            )?
+            .query_map(params![project_id, include_subprojects], |row| row.get(0))?
+            .collect::<Result<_, _>>()?;
+
+        let stale: Vec<u64> = cached.into_iter().filter(|id| !synced.contains(id)).collect();
+        if stale.is_empty() {
+            return Ok(0);
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        for issue_id in &stale {
+            delete_issue_rows(&tx, *issue_id)?;
+        }
+        tx.commit()?;
+
+        Ok(stale.len())
+    }
+
+    /// Remove a single issue and its journals from the cache
+    pub fn delete_issue(&self, issue_id: u64) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        delete_issue_rows(&tx, issue_id)?;
+        tx.commit()?;
+        Ok(())
+    }
+
     pub fn insert_issues(&self, issues: &[Issue]) -> Result<()> {
         let tx = self.conn.unchecked_transaction()?;
 
         for issue in issues {
-            tx.execute(
-                "INSERT OR REPLACE INTO issues
-                (id, project_id, tracker_id, tracker_name, status_id, status_name,
-                 priority_id, priority_name, author_id, author_name,
-                 assigned_to_id, assigned_to_name, subject, description,
-                 created_on, updated_on, due_date, done_ratio)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
-                params![
-                    issue.id,
-                    issue.project.id,
-                    issue.tracker.id,
-                    &issue.tracker.name,
-                    issue.status.id,
-                    &issue.status.name,
-                    issue.priority.id,
-                    &issue.priority.name,
-                    issue.author.id,
-                    &issue.author.name,
-                    issue.assigned_to.as_ref().map(|a| a.id),
-                    issue.assigned_to.as_ref().map(|a| &a.name),
-                    &issue.subject,
-                    &issue.description,
-                    issue.created_on.to_rfc3339(),
-                    issue.updated_on.to_rfc3339(),
-                    &issue.due_date,
-                    issue.done_ratio,
-                ],
-            )?;
+            upsert_issue(&tx, issue)?;
+            index_issue(&tx, issue.id)?;
         }
 
         // Update project's last_issue_activity based on the most recent issue from ALL issues (not just this batch)
@@ -445,55 +1188,25 @@ impl Database {
             }
         }
 
-        // Record per-project sync timestamp
-        let now = Utc::now().to_rfc3339();
-        for project_id in &project_ids {
-            tx.execute(
-                "UPDATE projects SET last_issues_sync = ?1 WHERE id = ?2",
-                params![now, project_id],
-            )?;
-        }
-
         tx.commit()?;
         Ok(())
     }
 
+    /// Cached issues of a project (or of all projects) matching a filter query.
+    /// Fails with a `QueryError` when the query names something that doesn't exist.
     pub fn get_issues(
         &self,
         project_id: Option<u64>,
         sort_order: IssueSortOrder,
-        filter: Option<&str>,
-        assigned_to_id: Option<u64>,
+        filter: &FilterQuery,
+        context: &QueryContext,
     ) -> Result<Vec<Issue>> {
-        let mut query = String::from(
-            "SELECT id, project_id, tracker_id, tracker_name, status_id, status_name,
-             priority_id, priority_name, author_id, author_name,
-             assigned_to_id, assigned_to_name, subject, description,
-             created_on, updated_on, due_date, done_ratio
-             FROM issues WHERE 1=1",
-        );
-
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
-
-        if let Some(pid) = project_id {
-            query.push_str(" AND project_id = ?");
-            params.push(Box::new(pid));
-        }
-
-        if let Some(assigned_id) = assigned_to_id {
-            query.push_str(" AND assigned_to_id = ?");
-            params.push(Box::new(assigned_id));
-        }
-
-        if let Some(f) = filter {
-            if !f.is_empty() {
-                query.push_str(" AND (subject LIKE ? OR description LIKE ? OR CAST(id AS TEXT) LIKE ?)");
-                let filter_param = format!("%{}%", f);
-                params.push(Box::new(filter_param.clone()));
-                params.push(Box::new(filter_param.clone()));
-                params.push(Box::new(filter_param));
-            }
-        }
+        let mut query = format!(
+            "SELECT {} FROM issues WHERE {}",
+            ISSUE_COLUMNS,
+            self.issue_conditions(project_id, filter, context, &mut params)?
+        );
 
         // Add ORDER BY based on sort order
         match sort_order {
@@ -508,65 +1221,249 @@ impl Database {
 
         let params_ref: Vec<&dyn rusqlite::ToSql> = params.iter().map(|b| b.as_ref()).collect();
 
-        let issue_iter = stmt.query_map(params_ref.as_slice(), |row| {
-            Ok(Issue {
-                id: row.get(0)?,
-                project: crate::redmine::IdName {
-                    id: row.get(1)?,
-                    name: String::new(), // Will be populated if needed
-                },
-                tracker: crate::redmine::IdName {
-                    id: row.get(2)?,
-                    name: row.get(3)?,
-                },
-                status: crate::redmine::IdName {
-                    id: row.get(4)?,
-                    name: row.get(5)?,
-                },
-                priority: crate::redmine::IdName {
-                    id: row.get(6)?,
-                    name: row.get(7)?,
-                },
-                author: crate::redmine::IdName {
-                    id: row.get(8)?,
-                    name: row.get(9)?,
-                },
-                assigned_to: {
-                    let id: Option<u64> = row.get(10)?;
-                    let name: Option<String> = row.get(11)?;
-                    match (id, name) {
-                        (Some(id), Some(name)) => Some(crate::redmine::IdName { id, name }),
-                        _ => None,
-                    }
-                },
-                parent: None, // Not stored in DB yet
-                category: None, // Not stored in DB yet
-                fixed_version: None, // Not stored in DB yet
-                subject: row.get(12)?,
-                description: row.get(13)?,
-                start_date: None, // Not stored in DB yet
-                due_date: row.get(16)?,
-                done_ratio: row.get(17)?,
-                is_private: None,      // Not stored in DB yet
-                estimated_hours: None, // Not stored in DB yet
-                total_estimated_hours: None, // Not stored in DB yet
-                spent_hours: None, // Not stored in DB yet
-                total_spent_hours: None, // Not stored in DB yet
-                created_on: parse_datetime_from_db(&row.get::<_, String>(14)?)?,
-                updated_on: parse_datetime_from_db(&row.get::<_, String>(15)?)?,
-                closed_on: None,           // Not stored in DB yet
-                journals: Vec::new(),      // Loaded separately
-                custom_fields: Vec::new(), // Not stored in DB
-                attachments: Vec::new(),   // Not stored in DB
-            })
-        })?;
+        let mut issues: Vec<Issue> = stmt
+            .query_map(params_ref.as_slice(), issue_from_row)?
+            .collect::<Result<_, _>>()?;
+        load_issue_children(&self.conn, &mut issues)?;
+
+        Ok(issues)
+    }
+
+    /// Number of cached issues of a project (or of all projects) matching a filter query
+    pub fn count_issues(&self, project_id: Option<u64>, filter: &FilterQuery, context: &QueryContext) -> Result<usize> {
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        let query = format!(
+            "SELECT COUNT(*) FROM issues WHERE {}",
+            self.issue_conditions(project_id, filter, context, &mut params)?
+        );
+        let params_ref: Vec<&dyn rusqlite::ToSql> = params.iter().map(|b| b.as_ref()).collect();
+        let count: i64 = self.conn.query_row(&query, params_ref.as_slice(), |row| row.get(0))?;
+        Ok(count as usize)
+    }
 
-        let mut issues = Vec::new();
-        for issue in issue_iter {
-            issues.push(issue?);
+    /// WHERE clause selecting the issues of a project (or of all projects) matching a filter query
+    fn issue_conditions(
+        &self,
+        project_id: Option<u64>,
+        filter: &FilterQuery,
+        context: &QueryContext,
+        params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+    ) -> Result<String> {
+        let mut conditions = vec!["1=1".to_string()];
+        if let Some(pid) = project_id {
+            conditions.push("project_id = ?".to_string());
+            params.push(Box::new(pid));
         }
+        conditions.extend(self.compile_issue_query(filter, context, params)?);
+        Ok(conditions.join(" AND "))
+    }
 
-        Ok(issues)
+    /// Full-text search over every cached issue, best matches first
+    pub fn search_issues(&self, input: &str, limit: usize) -> Result<Vec<SearchHit>> {
+        let Some(query) = fts_query(input) else {
+            return Ok(Vec::new());
+        };
+
+        // Subject matches rank above description matches, which rank above notes
+        let matches: Vec<(u64, String)> = self
+            .conn
+            .prepare(&format!(
+                "SELECT rowid, snippet(issue_search, -1, '{}', '{}', '…', 16) FROM issue_search
+                 WHERE issue_search MATCH ?1
+                 ORDER BY bm25(issue_search, 10.0, 3.0, 1.0)
+                 LIMIT ?2",
+                SNIPPET_MATCH_START, SNIPPET_MATCH_END
+            ))?
+            .query_map(params![query, limit], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_, _>>()?;
+
+        let mut stmt = self
+            .conn
+            .prepare(&format!("SELECT {} FROM issues WHERE id = ?1", ISSUE_COLUMNS))?;
+        let mut issues = Vec::with_capacity(matches.len());
+        let mut snippets = Vec::with_capacity(matches.len());
+        for (issue_id, snippet) in matches {
+            if let Some(issue) = stmt.query_row(params![issue_id], issue_from_row).optional()? {
+                issues.push(issue);
+                snippets.push(snippet);
+            }
+        }
+        load_issue_children(&self.conn, &mut issues)?;
+        let hits = issues
+            .into_iter()
+            .zip(snippets)
+            .map(|(issue, snippet)| SearchHit { issue, snippet })
+            .collect();
+
+        Ok(hits)
+    }
+
+    /// Turn each term of a filter query into a SQL condition on the issues table,
+    /// pushing the values it binds onto `params`
+    fn compile_issue_query(
+        &self,
+        query: &FilterQuery,
+        context: &QueryContext,
+        params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+    ) -> Result<Vec<String>, QueryError> {
+        let mut conditions = Vec::new();
+
+        for term in &query.terms {
+            let condition = match term {
+                Term::Status(op, value) => {
+                    let negate = *op == Comparison::Ne;
+                    match value {
+                        StatusValue::Any if negate => "0".to_string(),
+                        StatusValue::Any => continue,
+                        StatusValue::Open | StatusValue::Closed => {
+                            let closed = closed_condition(context);
+                            if (*value == StatusValue::Closed) != negate {
+                                closed
+                            } else {
+                                format!("NOT ({})", closed)
+                            }
+                        }
+                        StatusValue::Named(name) => {
+                            params.push(Box::new(name.clone()));
+                            format!("status_name {} ? COLLATE NOCASE", op.as_sql())
+                        }
+                    }
+                }
+                Term::Assignee(op, user) => Self::user_condition("assigned_to", "assignee", *op, user, context, params)?,
+                Term::Author(op, user) => Self::user_condition("author", "author", *op, user, context, params)?,
+                Term::Tracker(op, name) => {
+                    params.push(Box::new(name.clone()));
+                    format!("tracker_name {} ? COLLATE NOCASE", op.as_sql())
+                }
+                Term::Version(op, version) => {
+                    let negate = *op == Comparison::Ne;
+                    match version {
+                        VersionValue::Nobody if negate => "fixed_version_id IS NOT NULL".to_string(),
+                        VersionValue::Nobody => "fixed_version_id IS NULL".to_string(),
+                        VersionValue::Id(id) => {
+                            params.push(Box::new(*id));
+                            format!("IFNULL(fixed_version_id, 0) {} ?", op.as_sql())
+                        }
+                        VersionValue::Named(name) => {
+                            params.push(Box::new(name.clone()));
+                            format!("IFNULL(fixed_version_name, '') {} ? COLLATE NOCASE", op.as_sql())
+                        }
+                    }
+                }
+                Term::Priority(op, name) => {
+                    let priorities = if context.priorities.is_empty() {
+                        self.conn
+                            .prepare("SELECT DISTINCT priority_id, priority_name FROM issues ORDER BY priority_id")
+                            .and_then(|mut stmt| {
+                                stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                                    .collect::<Result<Vec<(u64, String)>, _>>()
+                            })
+                            .map_err(|e| QueryError::new(format!("Failed to read priorities: {}", e)))?
+                    } else {
+                        context.priorities.clone()
+                    };
+
+                    // Exact name first, then a unique prefix (`prio:imm`)
+                    let lower = name.to_lowercase();
+                    let position = priorities
+                        .iter()
+                        .position(|(_, p)| p.to_lowercase() == lower)
+                        .or_else(|| {
+                            let mut prefixed = priorities.iter().enumerate().filter(|(_, (_, p))| p.to_lowercase().starts_with(&lower));
+                            match (prefixed.next(), prefixed.next()) {
+                                (Some((i, _)), None) => Some(i),
+                                _ => None,
+                            }
+                        })
+                        .ok_or_else(|| {
+                            let names: Vec<&str> = priorities.iter().map(|(_, p)| p.as_str()).collect();
+                            QueryError::new(format!("Unknown priority '{}'. Priorities: {}", name, names.join(", ")))
+                        })?;
+
+                    let ids: Vec<String> = priorities
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| op.matches(i.cmp(&position)))
+                        .map(|(_, (id, _))| id.to_string())
+                        .collect();
+                    if ids.is_empty() {
+                        "0".to_string()
+                    } else {
+                        format!("priority_id IN ({})", ids.join(", "))
+                    }
+                }
+                Term::Date(field, op, value) => {
+                    let column = match field {
+                        DateField::Due => "due_date",
+                        DateField::Updated => "substr(updated_on, 1, 10)",
+                        DateField::Created => "substr(created_on, 1, 10)",
+                    };
+                    params.push(Box::new(value.resolve(*field, context.today).format("%Y-%m-%d").to_string()));
+                    format!("{} {} ?", column, op.as_sql())
+                }
+                Term::Word(word) => {
+                    // Full-text match on subject, description and notes, or part of the issue number
+                    let id_pattern = format!("%{}%", word.trim_start_matches('#'));
+                    match fts_query(word) {
+                        Some(fts) => {
+                            params.push(Box::new(fts));
+                            params.push(Box::new(id_pattern));
+                            "(id IN (SELECT rowid FROM issue_search WHERE issue_search MATCH ?) OR CAST(id AS TEXT) LIKE ?)"
+                                .to_string()
+                        }
+                        None => {
+                            params.push(Box::new(id_pattern));
+                            "CAST(id AS TEXT) LIKE ?".to_string()
+                        }
+                    }
+                }
+                Term::Phrase(phrase) => {
+                    if !phrase.chars().any(char::is_alphanumeric) {
+                        continue;
+                    }
+                    params.push(Box::new(format!("\"{}\"", phrase.replace('"', "\"\""))));
+                    "id IN (SELECT rowid FROM issue_search WHERE issue_search MATCH ?)".to_string()
+                }
+                Term::SavedQuery(query_id) => {
+                    params.push(Box::new(*query_id));
+                    "id IN (SELECT issue_id FROM saved_query_issues WHERE query_id = ?)".to_string()
+                }
+                Term::Watched(op) => {
+                    let not = if *op == Comparison::Ne { "NOT " } else { "" };
+                    format!("id {}IN (SELECT issue_id FROM watched_issues)", not)
+                }
+            };
+            conditions.push(condition);
+        }
+
+        Ok(conditions)
+    }
+
+    /// Condition on an `<column>_id`/`<column>_name` user reference
+    fn user_condition(
+        column: &str,
+        filter: &str,
+        op: Comparison,
+        user: &UserValue,
+        context: &QueryContext,
+        params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+    ) -> Result<String, QueryError> {
+        let negate = op == Comparison::Ne;
+        let id = match user {
+            UserValue::Nobody if negate => return Ok(format!("{}_id IS NOT NULL", column)),
+            UserValue::Nobody => return Ok(format!("{}_id IS NULL", column)),
+            UserValue::Named(name) => {
+                params.push(Box::new(format!("%{}%", name)));
+                let not = if negate { "NOT " } else { "" };
+                return Ok(format!("IFNULL({}_name, '') {}LIKE ?", column, not));
+            }
+            UserValue::Me => context.current_user_id.ok_or_else(|| {
+                QueryError::new(format!("{}:me needs your Redmine account, which isn't loaded yet", filter))
+            })?,
+            UserValue::Id(id) => *id,
+        };
+        params.push(Box::new(id));
+        Ok(format!("IFNULL({}_id, 0) {} ?", column, op.as_sql()))
     }
 
     // Issue with journals
@@ -574,34 +1471,10 @@ impl Database {
         let tx = self.conn.unchecked_transaction()?;
 
         // Insert the issue
-        tx.execute(
-            "INSERT OR REPLACE INTO issues
-            (id, project_id, tracker_id, tracker_name, status_id, status_name,
-             priority_id, priority_name, author_id, author_name,
-             assigned_to_id, assigned_to_name, subject, description,
-             created_on, updated_on, due_date, done_ratio)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
-            params![
-                issue.id,
-                issue.project.id,
-                issue.tracker.id,
-                &issue.tracker.name,
-                issue.status.id,
-                &issue.status.name,
-                issue.priority.id,
-                &issue.priority.name,
-                issue.author.id,
-                &issue.author.name,
-                issue.assigned_to.as_ref().map(|a| a.id),
-                issue.assigned_to.as_ref().map(|a| &a.name),
-                &issue.subject,
-                &issue.description,
-                issue.created_on.to_rfc3339(),
-                issue.updated_on.to_rfc3339(),
-                &issue.due_date,
-                issue.done_ratio,
-            ],
-        )?;
+        upsert_issue(&tx, issue)?;
+        replace_attachments(&tx, issue)?;
+        replace_relations(&tx, issue)?;
+        replace_watchers(&tx, issue)?;
 
         // Delete old journals for this issue
         tx.execute(
@@ -615,8 +1488,8 @@ impl Database {
         // Insert journals
         for journal in &issue.journals {
             tx.execute(
-                "INSERT INTO journals (id, issue_id, user_id, user_name, notes, created_on)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                "INSERT INTO journals (id, issue_id, user_id, user_name, notes, created_on, private_notes)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
                 params![
                     journal.id,
                     issue.id,
@@ -624,6 +1497,7 @@ impl Database {
                     &journal.user.name,
                     &journal.notes,
                     journal.created_on.to_rfc3339(),
+                    journal.private_notes,
                 ],
             )?;
 
@@ -643,87 +1517,48 @@ impl Database {
             }
         }
 
-        // Update project's last_issue_activity and last_issues_sync
-        let now = Utc::now().to_rfc3339();
+        index_issue(&tx, issue.id)?;
+
+        // Update project's last_issue_activity (a single issue doesn't count as a project sync)
         tx.execute(
-            "UPDATE projects SET last_issue_activity = ?1, last_issues_sync = ?2 WHERE id = ?3",
-            params![issue.updated_on.to_rfc3339(), now, issue.project.id],
+            "UPDATE projects SET last_issue_activity = ?1 WHERE id = ?2",
+            params![issue.updated_on.to_rfc3339(), issue.project.id],
         )?;
 
         tx.commit()?;
         Ok(())
     }
 
+    /// A cached issue's own fields, without journals or child tables
+    pub fn get_issue(&self, issue_id: u64) -> Result<Option<Issue>> {
+        Ok(self
+            .conn
+            .query_row(
+                &format!("SELECT {} FROM issues WHERE id = ?1", ISSUE_COLUMNS),
+                params![issue_id],
+                issue_from_row,
+            )
+            .optional()?)
+    }
+
     pub fn get_issue_with_journals(&self, issue_id: u64) -> Result<Option<Issue>> {
         // Get the issue
         let issue = self
             .conn
             .query_row(
-                "SELECT id, project_id, tracker_id, tracker_name, status_id, status_name,
-             priority_id, priority_name, author_id, author_name,
-             assigned_to_id, assigned_to_name, subject, description,
-             created_on, updated_on, due_date, done_ratio
-             FROM issues WHERE id = ?1",
+                &format!("SELECT {} FROM issues WHERE id = ?1", ISSUE_COLUMNS),
                 params![issue_id],
-                |row| {
-                    Ok(Issue {
-                        id: row.get(0)?,
-                        project: crate::redmine::IdName {
-                            id: row.get(1)?,
-                            name: String::new(),
-                        },
-                        tracker: crate::redmine::IdName {
-                            id: row.get(2)?,
-                            name: row.get(3)?,
-                        },
-                        status: crate::redmine::IdName {
-                            id: row.get(4)?,
-                            name: row.get(5)?,
-                        },
-                        priority: crate::redmine::IdName {
-                            id: row.get(6)?,
-                            name: row.get(7)?,
-                        },
-                        author: crate::redmine::IdName {
-                            id: row.get(8)?,
-                            name: row.get(9)?,
-                        },
-                        assigned_to: {
-                            let id: Option<u64> = row.get(10)?;
-                            let name: Option<String> = row.get(11)?;
-                            match (id, name) {
-                                (Some(id), Some(name)) => Some(crate::redmine::IdName { id, name }),
-                                _ => None,
-                            }
-                        },
-                        parent: None, // Not stored in DB yet
-                        category: None, // Not stored in DB yet
-                        fixed_version: None, // Not stored in DB yet
-                        subject: row.get(12)?,
-                        description: row.get(13)?,
-                        start_date: None, // Not stored in DB yet
-                        due_date: row.get(16)?,
-                        done_ratio: row.get(17)?,
-                        is_private: None,      // Not stored in DB yet
-                        estimated_hours: None, // Not stored in DB yet
-                        total_estimated_hours: None, // Not stored in DB yet
-                        spent_hours: None, // Not stored in DB yet
-                        total_spent_hours: None, // Not stored in DB yet
-                        created_on: parse_datetime_from_db(&row.get::<_, String>(14)?)?,
-                        updated_on: parse_datetime_from_db(&row.get::<_, String>(15)?)?,
-                        closed_on: None, // Not stored in DB yet
-                        journals: Vec::new(),
-                        custom_fields: Vec::new(), // Not stored in DB
-                        attachments: Vec::new(),   // Not stored in DB
-                    })
-                },
+                issue_from_row,
             )
             .optional()?;
 
         if let Some(mut issue) = issue {
+            load_issue_children(&self.conn, std::slice::from_mut(&mut issue))?;
+            issue.children = load_subtasks(&self.conn, issue_id, 0)?;
+
             // Get journals
             let mut stmt = self.conn.prepare(
-                "SELECT id, user_id, user_name, notes, created_on
+                "SELECT id, user_id, user_name, notes, created_on, private_notes
                  FROM journals WHERE issue_id = ?1
                  ORDER BY created_on ASC",
             )?;
@@ -739,7 +1574,7 @@ impl Database {
                         },
                         notes: row.get(3)?,
                         created_on: parse_datetime_from_db(&row.get::<_, String>(4)?)?,
-                        private_notes: false, // Not stored in DB yet
+                        private_notes: row.get(5)?,
                         details: Vec::new(),
                     },
                 ))
@@ -776,6 +1611,100 @@ impl Database {
         }
     }
 
+    // Time entries
+    /// Replace the cached time entries of an issue with a freshly fetched list
+    pub fn insert_time_entries(&self, issue_id: u64, entries: &[TimeEntry]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+
+        tx.execute("DELETE FROM time_entries WHERE issue_id = ?1", params![issue_id])?;
+
+        for entry in entries {
+            Self::upsert_time_entry(&tx, issue_id, entry)?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Insert or update a single time entry (e.g. after creating or editing it)
+    pub fn insert_time_entry(&self, entry: &TimeEntry) -> Result<()> {
+        let Some(issue_id) = entry.issue.as_ref().map(|i| i.id) else {
+            // Project-level entries are not shown anywhere, nothing to cache
+            return Ok(());
+        };
+        Self::upsert_time_entry(&self.conn, issue_id, entry)
+    }
+
+    fn upsert_time_entry(conn: &Connection, issue_id: u64, entry: &TimeEntry) -> Result<()> {
+        conn.execute(
+            "INSERT OR REPLACE INTO time_entries
+            (id, issue_id, project_id, user_id, user_name, activity_id, activity_name,
+             hours, comments, spent_on, created_on, updated_on)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+            params![
+                entry.id,
+                issue_id,
+                entry.project.id,
+                entry.user.id,
+                &entry.user.name,
+                entry.activity.id,
+                &entry.activity.name,
+                entry.hours,
+                &entry.comments,
+                &entry.spent_on,
+                entry.created_on.to_rfc3339(),
+                entry.updated_on.to_rfc3339(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_time_entries(&self, issue_id: u64) -> Result<Vec<TimeEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, issue_id, project_id, user_id, user_name, activity_id, activity_name,
+             hours, comments, spent_on, created_on, updated_on
+             FROM time_entries WHERE issue_id = ?1
+             ORDER BY spent_on DESC, id DESC",
+        )?;
+
+        let entries = stmt
+            .query_map(params![issue_id], |row| {
+                Ok(TimeEntry {
+                    id: row.get(0)?,
+                    issue: Some(crate::redmine::IdName {
+                        id: row.get(1)?,
+                        name: String::new(),
+                    }),
+                    project: crate::redmine::IdName {
+                        id: row.get(2)?,
+                        name: String::new(),
+                    },
+                    user: crate::redmine::IdName {
+                        id: row.get(3)?,
+                        name: row.get(4)?,
+                    },
+                    activity: crate::redmine::IdName {
+                        id: row.get(5)?,
+                        name: row.get(6)?,
+                    },
+                    hours: row.get(7)?,
+                    comments: row.get(8)?,
+                    spent_on: row.get(9)?,
+                    created_on: parse_datetime_from_db(&row.get::<_, String>(10)?)?,
+                    updated_on: parse_datetime_from_db(&row.get::<_, String>(11)?)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(entries)
+    }
+
+    pub fn delete_time_entry(&self, time_entry_id: u64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM time_entries WHERE id = ?1", params![time_entry_id])?;
+        Ok(())
+    }
+
     pub fn get_project_name(&self, project_id: u64) -> Result<Option<String>> {
         self.conn
             .query_row("SELECT name FROM projects WHERE id = ?1", params![project_id], |row| {
@@ -816,6 +1745,197 @@ impl Database {
             .map(|dt| dt.with_timezone(&Utc)))
     }
 
+    // Offline write queue
+
+    /// Queue a write for replay. Creates get a local issue id derived from the operation id.
+    pub fn queue_operation(&self, issue_id: Option<u64>, write: &QueuedWrite) -> Result<PendingOperation> {
+        let tx = self.conn.unchecked_transaction()?;
+        let created_at = Utc::now();
+        tx.execute(
+            "INSERT INTO pending_operations (issue_id, payload, created_at) VALUES (?1, ?2, ?3)",
+            params![issue_id.unwrap_or(0), serde_json::to_string(write)?, created_at.to_rfc3339()],
+        )?;
+        let id = tx.last_insert_rowid() as u64;
+
+        let issue_id = match issue_id {
+            Some(issue_id) => issue_id,
+            None => {
+                let local_id = LOCAL_ID_BASE + id;
+                tx.execute(
+                    "UPDATE pending_operations SET issue_id = ?1 WHERE id = ?2",
+                    params![local_id, id],
+                )?;
+                local_id
+            }
+        };
+        tx.commit()?;
+
+        Ok(PendingOperation {
+            id,
+            issue_id,
+            write: write.clone(),
+            created_at,
+            attempts: 0,
+            last_error: None,
+            blocked: false,
+        })
+    }
+
+    /// Queued writes in the order they were made
+    pub fn get_pending_operations(&self) -> Result<Vec<PendingOperation>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, issue_id, payload, created_at, attempts, last_error, blocked
+             FROM pending_operations ORDER BY id",
+        )?;
+
+        let operations = stmt
+            .query_map([], |row| {
+                let payload: String = row.get(2)?;
+                Ok(PendingOperation {
+                    id: row.get(0)?,
+                    issue_id: row.get(1)?,
+                    write: serde_json::from_str(&payload).map_err(|e| {
+                        rusqlite::Error::FromSqlConversionFailure(2, rusqlite::types::Type::Text, Box::new(e))
+                    })?,
+                    created_at: parse_datetime_from_db(&row.get::<_, String>(3)?)?,
+                    attempts: row.get(4)?,
+                    last_error: row.get(5)?,
+                    blocked: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(operations)
+    }
+
+    pub fn count_pending_operations(&self) -> Result<usize> {
+        let count: i64 = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM pending_operations", [], |row| row.get(0))?;
+        Ok(count as usize)
+    }
+
+    pub fn delete_pending_operation(&self, id: u64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM pending_operations WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// Drop every queued write for an issue (used when its queued creation is dropped)
+    pub fn delete_pending_operations_for_issue(&self, issue_id: u64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM pending_operations WHERE issue_id = ?1", params![issue_id])?;
+        Ok(())
+    }
+
+    /// Record a failed replay. A blocked operation is skipped until it is retried by hand.
+    pub fn record_pending_failure(&self, id: u64, error: &str, blocked: bool) -> Result<()> {
+        self.conn.execute(
+            "UPDATE pending_operations SET attempts = attempts + 1, last_error = ?1, blocked = ?2 WHERE id = ?3",
+            params![error, blocked, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn unblock_pending_operation(&self, id: u64) -> Result<()> {
+        self.conn
+            .execute("UPDATE pending_operations SET blocked = 0 WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// Point queued writes for a locally created issue at the id the server assigned
+    pub fn reassign_pending_operations(&self, from_issue_id: u64, to_issue_id: u64) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "UPDATE pending_operations SET issue_id = ?1 WHERE issue_id = ?2",
+            params![to_issue_id, from_issue_id],
+        )?;
+
+        // Queued writes can also name the issue as the parent of another
+        let payloads: Vec<(u64, String)> = tx
+            .prepare("SELECT id, payload FROM pending_operations")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_, _>>()?;
+        for (id, payload) in payloads {
+            let mut write: QueuedWrite = serde_json::from_str(&payload)?;
+            let parent_issue_id = match &mut write {
+                QueuedWrite::CreateIssue(new_issue) => &mut new_issue.parent_issue_id,
+                QueuedWrite::UpdateIssue(update) => &mut update.parent_issue_id,
+            };
+            if *parent_issue_id == Some(from_issue_id) {
+                *parent_issue_id = Some(to_issue_id);
+                tx.execute(
+                    "UPDATE pending_operations SET payload = ?1 WHERE id = ?2",
+                    params![serde_json::to_string(&write)?, id],
+                )?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// When the issues of a project were last synced from the server
+    pub fn get_project_issues_sync(&self, project_id: u64) -> Result<Option<DateTime<Utc>>> {
+        let result: Option<Option<String>> = self
+            .conn
+            .query_row(
+                "SELECT last_issues_sync FROM projects WHERE id = ?1",
+                params![project_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(result
+            .flatten()
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc)))
+    }
+
+    /// Record that every issue of a project updated before `synced_at` is in the cache
+    pub fn set_project_issues_sync(&self, project_id: u64, synced_at: DateTime<Utc>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE projects SET last_issues_sync = ?1 WHERE id = ?2",
+            params![synced_at.to_rfc3339(), project_id],
+        )?;
+        Ok(())
+    }
+
+    /// When every issue of a project was last downloaded, rather than only the updated ones
+    pub fn get_project_full_sync(&self, project_id: u64) -> Result<Option<DateTime<Utc>>> {
+        Ok(self
+            .get_metadata(&format!("{}{}", FULL_SYNC_KEY_PREFIX, project_id))?
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+            .map(|dt| dt.with_timezone(&Utc)))
+    }
+
+    pub fn set_project_full_sync(&self, project_id: u64, synced_at: DateTime<Utc>) -> Result<()> {
+        self.set_metadata(
+            &format!("{}{}", FULL_SYNC_KEY_PREFIX, project_id),
+            &synced_at.to_rfc3339(),
+        )
+    }
+
+    pub fn get_metadata(&self, key: &str) -> Result<Option<String>> {
+        self.conn
+            .query_row("SELECT value FROM metadata WHERE key = ?1", params![key], |row| row.get(0))
+            .optional()
+            .map_err(Into::into)
+    }
+
+    pub fn set_metadata(&self, key: &str, value: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO metadata (key, value) VALUES (?1, ?2)",
+            params![key, value],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete_metadata(&self, key: &str) -> Result<()> {
+        self.conn.execute("DELETE FROM metadata WHERE key = ?1", params![key])?;
+        Ok(())
+    }
+
     pub fn update_project_last_activity(&self, project_id: u64, last_activity: DateTime<Utc>) -> Result<()> {
         self.conn.execute(
             "UPDATE projects SET last_issue_activity = ?1 WHERE id = ?2",
@@ -823,4 +1943,261 @@ impl Database {
         )?;
         Ok(())
     }
+
+    // Filter presets
+
+    /// Saved presets, those bound to a number key first
+    pub fn get_filter_presets(&self) -> Result<Vec<FilterPreset>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, name, slot, project_id, issue_filter, sort_order, group_by_status, my_issues
+             FROM filter_presets ORDER BY slot IS NULL, slot, name COLLATE NOCASE",
+        )?;
+        let presets = stmt
+            .query_map([], |row| {
+                let sort_order: String = row.get(5)?;
+                Ok(FilterPreset {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    slot: row.get(2)?,
+                    project_id: row.get(3)?,
+                    issue_filter: row.get(4)?,
+                    sort_order: IssueSortOrder::from_key(&sort_order).unwrap_or(IssueSortOrder::UpdatedDesc),
+                    group_by_status: row.get(6)?,
+                    my_issues: row.get(7)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(presets)
+    }
+
+    /// Store a preset under its name, replacing the settings of one with the same name but keeping its key.
+    /// Returns the preset's id.
+    pub fn save_filter_preset(&self, preset: &FilterPreset) -> Result<u64> {
+        let id = self.conn.query_row(
+            "INSERT INTO filter_presets (name, project_id, issue_filter, sort_order, group_by_status, my_issues)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(name) DO UPDATE SET
+                project_id = excluded.project_id,
+                issue_filter = excluded.issue_filter,
+                sort_order = excluded.sort_order,
+                group_by_status = excluded.group_by_status,
+                my_issues = excluded.my_issues
+             RETURNING id",
+            params![
+                preset.name,
+                preset.project_id,
+                preset.issue_filter,
+                preset.sort_order.key(),
+                preset.group_by_status,
+                preset.my_issues,
+            ],
+            |row| row.get(0),
+        )?;
+        Ok(id)
+    }
+
+    /// Bind a preset to a number key, taking the key from any other preset, or unbind it with `None`
+    pub fn set_filter_preset_slot(&self, id: u64, slot: Option<u8>) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        if let Some(slot) = slot {
+            tx.execute(
+                "UPDATE filter_presets SET slot = NULL WHERE slot = ?1 AND id <> ?2",
+                params![slot, id],
+            )?;
+        }
+        tx.execute("UPDATE filter_presets SET slot = ?1 WHERE id = ?2", params![slot, id])?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn delete_filter_preset(&self, id: u64) -> Result<()> {
+        self.conn.execute("DELETE FROM filter_presets WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    // Redmine saved queries
+
+    /// Replace the cached list of saved queries, dropping the results of queries that are gone
+    pub fn replace_saved_queries(&self, queries: &[SavedQuery]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM saved_queries", [])?;
+        for query in queries {
+            tx.execute(
+                "INSERT INTO saved_queries (id, name, is_public, project_id) VALUES (?1, ?2, ?3, ?4)",
+                params![query.id, query.name, query.is_public, query.project_id],
+            )?;
+        }
+        tx.execute(
+            "DELETE FROM saved_query_issues WHERE query_id NOT IN (SELECT id FROM saved_queries)",
+            [],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Cached saved queries, global ones first
+    pub fn get_saved_queries(&self) -> Result<Vec<SavedQuery>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, name, is_public, project_id FROM saved_queries
+             ORDER BY project_id IS NOT NULL, name COLLATE NOCASE",
+        )?;
+        let queries = stmt
+            .query_map([], |row| {
+                Ok(SavedQuery {
+                    id: row.get(0)?,
+                    name: row.get(1)?,
+                    is_public: row.get(2)?,
+                    project_id: row.get(3)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(queries)
+    }
+
+    /// Remember which issues a run of a saved query returned
+    pub fn set_saved_query_issues(&self, query_id: u64, issue_ids: &[u64]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM saved_query_issues WHERE query_id = ?1", params![query_id])?;
+        for issue_id in issue_ids {
+            tx.execute(
+                "INSERT OR IGNORE INTO saved_query_issues (query_id, issue_id) VALUES (?1, ?2)",
+                params![query_id, issue_id],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Whether a saved query has been run, so its cached results can be shown offline
+    pub fn has_saved_query_issues(&self, query_id: u64) -> Result<bool> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM saved_query_issues WHERE query_id = ?1",
+            params![query_id],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    // Versions
+
+    /// Replace the cached versions of a project with the server's list
+    pub fn replace_project_versions(&self, project_id: u64, versions: &[Version]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM project_versions WHERE project_id = ?1", params![project_id])?;
+        for version in versions {
+            tx.execute(
+                "INSERT OR REPLACE INTO project_versions
+                 (project_id, id, owner_project_id, owner_project_name, name, description, status, due_date, sharing)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    project_id,
+                    version.id,
+                    version.project.id,
+                    &version.project.name,
+                    &version.name,
+                    &version.description,
+                    &version.status,
+                    &version.due_date,
+                    &version.sharing,
+                ],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Cached versions of a project in roadmap order: by due date, undated ones last, then by name
+    pub fn get_project_versions(&self, project_id: u64) -> Result<Vec<Version>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, owner_project_id, owner_project_name, name, description, status, due_date, sharing
+             FROM project_versions WHERE project_id = ?1
+             ORDER BY due_date IS NULL, due_date, name COLLATE NOCASE",
+        )?;
+        let versions = stmt
+            .query_map(params![project_id], |row| {
+                Ok(Version {
+                    id: row.get(0)?,
+                    project: IdName {
+                        id: row.get(1)?,
+                        name: row.get(2)?,
+                    },
+                    name: row.get(3)?,
+                    description: row.get(4)?,
+                    status: row.get(5)?,
+                    due_date: row.get(6)?,
+                    sharing: row.get(7)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(versions)
+    }
+
+    /// Name of a cached version, whichever project it was cached for
+    pub fn get_version_name(&self, version_id: u64) -> Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT name FROM project_versions WHERE id = ?1 LIMIT 1",
+                params![version_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(Into::into)
+    }
+
+    /// Progress of every version with cached issues, by version id
+    pub fn get_version_progress(&self, context: &QueryContext) -> Result<HashMap<u64, VersionProgress>> {
+        let closed = closed_condition(context);
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT fixed_version_id, COUNT(*), SUM({closed}),
+             SUM(CASE WHEN {closed} THEN 100 ELSE MIN(IFNULL(done_ratio, 0), 100) END)
+             FROM issues WHERE fixed_version_id IS NOT NULL GROUP BY fixed_version_id",
+        ))?;
+        let progress = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, u64>(0)?,
+                    VersionProgress {
+                        total: row.get::<_, i64>(1)? as usize,
+                        closed: row.get::<_, i64>(2)? as usize,
+                        done_ratio_sum: row.get::<_, i64>(3)? as u64,
+                    },
+                ))
+            })?
+            .collect::<rusqlite::Result<HashMap<_, _>>>()?;
+        Ok(progress)
+    }
+
+    // Watched issues
+
+    /// Replace the set of issues the current user watches
+    pub fn set_watched_issues(&self, issue_ids: &[u64]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM watched_issues", [])?;
+        for issue_id in issue_ids {
+            tx.execute("INSERT OR IGNORE INTO watched_issues (issue_id) VALUES (?1)", params![issue_id])?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Record that the current user started or stopped watching an issue
+    pub fn set_issue_watched(&self, issue_id: u64, watched: bool) -> Result<()> {
+        if watched {
+            self.conn
+                .execute("INSERT OR IGNORE INTO watched_issues (issue_id) VALUES (?1)", params![issue_id])?;
+        } else {
+            self.conn
+                .execute("DELETE FROM watched_issues WHERE issue_id = ?1", params![issue_id])?;
+        }
+        Ok(())
+    }
+
+    pub fn is_issue_watched(&self, issue_id: u64) -> Result<bool> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM watched_issues WHERE issue_id = ?1",
+            params![issue_id],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
 }
@@ -1,8 +1,7 @@
 /// Custom error types for the Redmine TUI application
 ///
-/// Note: Currently using anyhow::Result throughout the codebase.
-/// This module is kept for future migration to typed errors.
-#[allow(dead_code)]
+/// `RedmineClient` returns these; the rest of the app still passes them around
+/// inside `anyhow::Error` and downcasts where the distinction matters.
 #[derive(Debug, thiserror::Error)]
 pub enum RedmineError {
     /// HTTP/Network errors from API calls
@@ -25,9 +24,13 @@ pub enum RedmineError {
     #[error("API request failed with status {status}: {message}")]
     Api { status: u16, message: String },
 
-    /// Validation errors (form inputs, etc.)
-    #[error("Validation error: {0}")]
-    Validation(String),
+    /// Validation errors (form inputs, or the `errors` array of a 422 response)
+    #[error("Validation error: {}", .0.join("; "))]
+    Validation(Vec<String>),
+
+    /// Response body that doesn't match the expected format
+    #[error("Failed to parse JSON response from {path}: {message}")]
+    InvalidResponse { path: String, message: String },
 
     /// IO errors
     #[error("IO error: {0}")]
@@ -61,7 +64,56 @@ impl RedmineError {
 
     /// Create a validation error
     pub fn validation(message: impl Into<String>) -> Self {
-        Self::Validation(message.into())
+        Self::Validation(vec![message.into()])
+    }
+
+    /// Classify an unsuccessful HTTP response
+    pub fn from_response(status: u16, body: &str) -> Self {
+        #[derive(serde::Deserialize)]
+        struct ErrorsBody {
+            errors: Vec<String>,
+        }
+
+        match status {
+            401 => Self::auth(if body.trim().is_empty() {
+                "Invalid or missing API key".to_string()
+            } else {
+                body.trim().to_string()
+            }),
+            422 => match serde_json::from_str::<ErrorsBody>(body) {
+                Ok(parsed) if !parsed.errors.is_empty() => Self::Validation(parsed.errors),
+                _ => Self::api(status, body.trim()),
+            },
+            _ => Self::api(status, body.trim()),
+        }
+    }
+
+    /// Messages of a validation error, one per failed check
+    pub fn validation_errors(&self) -> &[String] {
+        match self {
+            Self::Validation(errors) => errors,
+            _ => &[],
+        }
+    }
+
+    /// Whether the server couldn't be reached at all, so the request may succeed later
+    pub fn is_offline(&self) -> bool {
+        matches!(self, Self::Network(e) if e.is_connect() || e.is_timeout())
+    }
+
+    /// Whether the request timed out after reaching the server, which may have applied it anyway
+    pub fn may_have_been_applied(&self) -> bool {
+        matches!(self, Self::Network(e) if e.is_timeout() && !e.is_connect())
+    }
+
+    /// HTTP status of an API error
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Auth { .. } => Some(401),
+            Self::Api { status, .. } => Some(*status),
+            Self::Validation(_) => Some(422),
+            _ => None,
+        }
     }
 
     /// Get a user-friendly error message
@@ -79,7 +131,8 @@ impl RedmineError {
             Self::Api { status: 422, message } => {
                 format!("Validation failed: {}", message)
             }
-            Self::Validation(msg) => msg.clone(),
+            Self::Validation(errors) => errors.join("\n"),
+            Self::InvalidResponse { .. } => "Unexpected response from the server.".to_string(),
             Self::Config(msg) => format!("Configuration error: {}", msg),
             Self::Database(_) => "Database error. Try refreshing data or clearing cache.".to_string(),
             _ => format!("{}", self),
@@ -117,4 +170,46 @@ mod tests {
         let msg = err.user_message();
         assert_eq!(msg, "Resource not found.");
     }
+
+    #[test]
+    fn test_from_response() {
+        let err = RedmineError::from_response(401, "");
+        assert!(matches!(err, RedmineError::Auth { .. }));
+        assert_eq!(err.status(), Some(401));
+
+        let err = RedmineError::from_response(422, r#"{"errors":["Subject cannot be blank","Tracker is not included in the list"]}"#);
+        assert_eq!(
+            err.validation_errors(),
+            ["Subject cannot be blank", "Tracker is not included in the list"]
+        );
+        assert_eq!(err.user_message(), "Subject cannot be blank\nTracker is not included in the list");
+
+        // A 422 without an errors array stays a plain API error
+        let err = RedmineError::from_response(422, "Unprocessable");
+        assert!(matches!(err, RedmineError::Api { status: 422, .. }));
+
+        let err = RedmineError::from_response(404, "");
+        assert_eq!(err.user_message(), "Resource not found.");
+        assert_eq!(err.to_string(), "API request failed with status 404: ");
+    }
+
+    #[tokio::test]
+    async fn test_unresponsive_server_times_out() {
+        // Accepts the connection but never answers
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let client = crate::redmine::RedmineClient::with_timeouts(
+            url,
+            "key".to_string(),
+            std::time::Duration::from_millis(200),
+            std::time::Duration::from_millis(200),
+        );
+
+        let err = client.get_projects(1, 0).await.unwrap_err();
+        assert!(matches!(&err, RedmineError::Network(e) if e.is_timeout()));
+        assert!(err.is_offline());
+        assert!(err.may_have_been_applied());
+        assert_eq!(err.user_message(), "Request timed out. Please check your connection.");
+        drop(listener);
+    }
 }
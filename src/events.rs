@@ -9,6 +9,12 @@ pub enum Event {
 
 pub struct EventHandler;
 
+impl Default for EventHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EventHandler {
     pub fn new() -> Self {
         Self
@@ -17,10 +23,8 @@ impl EventHandler {
     pub fn next(&self) -> anyhow::Result<Event> {
         if event::poll(Duration::from_millis(250))? {
             match event::read()? {
-                CrosstermEvent::Key(key) => {
-                    if key.kind == KeyEventKind::Press {
-                        return Ok(Event::Key(key));
-                    }
+                CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+                    return Ok(Event::Key(key));
                 }
                 CrosstermEvent::Mouse(mouse) => {
                     return Ok(Event::Mouse(mouse));
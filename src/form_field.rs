@@ -13,6 +13,7 @@ pub enum FieldType {
     Float,              // Decimal input
     Checkbox,           // Boolean toggle
     Progress,           // Progress bar (0-100%)
+    MultiSelect,        // Pick any number of options, with search (like watchers)
 }
 
 /// Represents a single option in a dropdown
@@ -30,6 +31,7 @@ pub enum FieldValue {
     Float(Option<f32>),
     Boolean(bool),
     OptionId(Option<u64>), // For dropdowns - stores the selected ID
+    OptionIds(Vec<u64>),   // For multi-selects - stores the checked IDs in the order they were picked
 }
 
 impl FieldValue {
@@ -50,6 +52,8 @@ impl FieldValue {
     pub fn as_float(&self) -> Option<f32> {
         match self {
             FieldValue::Float(f) => *f,
+            // Float fields are edited as text, so parse whatever was typed
+            FieldValue::Text(s) => s.trim().replace(',', ".").parse().ok(),
             _ => None,
         }
     }
@@ -67,6 +71,13 @@ impl FieldValue {
             _ => None,
         }
     }
+
+    pub fn as_option_ids(&self) -> &[u64] {
+        match self {
+            FieldValue::OptionIds(ids) => ids,
+            _ => &[],
+        }
+    }
 }
 
 /// Represents a form field definition
@@ -130,6 +141,18 @@ impl FormField {
         }
     }
 
+    pub fn new_multi_select(key: &str, label: &str, options: Vec<FieldOption>) -> Self {
+        Self {
+            key: key.to_string(),
+            label: label.to_string(),
+            field_type: FieldType::MultiSelect,
+            required: false,
+            options,
+            default_value: Some(FieldValue::OptionIds(Vec::new())),
+            help_text: None,
+        }
+    }
+
     pub fn new_date(key: &str, label: &str, required: bool) -> Self {
         Self {
             key: key.to_string(),
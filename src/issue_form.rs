@@ -1,6 +1,18 @@
-use crate::form_field::{FieldOption, FieldValue, FormField};
-use crate::redmine::{IssueCategory, IssueCustomField, IssueStatus, Priority, Tracker, User};
-use std::collections::HashMap;
+use crate::form_field::{FieldOption, FieldType, FieldValue, FormField};
+use crate::redmine::{
+    IdName, Issue, IssueCategory, IssueCustomField, IssueStatus, Priority, TimeEntryActivity, Tracker, User, Version,
+    RELATION_TYPES,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Choices the issue forms offer that depend on the issue's project
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProjectChoices<'a> {
+    pub categories: &'a [IssueCategory],
+    pub versions: &'a [Version],
+    /// Cached issues offered as parents
+    pub parent_issues: &'a [Issue],
+}
 
 /// Manages the state of an issue form (create or update)
 pub struct IssueForm {
@@ -9,7 +21,10 @@ pub struct IssueForm {
     pub current_field_idx: usize,
     pub search_text: HashMap<String, String>, // For searchable dropdowns
     pub search_mode: HashMap<String, bool>,   // Track if field is in search mode
+    pub option_cursor: HashMap<String, u64>,  // Highlighted option of multi-selects
     pub scroll_offset: usize,                 // For scrollable forms
+    pub field_errors: HashMap<String, String>, // Server validation messages by field key
+    pub form_errors: Vec<String>,             // Server validation messages that name no field
 }
 
 impl IssueForm {
@@ -20,7 +35,10 @@ impl IssueForm {
             current_field_idx: 0,
             search_text: HashMap::new(),
             search_mode: HashMap::new(),
+            option_cursor: HashMap::new(),
             scroll_offset: 0,
+            field_errors: HashMap::new(),
+            form_errors: Vec::new(),
         }
     }
 
@@ -30,9 +48,9 @@ impl IssueForm {
         statuses: &[IssueStatus],
         priorities: &[Priority],
         users: &[User],
-        categories: &[IssueCategory],
+        project: ProjectChoices,
     ) -> Self {
-        Self::new_issue_form_with_custom_fields(trackers, statuses, priorities, users, categories, &[])
+        Self::new_issue_form_with_custom_fields(trackers, statuses, priorities, users, project, &[])
     }
 
     /// Build a form for creating a new issue with custom fields
@@ -41,7 +59,7 @@ impl IssueForm {
         statuses: &[IssueStatus],
         priorities: &[Priority],
         users: &[User],
-        categories: &[IssueCategory],
+        project: ProjectChoices,
         custom_fields: &[IssueCustomField],
     ) -> Self {
         let mut form = Self::new();
@@ -114,7 +132,7 @@ impl IssueForm {
             .collect();
         
         // Sort alphabetically by name (case-insensitive)
-        user_options.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        user_options.sort_by_key(|a| a.name.to_lowercase());
         
         // Add "(Unassigned)" at the beginning
         user_options.insert(0, FieldOption {
@@ -130,12 +148,12 @@ impl IssueForm {
         ));
 
         // Category - optional dropdown (project-specific)
-        if !categories.is_empty() {
+        if !project.categories.is_empty() {
             let category_options: Vec<FieldOption> = std::iter::once(FieldOption {
                 id: 0,
                 name: "(None)".to_string(),
             })
-            .chain(categories.iter().map(|c| FieldOption {
+            .chain(project.categories.iter().map(|c| FieldOption {
                 id: c.id,
                 name: c.name.clone(),
             }))
@@ -148,6 +166,36 @@ impl IssueForm {
             ));
         }
 
+        // Target Version - optional searchable dropdown of the project's open versions
+        if project.versions.iter().any(Version::is_open) {
+            form.add_field(FormField::new_searchable_dropdown(
+                "fixed_version_id",
+                "Target Version",
+                version_options(project.versions, None),
+                false,
+            ));
+        }
+
+        // Parent Issue - optional searchable dropdown of cached issues
+        form.add_field(FormField::new_searchable_dropdown(
+            "parent_issue_id",
+            "Parent Issue",
+            parent_issue_options(project.parent_issues, None),
+            false,
+        ));
+
+        // Watchers - optional multi-select of project members, sorted alphabetically
+        let mut watcher_options: Vec<FieldOption> = users
+            .iter()
+            .filter(|u| u.id != 0)
+            .map(|u| FieldOption {
+                id: u.id,
+                name: format!("{} {}", u.firstname, u.lastname).trim().to_string(),
+            })
+            .collect();
+        watcher_options.sort_by_key(|a| a.name.to_lowercase());
+        form.add_field(FormField::new_multi_select("watcher_user_ids", "Watchers", watcher_options));
+
         // Start Date - optional date field
         form.add_field(FormField::new_date("start_date", "Start Date", false));
 
@@ -224,7 +272,7 @@ impl IssueForm {
             .collect();
         
         // Sort alphabetically by name
-        user_options.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        user_options.sort_by_key(|a| a.name.to_lowercase());
         
         // Add "(Unassigned)" at the beginning
         user_options.insert(0, FieldOption {
@@ -239,15 +287,12 @@ impl IssueForm {
         form
     }
 
-    /// Build a form for replying to/updating an issue
+    /// Build a form for replying to/updating an issue, starting from its current values
     pub fn update_issue_form(
         statuses: &[IssueStatus],
         users: &[User],
-        categories: &[IssueCategory],
-        current_status_id: u64,
-        current_assignee_id: Option<u64>,
-        current_done_ratio: Option<u32>,
-        current_category_id: Option<u64>,
+        project: ProjectChoices,
+        issue: &Issue,
     ) -> Self {
         let mut form = Self::new();
 
@@ -263,7 +308,7 @@ impl IssueForm {
             })
             .collect();
         let mut status_field = FormField::new_searchable_dropdown("status_id", "Status", status_options, false);
-        status_field.default_value = Some(FieldValue::OptionId(Some(current_status_id)));
+        status_field.default_value = Some(FieldValue::OptionId(Some(issue.status.id)));
         form.add_field(status_field);
 
         // Assignee - optional searchable dropdown, sorted alphabetically
@@ -277,7 +322,7 @@ impl IssueForm {
             .collect();
         
         // Sort alphabetically by name (case-insensitive)
-        user_options.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+        user_options.sort_by_key(|a| a.name.to_lowercase());
         
         // Add "(Unassigned)" at the beginning
         user_options.insert(0, FieldOption {
@@ -286,30 +331,53 @@ impl IssueForm {
         });
         
         let mut assignee_field = FormField::new_searchable_dropdown("assigned_to_id", "Assignee", user_options, false);
-        assignee_field.default_value = Some(FieldValue::OptionId(current_assignee_id));
+        assignee_field.default_value = Some(FieldValue::OptionId(issue.assigned_to.as_ref().map(|a| a.id)));
         form.add_field(assignee_field);
 
         // Progress/Done Ratio - optional progress field
         let mut progress_field = FormField::new_progress("done_ratio", "% Done");
-        progress_field.default_value = Some(FieldValue::Number(current_done_ratio));
+        progress_field.default_value = Some(FieldValue::Number(issue.done_ratio));
         form.add_field(progress_field);
 
         // Category - optional searchable dropdown (project-specific)
-        if !categories.is_empty() {
+        if !project.categories.is_empty() {
             let category_options: Vec<FieldOption> = std::iter::once(FieldOption {
                 id: 0,
                 name: "(None)".to_string(),
             })
-            .chain(categories.iter().map(|c| FieldOption {
+            .chain(project.categories.iter().map(|c| FieldOption {
                 id: c.id,
                 name: c.name.clone(),
             }))
             .collect();
             let mut category_field = FormField::new_searchable_dropdown("category_id", "Category", category_options, false);
-            category_field.default_value = Some(FieldValue::OptionId(current_category_id));
+            category_field.default_value = Some(FieldValue::OptionId(issue.category.as_ref().map(|c| c.id)));
             form.add_field(category_field);
         }
 
+        // Target Version - optional searchable dropdown, keeping the issue's version even if no longer open
+        let current_version = issue.fixed_version.as_ref();
+        if project.versions.iter().any(Version::is_open) || current_version.is_some() {
+            let mut version_field = FormField::new_searchable_dropdown(
+                "fixed_version_id",
+                "Target Version",
+                version_options(project.versions, current_version),
+                false,
+            );
+            version_field.default_value = Some(FieldValue::OptionId(Some(current_version.map_or(0, |v| v.id))));
+            form.add_field(version_field);
+        }
+
+        // Parent Issue - optional searchable dropdown, without the issue and its subtasks
+        let mut parent_field = FormField::new_searchable_dropdown(
+            "parent_issue_id",
+            "Parent Issue",
+            parent_issue_options(project.parent_issues, Some(issue.id)),
+            false,
+        );
+        parent_field.default_value = Some(FieldValue::OptionId(Some(issue.parent.as_ref().map_or(0, |p| p.id))));
+        form.add_field(parent_field);
+
         // Due Date - optional date field
         form.add_field(FormField::new_date("due_date", "Due Date", false));
 
@@ -322,6 +390,81 @@ impl IssueForm {
         form
     }
 
+    /// Build a form for logging or editing a time entry
+    pub fn time_entry_form(
+        activities: &[TimeEntryActivity],
+        hours: Option<f32>,
+        activity_id: Option<u64>,
+        spent_on: &str,
+        comments: &str,
+    ) -> Self {
+        let mut form = Self::new();
+
+        // Hours - required, edited as text so a prefilled value can be changed in place
+        let hours_text = hours.map(|h| format!("{:.2}", h)).unwrap_or_default();
+        form.add_field(
+            FormField::new_float("hours", "Hours", true)
+                .with_help_text("e.g. 1.5")
+                .with_default(FieldValue::Text(hours_text)),
+        );
+
+        // Activity - falls back to the server's default activity
+        let activity_options: Vec<FieldOption> = activities
+            .iter()
+            .map(|a| FieldOption {
+                id: a.id,
+                name: a.name.clone(),
+            })
+            .collect();
+        let default_activity_id = activity_id
+            .or_else(|| activities.iter().find(|a| a.is_default).map(|a| a.id))
+            .or_else(|| activities.first().map(|a| a.id));
+        let mut activity_field =
+            FormField::new_dropdown("activity_id", "Activity", activity_options, !activities.is_empty());
+        activity_field.default_value = Some(FieldValue::OptionId(default_activity_id));
+        form.add_field(activity_field);
+
+        // Date - defaults to today
+        form.add_field(
+            FormField::new_date("spent_on", "Date", true).with_default(FieldValue::Text(spent_on.to_string())),
+        );
+
+        // Comment - optional single line
+        form.add_field(
+            FormField::new_text("comments", "Comment", false).with_default(FieldValue::Text(comments.to_string())),
+        );
+
+        form
+    }
+
+    /// Build a form for relating an issue to another one. Relation type options are
+    /// identified by their index in `RELATION_TYPES`.
+    pub fn relation_form() -> Self {
+        let mut form = Self::new();
+
+        let type_options: Vec<FieldOption> = RELATION_TYPES
+            .iter()
+            .enumerate()
+            .map(|(i, (_, label))| FieldOption {
+                id: i as u64,
+                name: label.to_string(),
+            })
+            .collect();
+        let relates = RELATION_TYPES.iter().position(|(key, _)| *key == "relates");
+        form.add_field(
+            FormField::new_dropdown("relation_type", "Relation", type_options, true)
+                .with_default(FieldValue::OptionId(relates.map(|i| i as u64))),
+        );
+
+        form.add_field(FormField::new_text("issue_to_id", "Issue", true).with_help_text("Issue number, e.g. 1234"));
+
+        form.add_field(
+            FormField::new_text("delay", "Delay (days)", false).with_help_text("Only for precedes and follows"),
+        );
+
+        form
+    }
+
     pub fn add_field(&mut self, field: FormField) {
         // Initialize value with default
         if let Some(default_value) = &field.default_value {
@@ -376,9 +519,60 @@ impl IssueForm {
     }
 
     pub fn set_value(&mut self, key: String, value: FieldValue) {
+        // Editing a field dismisses the server's complaint about it
+        self.field_errors.remove(&key);
         self.values.insert(key, value);
     }
 
+    pub fn field_error(&self, key: &str) -> Option<&str> {
+        self.field_errors.get(key).map(String::as_str)
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.field_errors.is_empty() || !self.form_errors.is_empty()
+    }
+
+    /// Attach the messages of a 422 response to the fields they name.
+    ///
+    /// Redmine prefixes each message with the attribute's label ("Subject cannot be blank",
+    /// "Due date must be greater than start date"), so the longest matching label wins.
+    /// Messages that match no field are kept in `form_errors`. Focus moves to the first
+    /// field with an error.
+    pub fn apply_server_errors(&mut self, errors: &[String]) {
+        self.field_errors.clear();
+        self.form_errors.clear();
+
+        for message in errors {
+            let lower = message.to_lowercase();
+            let matched = self
+                .fields
+                .iter()
+                .flat_map(|field| server_labels(field).into_iter().map(move |label| (field, label)))
+                .filter(|(_, label)| {
+                    lower
+                        .strip_prefix(label.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+                })
+                .max_by_key(|(_, label)| label.len())
+                .map(|(field, _)| field.key.clone());
+
+            match matched {
+                Some(key) => {
+                    let entry = self.field_errors.entry(key).or_default();
+                    if !entry.is_empty() {
+                        entry.push_str("; ");
+                    }
+                    entry.push_str(message);
+                }
+                None => self.form_errors.push(message.clone()),
+            }
+        }
+
+        if let Some(idx) = self.fields.iter().position(|f| self.field_errors.contains_key(&f.key)) {
+            self.current_field_idx = idx;
+        }
+    }
+
     pub fn get_search_text(&self, key: &str) -> String {
         self.search_text.get(key).cloned().unwrap_or_else(String::new)
     }
@@ -395,6 +589,52 @@ impl IssueForm {
         self.search_mode.insert(key, mode);
     }
 
+    /// Whether the focused field is a dropdown taking search text
+    pub fn is_searching(&self) -> bool {
+        self.get_current_field().is_some_and(|field| self.is_search_mode(&field.key))
+    }
+
+    /// Option highlighted in a multi-select: the remembered one while the search still shows it,
+    /// otherwise the first option the search shows
+    pub fn option_cursor(&self, key: &str) -> Option<u64> {
+        let options = self.get_filtered_options(key);
+        self.option_cursor
+            .get(key)
+            .copied()
+            .filter(|id| options.iter().any(|o| o.id == *id))
+            .or_else(|| options.first().map(|o| o.id))
+    }
+
+    /// Move a multi-select's highlight through the options the search shows
+    pub fn move_option_cursor(&mut self, key: &str, step: isize) {
+        let options: Vec<u64> = self.get_filtered_options(key).iter().map(|o| o.id).collect();
+        if options.is_empty() {
+            return;
+        }
+        let current = self
+            .option_cursor(key)
+            .and_then(|id| options.iter().position(|o| *o == id))
+            .unwrap_or(0) as isize;
+        let next = (current + step).clamp(0, options.len() as isize - 1) as usize;
+        self.option_cursor.insert(key.to_string(), options[next]);
+    }
+
+    /// Check or uncheck the highlighted option of a multi-select
+    pub fn toggle_highlighted_option(&mut self, key: &str) {
+        let Some(id) = self.option_cursor(key) else {
+            return;
+        };
+        let mut ids = self.get_value(key).map(|v| v.as_option_ids().to_vec()).unwrap_or_default();
+        match ids.iter().position(|selected| *selected == id) {
+            Some(i) => {
+                ids.remove(i);
+            }
+            None => ids.push(id),
+        }
+        self.option_cursor.insert(key.to_string(), id);
+        self.set_value(key.to_string(), FieldValue::OptionIds(ids));
+    }
+
     pub fn clear_search(&mut self, key: &str) {
         self.search_text.remove(key);
         self.search_mode.remove(key);
@@ -409,7 +649,10 @@ impl IssueForm {
                     Some(FieldValue::Text(s)) if s.is_empty() => {
                         return Err(format!("{} is required", field.label));
                     }
-                    Some(FieldValue::OptionId(None)) => {
+                    Some(FieldValue::OptionId(None)) | Some(FieldValue::Float(None)) => {
+                        return Err(format!("{} is required", field.label));
+                    }
+                    Some(FieldValue::OptionIds(ids)) if ids.is_empty() => {
                         return Err(format!("{} is required", field.label));
                     }
                     None => {
@@ -418,6 +661,15 @@ impl IssueForm {
                     _ => {}
                 }
             }
+
+            // Typed numbers must parse, even on optional fields
+            if field.field_type == FieldType::Float {
+                if let Some(value @ FieldValue::Text(s)) = self.values.get(&field.key) {
+                    if !s.trim().is_empty() && value.as_float().is_none() {
+                        return Err(format!("{} must be a number", field.label));
+                    }
+                }
+            }
         }
         Ok(())
     }
@@ -455,6 +707,81 @@ impl IssueForm {
     }
 }
 
+/// "(None)" and the open versions, plus the issue's current version when it is no longer open
+fn version_options(versions: &[Version], current: Option<&IdName>) -> Vec<FieldOption> {
+    let mut options = vec![FieldOption {
+        id: 0,
+        name: "(None)".to_string(),
+    }];
+    options.extend(
+        versions
+            .iter()
+            .filter(|v| v.is_open() || current.is_some_and(|c| c.id == v.id))
+            .map(|v| FieldOption {
+                id: v.id,
+                name: v.name.clone(),
+            }),
+    );
+    if let Some(current) = current.filter(|c| !options.iter().any(|o| o.id == c.id)) {
+        options.push(FieldOption {
+            id: current.id,
+            name: current.name.clone(),
+        });
+    }
+    options
+}
+
+/// "(None)" and the given issues as "#id subject", so either can be searched. Excludes an issue
+/// and its subtasks, which can't become its parent.
+fn parent_issue_options(issues: &[Issue], exclude: Option<u64>) -> Vec<FieldOption> {
+    let mut excluded: HashSet<u64> = exclude.into_iter().collect();
+    // Subtasks can be listed before their parent, so repeat until nothing new is excluded
+    loop {
+        let subtasks: Vec<u64> = issues
+            .iter()
+            .filter(|issue| !excluded.contains(&issue.id))
+            .filter(|issue| issue.parent.as_ref().is_some_and(|p| excluded.contains(&p.id)))
+            .map(|issue| issue.id)
+            .collect();
+        if subtasks.is_empty() {
+            break;
+        }
+        excluded.extend(subtasks);
+    }
+
+    std::iter::once(FieldOption {
+        id: 0,
+        name: "(None)".to_string(),
+    })
+    .chain(
+        issues
+            .iter()
+            .filter(|issue| !excluded.contains(&issue.id))
+            .map(|issue| FieldOption {
+                id: issue.id,
+                name: format!("#{} {}", issue.id, issue.subject),
+            }),
+    )
+    .collect()
+}
+
+/// Lowercased labels Redmine may use for a field in validation messages
+fn server_labels(field: &FormField) -> Vec<String> {
+    // Our label without hints like "Notes (comment)"
+    let label = field.label.split(" (").next().unwrap_or(&field.label).to_lowercase();
+
+    let alias = match field.key.as_str() {
+        "estimated_hours" => Some("estimated time"),
+        "assigned_to_id" => Some("assignee"),
+        "parent_issue_id" => Some("parent task"),
+        "fixed_version_id" => Some("target version"),
+        "spent_on" => Some("date"),
+        _ => None,
+    };
+
+    std::iter::once(label).chain(alias.map(str::to_string)).collect()
+}
+
 impl Default for IssueForm {
     fn default() -> Self {
         Self::new()
@@ -577,4 +904,214 @@ mod tests {
         assert!(form.get_value("key1").is_some());
         assert_eq!(form.get_value("key1").unwrap().as_text().unwrap(), "value1");
     }
+
+    #[test]
+    fn test_float_field_parses_typed_text() {
+        let mut form = IssueForm::new();
+        form.add_field(FormField::new_float("hours", "Hours", true));
+
+        // Untouched required float is missing
+        assert!(form.validate().is_err());
+
+        form.set_value("hours".to_string(), FieldValue::Text("1,5".to_string()));
+        assert!(form.validate().is_ok());
+        assert_eq!(form.get_value("hours").unwrap().as_float(), Some(1.5));
+
+        form.set_value("hours".to_string(), FieldValue::Text("abc".to_string()));
+        assert_eq!(form.validate().unwrap_err(), "Hours must be a number");
+    }
+
+    #[test]
+    fn test_server_errors_map_to_fields() {
+        let mut form = IssueForm::new();
+        form.add_field(FormField::new_text("subject", "Subject", true));
+        form.add_field(FormField::new_date("start_date", "Start Date", false));
+        form.add_field(FormField::new_date("due_date", "Due Date", false));
+        form.add_field(FormField::new_float("estimated_hours", "Estimated Hours", false));
+        form.add_field(FormField::new_textarea("notes", "Notes (comment)", false));
+
+        form.apply_server_errors(&[
+            "Due date must be greater than start date".to_string(),
+            "Estimated time is invalid".to_string(),
+            "Notes is too long (maximum is 65535 characters)".to_string(),
+            "Subject cannot be blank".to_string(),
+            "Parent task is invalid".to_string(),
+        ]);
+
+        assert_eq!(form.field_error("subject"), Some("Subject cannot be blank"));
+        assert_eq!(form.field_error("due_date"), Some("Due date must be greater than start date"));
+        assert_eq!(form.field_error("start_date"), None);
+        assert_eq!(form.field_error("estimated_hours"), Some("Estimated time is invalid"));
+        assert!(form.field_error("notes").is_some());
+        assert_eq!(form.form_errors, vec!["Parent task is invalid".to_string()]);
+
+        // Focus jumps to the first field with an error
+        assert_eq!(form.current_field_idx, 0);
+
+        // Fixing a field clears its error
+        form.set_value("subject".to_string(), FieldValue::Text("Fixed".to_string()));
+        assert_eq!(form.field_error("subject"), None);
+        assert!(form.has_errors());
+    }
+
+    #[test]
+    fn test_parent_issue_options() {
+        let issue = |id: u64, parent: Option<u64>| -> Issue {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "project": {"id": 1, "name": "Project"},
+                "tracker": {"id": 1, "name": "Task"},
+                "status": {"id": 1, "name": "New"},
+                "priority": {"id": 2, "name": "Normal"},
+                "author": {"id": 1, "name": "Me"},
+                "parent": parent.map(|id| serde_json::json!({"id": id})),
+                "subject": format!("Issue {}", id),
+                "created_on": "2025-01-01T00:00:00Z",
+                "updated_on": "2025-01-01T00:00:00Z",
+            }))
+            .unwrap()
+        };
+        // 4 is a subtask of 3, which is a subtask of 2; 4 is listed before its parent
+        let issues = vec![issue(1, None), issue(4, Some(3)), issue(2, None), issue(3, Some(2))];
+
+        let project = ProjectChoices {
+            parent_issues: &issues,
+            ..Default::default()
+        };
+        let form = IssueForm::update_issue_form(&[], &[], project, &issues[3]);
+        let names: Vec<&str> = form
+            .fields
+            .iter()
+            .find(|f| f.key == "parent_issue_id")
+            .unwrap()
+            .options
+            .iter()
+            .map(|o| o.name.as_str())
+            .collect();
+        // The issue and its subtasks can't become its parent
+        assert_eq!(names, vec!["(None)", "#1 Issue 1", "#2 Issue 2"]);
+        assert_eq!(form.get_value("parent_issue_id").unwrap().as_option_id(), Some(2));
+
+        // Searching by number finds the issue
+        let mut form = IssueForm::new_issue_form(&[], &[], &[], &[], project);
+        form.set_search_text("parent_issue_id".to_string(), "#4".to_string());
+        let found: Vec<u64> = form.get_filtered_options("parent_issue_id").iter().map(|o| o.id).collect();
+        assert_eq!(found, vec![4]);
+    }
+
+    #[test]
+    fn test_multi_select_toggles_highlighted_option() {
+        let mut form = IssueForm::new();
+        let options = ["Alice", "Bob", "Charlie"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| FieldOption {
+                id: i as u64 + 1,
+                name: name.to_string(),
+            })
+            .collect();
+        form.add_field(FormField::new_multi_select("watchers", "Watchers", options));
+
+        // The highlight starts on the first option and stops at the ends
+        assert_eq!(form.option_cursor("watchers"), Some(1));
+        form.move_option_cursor("watchers", -1);
+        assert_eq!(form.option_cursor("watchers"), Some(1));
+        form.move_option_cursor("watchers", 2);
+        form.toggle_highlighted_option("watchers");
+        assert_eq!(form.get_value("watchers").unwrap().as_option_ids(), &[3]);
+
+        // Searching moves the highlight onto a match
+        form.set_search_text("watchers".to_string(), "bo".to_string());
+        form.toggle_highlighted_option("watchers");
+        assert_eq!(form.get_value("watchers").unwrap().as_option_ids(), &[3, 2]);
+
+        // Toggling again unchecks it
+        form.toggle_highlighted_option("watchers");
+        assert_eq!(form.get_value("watchers").unwrap().as_option_ids(), &[3]);
+        assert!(form.validate().is_ok());
+    }
+
+    #[test]
+    fn test_target_version_options() {
+        let version = |id: u64, name: &str, status: &str| Version {
+            id,
+            project: IdName {
+                id: 1,
+                name: "Project".to_string(),
+            },
+            name: name.to_string(),
+            description: None,
+            status: status.to_string(),
+            due_date: None,
+            sharing: None,
+        };
+        let versions = vec![version(1, "1.0", "closed"), version(2, "2.0", "open"), version(3, "2.1", "locked")];
+        let project = ProjectChoices {
+            versions: &versions,
+            ..Default::default()
+        };
+        let options = |form: &IssueForm| -> Vec<String> {
+            form.fields
+                .iter()
+                .find(|f| f.key == "fixed_version_id")
+                .unwrap()
+                .options
+                .iter()
+                .map(|o| o.name.clone())
+                .collect()
+        };
+
+        // New issues can only target open versions
+        let form = IssueForm::new_issue_form(&[], &[], &[], &[], project);
+        assert_eq!(options(&form), vec!["(None)", "2.0"]);
+
+        // An issue keeps its closed version as a choice, and it starts selected
+        let mut issue: Issue = serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "project": {"id": 1, "name": "Project"},
+            "tracker": {"id": 1, "name": "Task"},
+            "status": {"id": 1, "name": "New"},
+            "priority": {"id": 2, "name": "Normal"},
+            "author": {"id": 1, "name": "Me"},
+            "fixed_version": {"id": 1, "name": "1.0"},
+            "subject": "Shipped",
+            "created_on": "2025-01-01T00:00:00Z",
+            "updated_on": "2025-01-01T00:00:00Z",
+        }))
+        .unwrap();
+        let form = IssueForm::update_issue_form(&[], &[], project, &issue);
+        assert_eq!(options(&form), vec!["(None)", "1.0", "2.0"]);
+        assert_eq!(form.get_value("fixed_version_id").unwrap().as_option_id(), Some(1));
+
+        issue.fixed_version = None;
+        let form = IssueForm::update_issue_form(&[], &[], project, &issue);
+        assert_eq!(form.get_value("fixed_version_id").unwrap().as_option_id(), Some(0));
+    }
+
+    #[test]
+    fn test_time_entry_form_defaults() {
+        let activities = vec![
+            TimeEntryActivity {
+                id: 8,
+                name: "Design".to_string(),
+                is_default: false,
+            },
+            TimeEntryActivity {
+                id: 9,
+                name: "Development".to_string(),
+                is_default: true,
+            },
+        ];
+
+        let form = IssueForm::time_entry_form(&activities, Some(0.75), None, "2024-11-04", "");
+        assert_eq!(form.get_value("hours").unwrap().as_float(), Some(0.75));
+        assert_eq!(form.get_value("activity_id").unwrap().as_option_id(), Some(9));
+        assert_eq!(form.get_value("spent_on").unwrap().as_text(), Some("2024-11-04"));
+        assert!(form.validate().is_ok());
+
+        // Editing keeps the entry's own activity
+        let form = IssueForm::time_entry_form(&activities, Some(2.0), Some(8), "2024-11-01", "Review");
+        assert_eq!(form.get_value("activity_id").unwrap().as_option_id(), Some(8));
+        assert_eq!(form.get_value("comments").unwrap().as_text(), Some("Review"));
+    }
 }
@@ -0,0 +1,473 @@
+//! Filter syntax for the issue list.
+//!
+//! A query is a list of terms that must all match, e.g.
+//! `status:open assignee:me priority:>=high tracker:Bug version:2.0 due:<7d updated:>2w "login page"`.
+//! Words and quoted phrases without a `field:` prefix search the subject, description and notes.
+//! The parsed query is compiled to SQL by `Database::get_issues`.
+
+use chrono::{Months, NaiveDate};
+
+/// Filter names accepted before a `:`
+pub const FILTER_FIELDS: &[&str] = &[
+    "status", "assignee", "author", "watcher", "priority", "tracker", "version", "due", "updated", "created",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Comparison::Eq => "=",
+            Comparison::Ne => "<>",
+            Comparison::Lt => "<",
+            Comparison::Le => "<=",
+            Comparison::Gt => ">",
+            Comparison::Ge => ">=",
+        }
+    }
+
+    /// Whether `ordering` (left compared with right) satisfies the comparison
+    pub fn matches(&self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering::*;
+        match self {
+            Comparison::Eq => ordering == Equal,
+            Comparison::Ne => ordering != Equal,
+            Comparison::Lt => ordering == Less,
+            Comparison::Le => ordering != Greater,
+            Comparison::Gt => ordering == Greater,
+            Comparison::Ge => ordering != Less,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StatusValue {
+    Open,
+    Closed,
+    Any,
+    Named(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserValue {
+    Me,
+    Nobody,
+    Id(u64),
+    Named(String),
+}
+
+/// Target version of an issue
+#[derive(Debug, Clone, PartialEq)]
+pub enum VersionValue {
+    Nobody,
+    Id(u64),
+    Named(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateField {
+    Due,
+    Updated,
+    Created,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateUnit {
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateValue {
+    Absolute(NaiveDate),
+    /// Offset from today: forward for due dates, back for created/updated (a `-` sign flips it)
+    Relative {
+        amount: i64,
+        unit: DateUnit,
+    },
+}
+
+impl DateValue {
+    /// The day this value stands for when filtering `field`
+    pub fn resolve(&self, field: DateField, today: NaiveDate) -> NaiveDate {
+        let (amount, unit) = match *self {
+            DateValue::Absolute(date) => return date,
+            DateValue::Relative { amount, unit } => (amount, unit),
+        };
+        let amount = if field == DateField::Due { amount } else { -amount };
+
+        let shifted = match unit {
+            DateUnit::Days => today.checked_add_signed(chrono::Duration::days(amount)),
+            DateUnit::Weeks => today.checked_add_signed(chrono::Duration::weeks(amount)),
+            DateUnit::Months | DateUnit::Years => {
+                let months = if unit == DateUnit::Years { amount * 12 } else { amount };
+                let months = Months::new(months.unsigned_abs().min(u32::MAX as u64) as u32);
+                if amount >= 0 {
+                    today.checked_add_months(months)
+                } else {
+                    today.checked_sub_months(months)
+                }
+            }
+        };
+        shifted.unwrap_or(today)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Term {
+    Status(Comparison, StatusValue),
+    Assignee(Comparison, UserValue),
+    Author(Comparison, UserValue),
+    Tracker(Comparison, String),
+    Version(Comparison, VersionValue),
+    Priority(Comparison, String),
+    Date(DateField, Comparison, DateValue),
+    /// A word, matched as a prefix in subject, description and notes, or against the issue number
+    Word(String),
+    /// A quoted phrase, matched as a whole
+    Phrase(String),
+    /// Issues the last run of a Redmine saved query returned (set by the app, not written in queries)
+    SavedQuery(u64),
+    /// Issues the current user watches (or with `Ne`, doesn't), as last reported by the server
+    Watched(Comparison),
+}
+
+/// A parsed filter: every term must match
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FilterQuery {
+    pub terms: Vec<Term>,
+}
+
+/// What's wrong with a query, and where (byte offset into the input) if it's a syntax error
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("{message}")]
+pub struct QueryError {
+    pub message: String,
+    pub position: Option<usize>,
+}
+
+impl QueryError {
+    fn at(position: usize, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            position: Some(position),
+        }
+    }
+
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            position: None,
+        }
+    }
+}
+
+/// Everything outside the query that compiling it needs
+#[derive(Debug, Clone)]
+pub struct QueryContext {
+    pub current_user_id: Option<u64>,
+    /// Statuses that count as closed; when empty, issues with a close date count as closed
+    pub closed_status_ids: Vec<u64>,
+    /// Priorities from lowest to highest (`id`, `name`); when empty, the cached ones ordered by id
+    pub priorities: Vec<(u64, String)>,
+    pub today: NaiveDate,
+}
+
+impl Default for QueryContext {
+    fn default() -> Self {
+        Self {
+            current_user_id: None,
+            closed_status_ids: Vec::new(),
+            priorities: Vec::new(),
+            today: chrono::Local::now().date_naive(),
+        }
+    }
+}
+
+impl FilterQuery {
+    pub fn parse(input: &str) -> Result<Self, QueryError> {
+        let mut terms = Vec::new();
+        let mut chars = input.char_indices().peekable();
+
+        while let Some(&(start, c)) = chars.peek() {
+            if c.is_whitespace() {
+                chars.next();
+                continue;
+            }
+
+            // Read up to the next whitespace outside quotes
+            let mut token = String::new();
+            let mut quote_start = None;
+            while let Some(&(i, c)) = chars.peek() {
+                if c.is_whitespace() && quote_start.is_none() {
+                    break;
+                }
+                chars.next();
+                if c == '"' {
+                    quote_start = match quote_start {
+                        Some(_) => None,
+                        None => Some(i),
+                    };
+                }
+                token.push(c);
+            }
+            if let Some(i) = quote_start {
+                return Err(QueryError::at(i, "Missing closing quote"));
+            }
+
+            terms.push(parse_term(&token, start)?);
+        }
+
+        Ok(Self { terms })
+    }
+}
+
+fn parse_term(token: &str, start: usize) -> Result<Term, QueryError> {
+    if let Some(phrase) = token.strip_prefix('"') {
+        let phrase = phrase.strip_suffix('"').unwrap_or(phrase).trim();
+        if phrase.is_empty() {
+            return Err(QueryError::at(start, "Empty quotes"));
+        }
+        return Ok(Term::Phrase(phrase.to_string()));
+    }
+
+    let Some((field, rest)) = token.split_once(':') else {
+        return Ok(Term::Word(token.to_string()));
+    };
+    // URLs like `http://host` are words, not filters
+    if !field.chars().all(|c| c.is_ascii_alphabetic()) || field.is_empty() || rest.starts_with("//") {
+        return Ok(Term::Word(token.to_string()));
+    }
+    let field = field.to_lowercase();
+    let value_start = start + field.len() + 1;
+
+    let (op, value) = parse_comparison(rest);
+    let value_start = value_start + (rest.len() - value.len());
+    let value = unquote(value);
+    if value.is_empty() {
+        return Err(QueryError::at(value_start, format!("Missing value after '{}:'", field)));
+    }
+
+    let equality_only = |op: Comparison| -> Result<Comparison, QueryError> {
+        match op {
+            Comparison::Eq | Comparison::Ne => Ok(op),
+            _ => Err(QueryError::at(
+                start + field.len() + 1,
+                format!("'{}' only supports '{}:value' and '{}:!value'", field, field, field),
+            )),
+        }
+    };
+
+    match field.as_str() {
+        "status" => {
+            let status = match value.to_lowercase().as_str() {
+                "open" => StatusValue::Open,
+                "closed" => StatusValue::Closed,
+                "all" | "*" => StatusValue::Any,
+                _ => StatusValue::Named(value.to_string()),
+            };
+            Ok(Term::Status(equality_only(op)?, status))
+        }
+        "assignee" | "author" => {
+            let user = match value.to_lowercase().as_str() {
+                "me" => UserValue::Me,
+                "none" => UserValue::Nobody,
+                _ => match value.parse() {
+                    Ok(id) => UserValue::Id(id),
+                    Err(_) => UserValue::Named(value.to_string()),
+                },
+            };
+            if field == "author" && user == UserValue::Nobody {
+                return Err(QueryError::at(value_start, "Every issue has an author"));
+            }
+            let op = equality_only(op)?;
+            Ok(if field == "assignee" {
+                Term::Assignee(op, user)
+            } else {
+                Term::Author(op, user)
+            })
+        }
+        "watcher" => {
+            // Only the current user's watched issues are known, from the dashboard and opened issues
+            if !value.eq_ignore_ascii_case("me") {
+                return Err(QueryError::at(value_start, "Only 'watcher:me' and 'watcher:!me' are supported"));
+            }
+            Ok(Term::Watched(equality_only(op)?))
+        }
+        "tracker" => Ok(Term::Tracker(equality_only(op)?, value.to_string())),
+        "version" => {
+            let version = if value.eq_ignore_ascii_case("none") {
+                VersionValue::Nobody
+            } else {
+                match value.parse() {
+                    Ok(id) => VersionValue::Id(id),
+                    Err(_) => VersionValue::Named(value.to_string()),
+                }
+            };
+            Ok(Term::Version(equality_only(op)?, version))
+        }
+        "priority" => Ok(Term::Priority(op, value.to_string())),
+        "due" | "updated" | "created" => {
+            let date_field = match field.as_str() {
+                "due" => DateField::Due,
+                "updated" => DateField::Updated,
+                _ => DateField::Created,
+            };
+            let date = parse_date(value).ok_or_else(|| {
+                QueryError::at(
+                    value_start,
+                    format!(
+                        "Invalid date '{}': use YYYY-MM-DD, today, or an offset like 7d, 2w, 3m, 1y",
+                        value
+                    ),
+                )
+            })?;
+            Ok(Term::Date(date_field, op, date))
+        }
+        _ => Err(QueryError::at(
+            start,
+            format!("Unknown filter '{}'. Filters: {}", field, FILTER_FIELDS.join(", ")),
+        )),
+    }
+}
+
+/// Split a leading comparison operator off a filter value (`!` means not equal)
+fn parse_comparison(value: &str) -> (Comparison, &str) {
+    for (prefix, op) in [
+        (">=", Comparison::Ge),
+        ("<=", Comparison::Le),
+        ("!=", Comparison::Ne),
+        (">", Comparison::Gt),
+        ("<", Comparison::Lt),
+        ("!", Comparison::Ne),
+        ("=", Comparison::Eq),
+    ] {
+        if let Some(rest) = value.strip_prefix(prefix) {
+            return (op, rest);
+        }
+    }
+    (Comparison::Eq, value)
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+        .trim()
+}
+
+fn parse_date(value: &str) -> Option<DateValue> {
+    if value.eq_ignore_ascii_case("today") {
+        return Some(DateValue::Relative {
+            amount: 0,
+            unit: DateUnit::Days,
+        });
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(DateValue::Absolute(date));
+    }
+
+    let unit = match value.chars().last()?.to_ascii_lowercase() {
+        'd' => DateUnit::Days,
+        'w' => DateUnit::Weeks,
+        'm' => DateUnit::Months,
+        'y' => DateUnit::Years,
+        _ => return None,
+    };
+    let amount = value[..value.len() - 1].parse::<i64>().ok()?;
+    // Keep offsets in a range chrono can add without overflowing
+    (amount.abs() <= 100_000).then_some(DateValue::Relative { amount, unit })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query() {
+        let query = FilterQuery::parse(
+            r#"status:open assignee:me priority:>=high tracker:Bug due:<7d updated:>2w "login page" crash"#,
+        )
+        .unwrap();
+        assert_eq!(
+            query.terms,
+            vec![
+                Term::Status(Comparison::Eq, StatusValue::Open),
+                Term::Assignee(Comparison::Eq, UserValue::Me),
+                Term::Priority(Comparison::Ge, "high".to_string()),
+                Term::Tracker(Comparison::Eq, "Bug".to_string()),
+                Term::Date(
+                    DateField::Due,
+                    Comparison::Lt,
+                    DateValue::Relative {
+                        amount: 7,
+                        unit: DateUnit::Days
+                    }
+                ),
+                Term::Date(
+                    DateField::Updated,
+                    Comparison::Gt,
+                    DateValue::Relative {
+                        amount: 2,
+                        unit: DateUnit::Weeks
+                    }
+                ),
+                Term::Phrase("login page".to_string()),
+                Term::Word("crash".to_string()),
+            ]
+        );
+
+        let query =
+            FilterQuery::parse(r#"status:"In Progress" assignee:!none author:12 watcher:!me version:"1.0 beta" version:!none http://host"#)
+                .unwrap();
+        assert_eq!(
+            query.terms,
+            vec![
+                Term::Status(Comparison::Eq, StatusValue::Named("In Progress".to_string())),
+                Term::Assignee(Comparison::Ne, UserValue::Nobody),
+                Term::Author(Comparison::Eq, UserValue::Id(12)),
+                Term::Watched(Comparison::Ne),
+                Term::Version(Comparison::Eq, VersionValue::Named("1.0 beta".to_string())),
+                Term::Version(Comparison::Ne, VersionValue::Nobody),
+                Term::Word("http://host".to_string()),
+            ]
+        );
+        assert!(FilterQuery::parse("   ").unwrap().terms.is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| FilterQuery::parse(input).unwrap_err();
+
+        assert_eq!(error("bug colour:red").position, Some(4));
+        assert_eq!(error(r#"status:open "login"#).position, Some(12));
+        assert_eq!(error("status:").position, Some(7));
+        assert_eq!(error("due:>soon").position, Some(5));
+        assert!(error("tracker:>Bug").message.contains("tracker:!value"));
+        assert_eq!(error("watcher:alice").position, Some(8));
+    }
+
+    #[test]
+    fn test_resolve_dates() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        let date = |value: &str, field| parse_date(value).unwrap().resolve(field, today).to_string();
+
+        assert_eq!(date("7d", DateField::Due), "2025-04-07");
+        assert_eq!(date("-7d", DateField::Due), "2025-03-24");
+        assert_eq!(date("2w", DateField::Updated), "2025-03-17");
+        assert_eq!(date("1m", DateField::Created), "2025-02-28");
+        assert_eq!(date("today", DateField::Updated), "2025-03-31");
+        assert_eq!(date("2024-12-01", DateField::Due), "2024-12-01");
+    }
+}
@@ -1,13 +1,17 @@
-// Library exports for testing
+// Library exports shared by the binary and tests
 
 pub mod app;
+pub mod cli;
 pub mod config;
 pub mod db;
 pub mod error;
+pub mod events;
 pub mod form_field;
 pub mod issue_form;
+pub mod issue_query;
 pub mod redmine;
 pub mod theme;
+pub mod ui;
 
 // Re-export commonly used types
 pub use error::RedmineError;
@@ -1,30 +1,53 @@
-mod app;
-mod config;
-mod db;
-mod error;
-mod events;
-mod form_field;
-mod issue_form;
-mod redmine;
-mod theme;
-mod ui;
-
 use anyhow::Result;
-use app::App;
+use clap::Parser;
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use events::EventHandler;
+use minecli::app::{App, Screen};
+use minecli::cli::Cli;
+use minecli::config::Config;
+use minecli::events::{self, EventHandler};
+use minecli::ui;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
+use std::process::ExitCode;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+
     // Initialize logging
     init_logging()?;
 
+    let mut config = match Config::load() {
+        Ok(config) => config,
+        Err(e) if cli.command.is_some() => {
+            eprintln!("Error: {:#}", e);
+            return Ok(ExitCode::from(minecli::cli::EXIT_FAILURE));
+        }
+        Err(_) => Config::default(),
+    };
+    if let Some(profile) = &cli.profile {
+        if let Err(e) = config.use_profile(profile) {
+            eprintln!("Error: {}", e);
+            return Ok(ExitCode::from(minecli::cli::EXIT_USAGE));
+        }
+    }
+
+    // The key may come from MINECLI_API_KEY or api_key_command instead of the config file
+    let api_key_error = config.resolve_api_key().err();
+
+    // Subcommands run without the TUI
+    if let Some(command) = cli.command {
+        if let Some(e) = api_key_error.filter(|_| command.needs_server()) {
+            eprintln!("Error: {:#}", e);
+            return Ok(ExitCode::from(minecli::cli::EXIT_NOT_CONFIGURED));
+        }
+        return Ok(minecli::cli::run(config, command).await);
+    }
+
     // Set up panic hook to restore terminal
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
@@ -41,8 +64,19 @@ async fn main() -> Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app
-    let mut app = App::new()?;
+    // Create app (fails e.g. when the cache was written by a newer minecli)
+    let mut app = match App::new(config) {
+        Ok(app) => app,
+        Err(e) => {
+            disable_raw_mode()?;
+            execute!(terminal.backend_mut(), LeaveAlternateScreen, DisableMouseCapture)?;
+            eprintln!("Error: {:#}", e);
+            return Ok(ExitCode::from(minecli::cli::EXIT_FAILURE));
+        }
+    };
+    if let Some(e) = api_key_error {
+        app.error_message = Some(format!("Failed to get API key: {:#}", e));
+    }
 
     // Load cached data from DB immediately
     app.apply_filters();
@@ -52,29 +86,12 @@ async fn main() -> Result<()> {
         tracing::warn!("Failed to load users from cache: {}", e);
     }
 
-    // Load initial data if configured (fetch from API in background)
-    if app.client.is_some() {
-        if let Err(e) = app.load_metadata().await {
-            eprintln!("Warning: Failed to load metadata: {}", e);
-            app.error_message = Some(format!("Failed to load metadata: {}", e));
-        }
-        // Only fetch from API if we have no cached data
-        if app.total_projects == 0 {
-            if let Err(e) = app.load_projects().await {
-                eprintln!("Warning: Failed to load projects: {}", e);
-                app.error_message = Some(format!("Failed to load projects: {}", e));
-            }
-        }
+    // Load initial data if configured
+    app.load_initial_data().await;
 
-        // If users cache is empty, load them from API
-        if app.users.is_empty() {
-            if let Err(e) = app.load_all_users().await {
-                tracing::warn!("Failed to load users from API: {}", e);
-            }
-        }
-        
-        // Clear status message after startup
-        app.status_message = None;
+    // Land on the dashboard once the current user is known
+    if app.screen == Screen::Dashboard {
+        app.show_dashboard();
     }
 
     // Event handler
@@ -92,7 +109,7 @@ async fn main() -> Result<()> {
         eprintln!("Error: {:?}", err);
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
 
 /// Initialize logging to file
@@ -136,11 +153,47 @@ async fn run_app(
         
         terminal.draw(|f| ui::draw(f, app))?;
 
+        // Handle profile switch: fetch metadata and projects for the new server
+        if app.reload_profile_flag {
+            app.reload_profile_flag = false;
+            app.load_initial_data().await;
+            if app.screen == Screen::Dashboard {
+                app.show_dashboard();
+            }
+        }
+
+        // Handle dashboard refresh request
+        if app.refresh_dashboard_flag {
+            app.refresh_dashboard_flag = false;
+            if let Err(e) = app.fetch_dashboard().await {
+                app.report_error("Failed to refresh dashboard", e);
+            }
+        }
+
+        // Handle roadmap requests
+        if let Some(project_id) = app.fetch_versions_project_id.take() {
+            if let Err(e) = app.fetch_project_versions(project_id).await {
+                app.report_error("Failed to load versions", e);
+            }
+        }
+        if let Some(version_id) = app.fetch_version_issues_id.take() {
+            if let Err(e) = app.fetch_version_issues(version_id).await {
+                app.report_error("Failed to load version issues", e);
+            }
+        }
+
+        // Handle a card moved on the board
+        if let Some((issue_id, status_id)) = app.move_board_card_to.take() {
+            if let Err(e) = app.move_issue_to_status(issue_id, status_id).await {
+                app.report_error("Failed to move issue", e);
+            }
+        }
+
         // Handle refresh projects request
         if app.refresh_projects {
             app.refresh_projects = false;
             if let Err(e) = app.load_projects().await {
-                app.error_message = Some(format!("Failed to load projects: {}", e));
+                app.report_error("Failed to load projects", e);
                 app.loading = false;
             }
         }
@@ -149,7 +202,7 @@ async fn run_app(
         if app.refresh_issues {
             app.refresh_issues = false;
             if let Err(e) = app.load_issues().await {
-                app.error_message = Some(format!("Failed to load issues: {}", e));
+                app.report_error("Failed to load issues", e);
                 app.loading = false;
             }
         }
@@ -157,7 +210,7 @@ async fn run_app(
         // Handle incremental issue loading (one page per loop iteration)
         if app.issues_loading_in_progress {
             if let Err(e) = app.load_issues_next_page().await {
-                app.error_message = Some(format!("Failed to load issues: {}", e));
+                app.report_error("Failed to load issues", e);
                 app.issues_loading_in_progress = false;
                 app.loading = false;
             }
@@ -167,7 +220,7 @@ async fn run_app(
         if app.create_new_issue_flag {
             app.create_new_issue_flag = false;
             if let Err(e) = app.create_new_issue().await {
-                app.error_message = Some(format!("Failed to create issue: {}", e));
+                app.report_error("Failed to create issue", e);
             }
         }
 
@@ -177,10 +230,10 @@ async fn run_app(
             if let Some(project_id) = app.load_users_project_id.take() {
                 app.status_message = Some("Loading project data...".to_string());
                 if let Err(e) = app.load_project_users(project_id).await {
-                    app.error_message = Some(format!("Failed to load users: {}", e));
+                    app.report_error("Failed to load users", e);
                 }
                 if let Err(e) = app.load_project_metadata(project_id).await {
-                    app.error_message = Some(format!("Failed to load project metadata: {}", e));
+                    app.report_error("Failed to load project metadata", e);
                 }
                 // Final status message set by load_project_users
             }
@@ -191,16 +244,89 @@ async fn run_app(
             app.add_comment_flag = false;
             if let Some(issue_id) = app.comment_issue_id.take() {
                 if let Err(e) = app.add_comment_to_issue(issue_id).await {
-                    app.error_message = Some(format!("Failed to add comment: {}", e));
+                    app.report_error("Failed to add comment", e);
                 }
             }
         }
 
+        // Handle saved query list refresh and runs
+        if app.fetch_saved_queries_flag {
+            app.fetch_saved_queries_flag = false;
+            if let Err(e) = app.fetch_saved_queries().await {
+                app.report_error("Failed to load saved queries", e);
+            }
+        }
+        if let Some(query) = app.run_saved_query_flag.take() {
+            if let Err(e) = app.run_saved_query(query).await {
+                app.report_error("Failed to run saved query", e);
+                app.loading = false;
+            }
+        }
+
+        // Handle the user's choice after an update conflict
+        if let Some(resolution) = app.resolve_conflict_flag.take() {
+            if let Err(e) = app.resolve_issue_conflict(resolution).await {
+                app.report_error("Failed to update issue", e);
+            }
+        }
+
+        // Handle time entry save
+        if app.submit_time_entry_flag {
+            app.submit_time_entry_flag = false;
+            if let Err(e) = app.submit_time_entry().await {
+                app.report_error("Failed to save time entry", e);
+            }
+        }
+
+        // Handle time entries refresh
+        if app.load_time_entries_flag {
+            app.load_time_entries_flag = false;
+            if let Some(issue_id) = app.time_entry_issue_id {
+                if let Err(e) = app.load_time_entries(issue_id).await {
+                    app.report_error("Failed to load time entries", e);
+                }
+            }
+        }
+
+        // Handle time entry deletion
+        if let Some(time_entry_id) = app.delete_time_entry_id.take() {
+            if let Err(e) = app.delete_time_entry(time_entry_id).await {
+                app.report_error("Failed to delete time entry", e);
+            }
+        }
+
+        // Handle relation changes
+        if app.submit_relation_flag {
+            app.submit_relation_flag = false;
+            if let Err(e) = app.submit_relation().await {
+                app.report_error("Failed to add relation", e);
+            }
+        }
+        if let Some(relation_id) = app.delete_relation_id.take() {
+            if let Err(e) = app.delete_relation(relation_id).await {
+                app.report_error("Failed to remove relation", e);
+            }
+        }
+        if let Some(issue_id) = app.toggle_watch_issue_id.take() {
+            if let Err(e) = app.toggle_watch(issue_id).await {
+                app.report_error("Failed to change watching", e);
+            }
+        }
+
+        // Handle offline queue replay and dropped operations
+        if app.replay_pending_flag {
+            app.replay_pending_flag = false;
+            app.replay_pending_operations().await;
+        }
+        if let Some(operation_id) = app.drop_pending_operation_id.take() {
+            app.drop_pending_operation(operation_id).await;
+        }
+
         // Handle bulk update execution
         if app.execute_bulk_update_flag {
             app.execute_bulk_update_flag = false;
             if let Err(e) = app.execute_bulk_update().await {
-                app.error_message = Some(format!("Bulk update failed: {}", e));
+                app.report_error("Bulk update failed", e);
             }
         }
 
@@ -216,7 +342,7 @@ async fn run_app(
             app.load_image_flag = false;
             if let Some(url) = &app.viewing_image_url.clone() {
                 if let Err(e) = app.download_single_image(url).await {
-                    app.error_message = Some(format!("Failed to load image: {}", e));
+                    app.report_error("Failed to load image", e);
                     app.show_image_viewer = false;
                     app.viewing_image_url = None;
                 }
@@ -232,13 +358,24 @@ async fn run_app(
             // Then fetch from API if we don't have recent data
             if app.total_issues == 0 {
                 if let Err(e) = app.load_issues().await {
-                    app.error_message = Some(format!("Failed to load issues: {}", e));
+                    app.report_error("Failed to load issues", e);
                     app.loading = false;
                 }
             }
             last_selected_project = current_project_id;
         }
 
+        // Handle navigation to an issue that isn't cached yet
+        if let Some(issue_id) = app.load_issue_id.take() {
+            if let Err(e) = app.load_issue_detail(issue_id).await {
+                app.report_error("Failed to load issue detail", e);
+            }
+            if app.current_issue.as_ref().map(|i| i.id) != Some(issue_id) {
+                // Stay on the issue we came from
+                app.issue_history.pop();
+            }
+        }
+
         // Handle issue detail loading
         if app.loading_issue && issue_to_load.is_none() {
             if let Some(issue) = &app.current_issue {
@@ -248,7 +385,7 @@ async fn run_app(
 
         if let Some(issue_id) = issue_to_load {
             if let Err(e) = app.load_issue_detail(issue_id).await {
-                app.error_message = Some(format!("Failed to load issue detail: {}", e));
+                app.report_error("Failed to load issue detail", e);
                 app.loading_issue = false;
             }
             issue_to_load = None;
@@ -271,7 +408,8 @@ async fn run_app(
                     app.handle_mouse(mouse, rect);
                 }
                 events::Event::Tick => {
-                    // Handle periodic updates if needed
+                    // Retry writes queued while offline every so often
+                    app.schedule_pending_replay();
                 }
             }
         }
@@ -1,8 +1,34 @@
-use anyhow::{Context, Result};
-use reqwest::Client;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::{Client, RequestBuilder, Response};
 use serde::de::DeserializeOwned;
 
 use super::models::*;
+use crate::error::RedmineError;
+
+type Result<T> = std::result::Result<T, RedmineError>;
+
+/// Filters for listing issues across projects
+#[derive(Debug, Clone, Default)]
+pub struct IssueQuery {
+    /// Project id or identifier
+    pub project: Option<String>,
+    /// User id or `me`
+    pub assigned_to: Option<String>,
+    /// Author's user id or `me`
+    pub author: Option<String>,
+    /// Watcher's user id or `me`
+    pub watcher: Option<String>,
+    /// `open`, `closed`, `*` or a status id
+    pub status: Option<String>,
+    /// Target version id
+    pub fixed_version: Option<u64>,
+    /// Saved query whose filters the server applies
+    pub query_id: Option<u64>,
+    /// Only these issues, when any are given
+    pub issue_ids: Vec<u64>,
+}
 
 #[derive(Clone)]
 pub struct RedmineClient {
@@ -11,9 +37,24 @@ pub struct RedmineClient {
     client: Client,
 }
 
+/// Longest a request may take from connecting to reading the whole response
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Longest to wait for the server to accept the connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl RedmineClient {
     pub fn new(base_url: String, api_key: String) -> Self {
-        let client = Client::new();
+        Self::with_timeouts(base_url, api_key, REQUEST_TIMEOUT, CONNECT_TIMEOUT)
+    }
+
+    /// Client whose requests fail with a timeout error instead of hanging on an unresponsive server
+    pub fn with_timeouts(base_url: String, api_key: String, timeout: Duration, connect_timeout: Duration) -> Self {
+        let client = Client::builder()
+            .timeout(timeout)
+            .connect_timeout(connect_timeout)
+            .build()
+            .expect("Failed to build HTTP client");
         Self {
             base_url,
             api_key,
@@ -25,83 +66,66 @@ impl RedmineClient {
         format!("{}/{}", self.base_url.trim_end_matches('/'), path)
     }
 
-    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        let url = self.build_url(path);
-        let response = self
-            .client
-            .get(&url)
-            .header("X-Redmine-API-Key", &self.api_key)
-            .header("Content-Type", "application/json")
-            .send()
-            .await
-            .context("Failed to send GET request")?;
+    /// Send a request with the API key and turn unsuccessful responses into typed errors
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let response = request.header("X-Redmine-API-Key", &self.api_key).send().await?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("API request failed with status {}: {}", status, error_text);
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
         }
 
-        // Try to parse JSON, but if it fails, show the actual response
-        let response_text = response.text().await.context("Failed to read response body")?;
-        serde_json::from_str(&response_text).map_err(|e| {
-            anyhow::anyhow!(
-                "Failed to parse JSON response from {}\nSerde error: {}\nResponse body (first 2000 chars): {}",
-                path,
+        let error_text = response.text().await.unwrap_or_default();
+        Err(RedmineError::from_response(status.as_u16(), &error_text))
+    }
+
+    /// Parse a JSON body, keeping the start of the body in the error for debugging
+    async fn parse<T: DeserializeOwned>(path: &str, response: Response) -> Result<T> {
+        let response_text = response.text().await?;
+        serde_json::from_str(&response_text).map_err(|e| RedmineError::InvalidResponse {
+            path: path.to_string(),
+            message: format!(
+                "{}\nResponse body (first 2000 chars): {}",
                 e,
                 &response_text.chars().take(2000).collect::<String>()
-            )
+            ),
         })
     }
 
-    async fn post<T: DeserializeOwned>(&self, path: &str, body: &impl serde::Serialize) -> Result<T> {
-        let url = self.build_url(path);
-        let response = self
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let request = self
             .client
-            .post(&url)
-            .header("X-Redmine-API-Key", &self.api_key)
-            .header("Content-Type", "application/json")
-            .json(body)
-            .send()
-            .await
-            .context("Failed to send POST request")?;
+            .get(self.build_url(path))
+            .header("Content-Type", "application/json");
+        let response = self.send(request).await?;
+        Self::parse(path, response).await
+    }
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("API request failed with status {}: {}", status, error_text);
-        }
+    async fn post<T: DeserializeOwned>(&self, path: &str, body: &impl serde::Serialize) -> Result<T> {
+        let request = self.client.post(self.build_url(path)).json(body);
+        let response = self.send(request).await?;
+        Self::parse(path, response).await
+    }
 
-        // Try to parse JSON, but if it fails, show the actual response
-        let response_text = response.text().await.context("Failed to read response body")?;
-        serde_json::from_str(&response_text).map_err(|e| {
-            anyhow::anyhow!(
-                "Failed to parse JSON response from POST {}\nSerde error: {}\nResponse body (first 2000 chars): {}",
-                path,
-                e,
-                &response_text.chars().take(2000).collect::<String>()
-            )
-        })
+    /// POST to an endpoint that answers without a body
+    async fn post_empty(&self, path: &str, body: &impl serde::Serialize) -> Result<()> {
+        let request = self.client.post(self.build_url(path)).json(body);
+        self.send(request).await?;
+        Ok(())
     }
 
     async fn put(&self, path: &str, body: &impl serde::Serialize) -> Result<()> {
-        let url = self.build_url(path);
-        let response = self
-            .client
-            .put(&url)
-            .header("X-Redmine-API-Key", &self.api_key)
-            .header("Content-Type", "application/json")
-            .json(body)
-            .send()
-            .await
-            .context("Failed to send PUT request")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("API request failed with status {}: {}", status, error_text);
-        }
+        let request = self.client.put(self.build_url(path)).json(body);
+        self.send(request).await?;
+        Ok(())
+    }
 
+    async fn delete(&self, path: &str) -> Result<()> {
+        let request = self
+            .client
+            .delete(self.build_url(path))
+            .header("Content-Type", "application/json");
+        self.send(request).await?;
         Ok(())
     }
 
@@ -117,6 +141,7 @@ impl RedmineClient {
         limit: u32,
         offset: u32,
         exclude_subprojects: bool,
+        updated_since: Option<DateTime<Utc>>,
     ) -> Result<IssuesResponse> {
         let mut query = format!("limit={}&offset={}", limit, offset);
 
@@ -133,14 +158,57 @@ impl RedmineClient {
             query.push_str(&format!("&status_id={}", sid));
         }
 
+        // Only issues changed since then (`updated_on=>=<timestamp>`, URL-encoded)
+        if let Some(since) = updated_since {
+            query.push_str(&format!("&updated_on=%3E%3D{}", since.format("%Y-%m-%dT%H:%M:%SZ")));
+        }
+
         // Note: attachments are not available in the issues list endpoint
         // They can only be fetched when getting a single issue
 
         self.get(&format!("issues.json?{}", query)).await
     }
 
+    /// List issues matching the given filters, most recently updated first
+    pub async fn query_issues(&self, query: &IssueQuery, limit: u32, offset: u32) -> Result<IssuesResponse> {
+        let mut params = format!("limit={}&offset={}&sort=updated_on:desc", limit, offset);
+
+        if let Some(project) = &query.project {
+            params.push_str(&format!("&project_id={}", project));
+        }
+        if let Some(assigned_to) = &query.assigned_to {
+            params.push_str(&format!("&assigned_to_id={}", assigned_to));
+        }
+        if let Some(author) = &query.author {
+            params.push_str(&format!("&author_id={}", author));
+        }
+        if let Some(watcher) = &query.watcher {
+            params.push_str(&format!("&watcher_id={}", watcher));
+        }
+        if let Some(status) = &query.status {
+            params.push_str(&format!("&status_id={}", status));
+        }
+        if let Some(fixed_version) = query.fixed_version {
+            params.push_str(&format!("&fixed_version_id={}", fixed_version));
+        }
+        if let Some(query_id) = query.query_id {
+            params.push_str(&format!("&query_id={}", query_id));
+        }
+        if !query.issue_ids.is_empty() {
+            let ids: Vec<String> = query.issue_ids.iter().map(|id| id.to_string()).collect();
+            params.push_str(&format!("&issue_id={}", ids.join(",")));
+        }
+
+        self.get(&format!("issues.json?{}", params)).await
+    }
+
+    /// Issue queries saved in the web UI that the user can see
+    pub async fn get_queries(&self, limit: u32, offset: u32) -> Result<QueriesResponse> {
+        self.get(&format!("queries.json?limit={}&offset={}", limit, offset)).await
+    }
+
     pub async fn get_issue(&self, issue_id: u64) -> Result<IssueWrapper> {
-        self.get(&format!("issues/{}.json?include=journals,attachments", issue_id))
+        self.get(&format!("issues/{}.json?include=journals,attachments,relations,children,watchers", issue_id))
             .await
     }
 
@@ -225,6 +293,11 @@ impl RedmineClient {
         .await
     }
 
+    /// Versions a project can use, including ones shared from other projects (not paginated)
+    pub async fn get_project_versions(&self, project_id: u64) -> Result<VersionsResponse> {
+        self.get(&format!("projects/{}/versions.json", project_id)).await
+    }
+
     /// Fetch a sample issue for a specific tracker to discover its custom fields
     pub async fn get_sample_issue_for_tracker(&self, tracker_id: u64) -> Result<Option<Issue>> {
         let response: IssuesResponse = self
@@ -236,20 +309,8 @@ impl RedmineClient {
 
     /// Download an attachment from Redmine
     pub async fn download_attachment(&self, url: &str) -> Result<Vec<u8>> {
-        let response = self
-            .client
-            .get(url)
-            .header("X-Redmine-API-Key", &self.api_key)
-            .send()
-            .await
-            .context("Failed to download attachment")?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            anyhow::bail!("Failed to download attachment with status {}", status);
-        }
-
-        let bytes = response.bytes().await.context("Failed to read attachment bytes")?;
+        let response = self.send(self.client.get(url)).await?;
+        let bytes = response.bytes().await?;
 
         Ok(bytes.to_vec())
     }
@@ -277,31 +338,81 @@ impl RedmineClient {
 
     /// Upload a file and get an upload token
     pub async fn upload_file(&self, filename: &str, content: Vec<u8>) -> Result<UploadResponse> {
-        let url = self.build_url("uploads.json");
-        
-        let response = self
+        let request = self
             .client
-            .post(&url)
-            .header("X-Redmine-API-Key", &self.api_key)
+            .post(self.build_url("uploads.json"))
             .header("Content-Type", "application/octet-stream")
             .query(&[("filename", filename)])
-            .body(content)
-            .send()
-            .await
-            .context("Failed to upload file")?;
+            .body(content);
+        let response = self.send(request).await?;
+        Self::parse("uploads.json", response).await
+    }
+
+    /// Get time entries logged on an issue, following pagination
+    pub async fn get_time_entries(&self, issue_id: u64) -> Result<TimeEntriesResponse> {
+        let limit = 100;
+        let mut offset = 0;
+        let mut all_entries = Vec::new();
+
+        loop {
+            let response: TimeEntriesResponse = self
+                .get(&format!(
+                    "time_entries.json?issue_id={}&limit={}&offset={}",
+                    issue_id, limit, offset
+                ))
+                .await?;
+
+            let count = response.time_entries.len();
+            all_entries.extend(response.time_entries);
+
+            if count < limit as usize {
+                break;
+            }
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("File upload failed with status {}: {}", status, error_text);
+            offset += limit;
         }
 
-        let response_text = response.text().await.context("Failed to read upload response")?;
-        serde_json::from_str(&response_text).with_context(|| {
-            format!(
-                "Failed to parse upload response\nResponse: {}",
-                &response_text.chars().take(500).collect::<String>()
-            )
+        Ok(TimeEntriesResponse {
+            time_entries: all_entries,
+            total_count: None,
+            offset: None,
+            limit: None,
         })
     }
+
+    pub async fn get_time_entry_activities(&self) -> Result<TimeEntryActivitiesResponse> {
+        self.get("enumerations/time_entry_activities.json").await
+    }
+
+    pub async fn create_time_entry(&self, time_entry: CreateTimeEntry) -> Result<TimeEntryWrapper> {
+        let wrapper = CreateTimeEntryWrapper { time_entry };
+        self.post("time_entries.json", &wrapper).await
+    }
+
+    pub async fn update_time_entry(&self, time_entry_id: u64, time_entry: UpdateTimeEntry) -> Result<()> {
+        let wrapper = UpdateTimeEntryWrapper { time_entry };
+        self.put(&format!("time_entries/{}.json", time_entry_id), &wrapper).await
+    }
+
+    pub async fn delete_time_entry(&self, time_entry_id: u64) -> Result<()> {
+        self.delete(&format!("time_entries/{}.json", time_entry_id)).await
+    }
+
+    pub async fn create_relation(&self, issue_id: u64, relation: CreateRelation) -> Result<RelationWrapper> {
+        let wrapper = CreateRelationWrapper { relation };
+        self.post(&format!("issues/{}/relations.json", issue_id), &wrapper).await
+    }
+
+    pub async fn delete_relation(&self, relation_id: u64) -> Result<()> {
+        self.delete(&format!("relations/{}.json", relation_id)).await
+    }
+
+    pub async fn add_watcher(&self, issue_id: u64, user_id: u64) -> Result<()> {
+        self.post_empty(&format!("issues/{}/watchers.json", issue_id), &AddWatcher { user_id })
+            .await
+    }
+
+    pub async fn remove_watcher(&self, issue_id: u64, user_id: u64) -> Result<()> {
+        self.delete(&format!("issues/{}/watchers/{}.json", issue_id, user_id)).await
+    }
 }
@@ -1,5 +1,5 @@
 pub mod client;
 pub mod models;
 
-pub use client::RedmineClient;
+pub use client::{IssueQuery, RedmineClient};
 pub use models::*;
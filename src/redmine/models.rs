@@ -62,6 +62,121 @@ pub struct Issue {
     pub custom_fields: Vec<IssueCustomField>,
     #[serde(default)]
     pub attachments: Vec<Attachment>,
+    /// Only returned by the issue detail endpoint
+    #[serde(default)]
+    pub relations: Vec<IssueRelation>,
+    /// Subtask tree, only returned by the issue detail endpoint
+    #[serde(default)]
+    pub children: Vec<IssueChild>,
+    /// Only returned by the issue detail endpoint
+    #[serde(default)]
+    pub watchers: Vec<IdName>,
+}
+
+impl Issue {
+    /// Relations with the label they have from this issue's side, in `RELATION_TYPES` order
+    pub fn related_issues(&self) -> Vec<(&'static str, &IssueRelation)> {
+        let mut related: Vec<(usize, &'static str, &IssueRelation)> = self
+            .relations
+            .iter()
+            .filter_map(|relation| {
+                let relation_type = relation.relation_type_from(self.id);
+                RELATION_TYPES
+                    .iter()
+                    .position(|(key, _)| *key == relation_type)
+                    .map(|order| (order, RELATION_TYPES[order].1, relation))
+            })
+            .collect();
+        related.sort_by_key(|(order, _, relation)| (*order, relation.other_issue_id(self.id)));
+        related.into_iter().map(|(_, label, relation)| (label, relation)).collect()
+    }
+}
+
+/// A subtask as listed under its parent, with its own subtasks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueChild {
+    pub id: u64,
+    pub tracker: IdName,
+    pub subject: String,
+    #[serde(default)]
+    pub children: Vec<IssueChild>,
+}
+
+/// Relation types the API accepts, with their label, in display order
+pub const RELATION_TYPES: &[(&str, &str)] = &[
+    ("blocks", "Blocks"),
+    ("blocked", "Blocked by"),
+    ("relates", "Related to"),
+    ("duplicates", "Duplicates"),
+    ("duplicated", "Duplicated by"),
+    ("precedes", "Precedes"),
+    ("follows", "Follows"),
+    ("copied_to", "Copied to"),
+    ("copied_from", "Copied from"),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueRelation {
+    pub id: u64,
+    pub issue_id: u64,
+    pub issue_to_id: u64,
+    pub relation_type: String,
+    /// Days between the issues of a precedes/follows relation
+    #[serde(default)]
+    pub delay: Option<i32>,
+}
+
+impl IssueRelation {
+    /// The issue at the other end of the relation
+    pub fn other_issue_id(&self, issue_id: u64) -> u64 {
+        if self.issue_id == issue_id {
+            self.issue_to_id
+        } else {
+            self.issue_id
+        }
+    }
+
+    /// The relation type as seen from `issue_id`: a relation "A blocks B" reads "blocked" from B
+    pub fn relation_type_from(&self, issue_id: u64) -> &str {
+        if self.issue_id == issue_id {
+            return &self.relation_type;
+        }
+        match self.relation_type.as_str() {
+            "blocks" => "blocked",
+            "blocked" => "blocks",
+            "duplicates" => "duplicated",
+            "duplicated" => "duplicates",
+            "precedes" => "follows",
+            "follows" => "precedes",
+            "copied_to" => "copied_from",
+            "copied_from" => "copied_to",
+            other => other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationWrapper {
+    pub relation: IssueRelation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRelation {
+    pub issue_to_id: u64,
+    pub relation_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRelationWrapper {
+    pub relation: CreateRelation,
+}
+
+/// Body of `POST /issues/:id/watchers.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddWatcher {
+    pub user_id: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -163,6 +278,12 @@ pub struct CreateIssue {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub done_ratio: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_version_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_issue_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watcher_user_ids: Option<Vec<u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub uploads: Option<Vec<Upload>>,
 }
 
@@ -171,7 +292,7 @@ pub struct CreateIssueWrapper {
     pub issue: CreateIssue,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateIssue {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subject: Option<String>,
@@ -193,6 +314,22 @@ pub struct UpdateIssue {
     pub due_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub estimated_hours: Option<f32>,
+    /// 0 removes the target version
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_clearable_id",
+        deserialize_with = "deserialize_clearable_id"
+    )]
+    pub fixed_version_id: Option<u64>,
+    /// 0 removes the parent
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_clearable_id",
+        deserialize_with = "deserialize_clearable_id"
+    )]
+    pub parent_issue_id: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -206,6 +343,33 @@ pub struct UpdateIssueWrapper {
     pub issue: UpdateIssue,
 }
 
+// Redmine removes the parent or target version when the id is blank; an id of 0 is rejected
+fn serialize_clearable_id<S>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value {
+        Some(0) => serializer.serialize_str(""),
+        Some(id) => serializer.serialize_u64(*id),
+        None => serializer.serialize_none(),
+    }
+}
+
+// Reads back what serialize_clearable_id wrote, for updates waiting in the offline queue
+fn deserialize_clearable_id<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde_json::Value;
+
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(None),
+        Value::String(s) if s.is_empty() => Ok(Some(0)),
+        Value::Number(n) => n.as_u64().map(Some).ok_or_else(|| serde::de::Error::custom("invalid issue id")),
+        other => Err(serde::de::Error::custom(format!("invalid issue or version id: {}", other))),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: u64,
@@ -230,6 +394,8 @@ pub struct TrackersResponse {
 pub struct IssueStatus {
     pub id: u64,
     pub name: String,
+    #[serde(default)]
+    pub is_closed: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -237,10 +403,32 @@ pub struct IssueStatusesResponse {
     pub issue_statuses: Vec<IssueStatus>,
 }
 
+/// Issue query saved in the Redmine web UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedQuery {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub is_public: bool,
+    /// Project the query belongs to; `None` for queries available in every project
+    #[serde(default)]
+    pub project_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueriesResponse {
+    pub queries: Vec<SavedQuery>,
+    pub total_count: Option<u32>,
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Priority {
     pub id: u64,
     pub name: String,
+    #[serde(default)]
+    pub is_default: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -283,6 +471,34 @@ pub struct IssueCategory {
     pub name: String,
 }
 
+/// A project version (milestone), possibly shared from another project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Version {
+    pub id: u64,
+    pub project: IdName,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// open, locked or closed; issues can only be assigned to open versions
+    pub status: String,
+    #[serde(default)]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub sharing: Option<String>,
+}
+
+impl Version {
+    pub fn is_open(&self) -> bool {
+        self.status == "open"
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionsResponse {
+    pub versions: Vec<Version>,
+    pub total_count: Option<u32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueCustomField {
     pub id: u64,
@@ -351,3 +567,79 @@ pub struct ProjectDetail {
 pub struct ProjectDetailWrapper {
     pub project: ProjectDetail,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub id: u64,
+    pub project: IdName,
+    #[serde(default)]
+    pub issue: Option<IdName>,
+    pub user: IdName,
+    pub activity: IdName,
+    pub hours: f32,
+    #[serde(default)]
+    pub comments: Option<String>,
+    pub spent_on: String, // YYYY-MM-DD
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntriesResponse {
+    pub time_entries: Vec<TimeEntry>,
+    pub total_count: Option<u32>,
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntryWrapper {
+    pub time_entry: TimeEntry,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntryActivity {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntryActivitiesResponse {
+    pub time_entry_activities: Vec<TimeEntryActivity>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTimeEntry {
+    pub issue_id: u64,
+    pub hours: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spent_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTimeEntryWrapper {
+    pub time_entry: CreateTimeEntry,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTimeEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spent_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTimeEntryWrapper {
+    pub time_entry: UpdateTimeEntry,
+}
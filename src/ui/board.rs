@@ -0,0 +1,124 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use super::main_screen::issue_number;
+use super::widgets::get_loading_spinner;
+use crate::app::{App, BoardColumn};
+use crate::redmine::Issue;
+
+/// Narrowest a column gets before the board scrolls sideways instead
+const MIN_COLUMN_WIDTH: u16 = 28;
+
+/// Kanban board: one column per status, scrolled so the focused column is visible
+pub fn draw_board(f: &mut Frame, app: &App, area: Rect) {
+    let columns = app.board_columns();
+    if columns.is_empty() {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.border))
+            .title(" Board ");
+        let message = ListItem::new("  No statuses known yet (press I to sync)");
+        let empty = List::new(vec![message.style(Style::default().fg(app.theme.text_muted))]).block(block);
+        f.render_widget(empty, area);
+        return;
+    }
+
+    let focused = app.board_column_index(columns.len());
+    let visible = ((area.width / MIN_COLUMN_WIDTH).max(1) as usize).min(columns.len());
+    let first = (focused + 1).saturating_sub(visible);
+    let cells = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, visible as u32); visible])
+        .split(area);
+
+    for (offset, cell) in cells.iter().enumerate() {
+        let index = first + offset;
+        let more_left = offset == 0 && first > 0;
+        let more_right = offset + 1 == visible && index + 1 < columns.len();
+        draw_column(f, app, &columns[index], index == focused, (more_left, more_right), *cell);
+    }
+}
+
+fn draw_column(f: &mut Frame, app: &App, column: &BoardColumn, is_focused: bool, more: (bool, bool), area: Rect) {
+    let cursor = app.board_card_index(column);
+
+    let items: Vec<ListItem> = if column.issues.is_empty() {
+        vec![ListItem::new("  No issues").style(Style::default().fg(app.theme.text_muted))]
+    } else {
+        column
+            .issues
+            .iter()
+            .enumerate()
+            .map(|(index, issue)| card(app, issue, index == cursor, is_focused))
+            .collect()
+    };
+
+    let loader = if app.loading && is_focused {
+        format!(" {}", get_loading_spinner())
+    } else {
+        String::new()
+    };
+    let title = format!(
+        " {}{} ({}){}{} ",
+        if more.0 { "◀ " } else { "" },
+        column.status.name,
+        column.issues.len(),
+        loader,
+        if more.1 { " ▶" } else { "" }
+    );
+    let border_style = if is_focused {
+        Style::default().fg(app.theme.border_focused)
+    } else {
+        Style::default().fg(app.theme.border)
+    };
+
+    let title = Span::styled(title, Style::default().fg(app.theme.get_status_color(&column.status.name)));
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(title),
+    );
+    let mut list_state = ListState::default();
+    if !column.issues.is_empty() {
+        list_state.select(Some(cursor));
+    }
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+/// A card: number and priority, subject, assignee, with a bar in the priority's color
+fn card<'a>(app: &App, issue: &Issue, is_at_cursor: bool, is_focused: bool) -> ListItem<'a> {
+    let priority_color = app.theme.get_priority_color(&issue.priority.name);
+    let bar = || Span::styled("▌ ", Style::default().fg(priority_color));
+    let subject_style = match (is_at_cursor, is_focused) {
+        (true, true) => Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD),
+        (true, false) => Style::default()
+            .fg(app.theme.text_secondary)
+            .add_modifier(Modifier::BOLD),
+        _ => Style::default().fg(app.theme.text),
+    };
+    let assignee = match &issue.assigned_to {
+        Some(user) => Span::styled(format!("@ {}", user.name), Style::default().fg(app.theme.accent)),
+        None => Span::styled("Unassigned", Style::default().fg(app.theme.text_muted)),
+    };
+
+    ListItem::new(vec![
+        Line::from(vec![
+            bar(),
+            Span::styled(issue_number(app, issue), Style::default().fg(app.theme.text_muted)),
+            Span::styled(
+                issue.priority.name.clone(),
+                Style::default().fg(priority_color).add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![bar(), Span::styled(issue.subject.clone(), subject_style)]),
+        Line::from(vec![bar(), assignee]),
+        Line::from(""),
+    ])
+}
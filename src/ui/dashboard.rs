@@ -0,0 +1,118 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use super::main_screen::issue_number;
+use super::widgets::get_loading_spinner;
+use crate::app::{App, DashboardSection};
+
+/// "My Work" dashboard: one list per section in a 2x2 grid
+pub fn draw_dashboard(f: &mut Frame, app: &App, area: Rect) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    for (row, sections) in rows.iter().zip(DashboardSection::ALL.chunks(2)) {
+        let cells = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(*row);
+        for (cell, section) in cells.iter().zip(sections) {
+            draw_section(f, app, *section, *cell);
+        }
+    }
+}
+
+fn draw_section(f: &mut Frame, app: &App, section: DashboardSection, area: Rect) {
+    let i = section.index();
+    let is_focused = app.dashboard_section == section;
+    let issues = &app.dashboard_issues[i];
+    let today = chrono::Local::now().date_naive().format("%Y-%m-%d").to_string();
+
+    let items: Vec<ListItem> = if issues.is_empty() {
+        let message = if app.current_user_id.is_none() {
+            "  Waiting for the server to identify you..."
+        } else {
+            "  Nothing here"
+        };
+        vec![ListItem::new(message).style(Style::default().fg(app.theme.text_muted))]
+    } else {
+        issues
+            .iter()
+            .enumerate()
+            .map(|(index, issue)| {
+                let is_at_cursor = index == app.dashboard_list_states[i];
+                let line_style = match (is_at_cursor, is_focused) {
+                    (true, true) => Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD),
+                    (true, false) => Style::default()
+                        .fg(app.theme.text_secondary)
+                        .add_modifier(Modifier::BOLD),
+                    _ => Style::default().fg(app.theme.text),
+                };
+
+                let mut spans = vec![
+                    Span::styled(issue_number(app, issue), Style::default().fg(app.theme.text_muted)),
+                    Span::styled(
+                        format!("{} ", issue.priority.name.chars().next().unwrap_or('N')),
+                        Style::default()
+                            .fg(app.theme.get_priority_color(&issue.priority.name))
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("[{}] ", issue.status.name),
+                        Style::default().fg(app.theme.get_status_color(&issue.status.name)),
+                    ),
+                    Span::styled(
+                        format!("{} ", issue.project.name),
+                        Style::default().fg(app.theme.accent),
+                    ),
+                    Span::styled(issue.subject.clone(), line_style),
+                ];
+                if let Some(due) = &issue.due_date {
+                    let color = if due.as_str() < today.as_str() {
+                        app.theme.error
+                    } else {
+                        app.theme.text_muted
+                    };
+                    spans.push(Span::styled(format!("  due {}", due), Style::default().fg(color)));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    };
+
+    let loader = if app.loading && is_focused {
+        format!(" {} Loading...", get_loading_spinner())
+    } else {
+        String::new()
+    };
+    let title = format!(
+        " {} {} ({}){} [Sort: {}] ",
+        i + 1,
+        section.title(),
+        issues.len(),
+        loader,
+        app.dashboard_sort_order(section).as_str()
+    );
+    let border_style = if is_focused {
+        Style::default().fg(app.theme.border_focused)
+    } else {
+        Style::default().fg(app.theme.border)
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(title),
+    );
+    let mut list_state = ListState::default();
+    if !issues.is_empty() {
+        list_state.select(Some(app.dashboard_list_states[i]));
+    }
+    f.render_stateful_widget(list, area, &mut list_state);
+}
@@ -52,7 +52,7 @@ pub fn draw_reply_form(f: &mut Frame, app: &App, main_area: Rect) {
             };
             constraints.push(Constraint::Length(height));
         }
-        constraints.push(Constraint::Min(2)); // Help text
+        constraints.push(Constraint::Min(2 + form.form_errors.len() as u16)); // Errors and help text
 
         let content_area = Rect {
             x: inner_area.x + 1,
@@ -91,7 +91,8 @@ pub fn draw_reply_form(f: &mut Frame, app: &App, main_area: Rect) {
         // Show attachments info and help
         let attachments_count = app.pending_attachments.len();
         
-        let help_lines = vec![
+        let mut help_lines = form_error_lines(app, form);
+        help_lines.extend([
             Line::from(vec![
                 Span::styled("Tab", Style::default().fg(app.theme.warning)),
                 Span::raw(": Next | "),
@@ -118,7 +119,7 @@ pub fn draw_reply_form(f: &mut Frame, app: &App, main_area: Rect) {
                 }
                 spans
             }).alignment(Alignment::Center),
-        ];
+        ]);
         
         let help = Paragraph::new(help_lines)
             .style(Style::default().fg(app.theme.text_muted));
@@ -184,7 +185,7 @@ pub fn draw_create_issue_form(f: &mut Frame, app: &App, main_area: Rect) {
             row_heights.push(height);
             constraints.push(Constraint::Length(height));
         }
-        constraints.push(Constraint::Min(2)); // Help text
+        constraints.push(Constraint::Min(2 + form.form_errors.len() as u16)); // Errors and help text
 
         let content_area = Rect {
             x: inner_area.x + 1,
@@ -223,7 +224,8 @@ pub fn draw_create_issue_form(f: &mut Frame, app: &App, main_area: Rect) {
         // Show attachments info and help
         let attachments_count = app.pending_attachments.len();
         
-        let help_lines = vec![
+        let mut help_lines = form_error_lines(app, form);
+        help_lines.extend([
             Line::from(vec![
                 Span::styled("Tab", Style::default().fg(app.theme.warning)),
                 Span::raw(": Next | "),
@@ -250,7 +252,7 @@ pub fn draw_create_issue_form(f: &mut Frame, app: &App, main_area: Rect) {
                 }
                 spans
             }).alignment(Alignment::Center),
-        ];
+        ]);
         
         let help = Paragraph::new(help_lines)
             .style(Style::default().fg(app.theme.text_muted));
@@ -271,6 +273,84 @@ pub fn draw_create_issue_form(f: &mut Frame, app: &App, main_area: Rect) {
     f.render_widget(block, area);
 }
 
+pub fn draw_time_entry_form(f: &mut Frame, app: &App, main_area: Rect) {
+    let area = centered_rect(60, 60, main_area);
+    f.render_widget(Clear, area);
+
+    if let Some(form) = &app.time_entry_form {
+        draw_compact_form(f, app, form, area);
+    }
+
+    let title = match (app.editing_time_entry_id, app.time_entry_issue_id) {
+        (Some(_), Some(issue_id)) => format!(" Edit Time Entry on #{} ", issue_id),
+        (None, Some(issue_id)) => format!(" Log Time on #{} ", issue_id),
+        _ => " Log Time ".to_string(),
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(app.theme.success))
+        .title(title);
+    f.render_widget(block, area);
+}
+
+pub fn draw_relation_form(f: &mut Frame, app: &App, main_area: Rect) {
+    let area = centered_rect(60, 50, main_area);
+    f.render_widget(Clear, area);
+
+    if let Some(form) = &app.relation_form {
+        draw_compact_form(f, app, form, area);
+    }
+
+    let title = match app.relation_issue_id {
+        Some(issue_id) => format!(" Relate #{} to ", issue_id),
+        None => " Add Relation ".to_string(),
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(app.theme.success))
+        .title(title);
+    f.render_widget(block, area);
+}
+
+/// Fields one per row with the errors and key hints below, for the small popup forms
+fn draw_compact_form(f: &mut Frame, app: &App, form: &crate::issue_form::IssueForm, area: Rect) {
+    let content_area = Rect {
+        x: area.x + 2,
+        y: area.y + 2,
+        width: area.width.saturating_sub(4),
+        height: area.height.saturating_sub(4),
+    };
+
+    let mut constraints: Vec<Constraint> = form.fields.iter().map(|_| Constraint::Length(3)).collect();
+    constraints.push(Constraint::Min(1 + form.form_errors.len() as u16)); // Errors and help text
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(content_area);
+
+    for (i, field) in form.fields.iter().enumerate() {
+        render_form_field(app, f, field, form, i, chunks[i]);
+    }
+
+    let mut help_lines = form_error_lines(app, form);
+    help_lines.push(Line::from(vec![
+        Span::styled("Tab", Style::default().fg(app.theme.warning)),
+        Span::raw(": Next | "),
+        Span::styled("Shift+Tab", Style::default().fg(app.theme.warning)),
+        Span::raw(": Prev | "),
+        Span::styled("Ctrl+S", Style::default().fg(app.theme.success)),
+        Span::raw(": Save | "),
+        Span::styled("ESC", Style::default().fg(app.theme.error)),
+        Span::raw(": Cancel"),
+    ]));
+    let help = Paragraph::new(help_lines)
+        .style(Style::default().fg(app.theme.text_muted))
+        .alignment(Alignment::Center);
+    f.render_widget(help, chunks[form.fields.len()]);
+}
+
 fn render_form_field(
     app: &App,
     f: &mut Frame,
@@ -335,12 +415,14 @@ fn render_form_field(
             )
         };
 
-        let list = List::new(items).block(
+        let list = List::new(items).block(with_field_error(
+            app,
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(app.theme.warning))
                 .title(title),
-        );
+            form.field_error(&field.key),
+        ));
 
         // Calculate selected index for highlighting
         if let Some(current_id) = current_id {
@@ -356,6 +438,60 @@ fn render_form_field(
         return;
     }
 
+    // Render MultiSelect as a checklist when focused, with the search in the title
+    if is_focused && field.field_type == FieldType::MultiSelect {
+        let selected_ids = value.map(|v| v.as_option_ids()).unwrap_or_default();
+        let cursor = form.option_cursor(&field.key);
+        let filtered_options = form.get_filtered_options(&field.key);
+
+        let items: Vec<ListItem> = filtered_options
+            .iter()
+            .map(|opt| {
+                let is_highlighted = cursor == Some(opt.id);
+                let check = if selected_ids.contains(&opt.id) { "[x]" } else { "[ ]" };
+                let content = format!("{} {} {}", if is_highlighted { "►" } else { " " }, check, opt.name);
+                let item_style = if is_highlighted {
+                    Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(content).style(item_style)
+            })
+            .collect();
+
+        let required_marker = if field.required { " *" } else { "" };
+        let title = if form.is_search_mode(&field.key) {
+            format!(
+                "{}{} ({}█ {} matches, Space to check)",
+                field.label,
+                required_marker,
+                form.get_search_text(&field.key),
+                filtered_options.len()
+            )
+        } else {
+            format!(
+                "{}{} ({} selected, j/k to navigate, Space to check, / to search)",
+                field.label,
+                required_marker,
+                selected_ids.len()
+            )
+        };
+
+        let list = List::new(items).block(with_field_error(
+            app,
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.warning))
+                .title(title),
+            form.field_error(&field.key),
+        ));
+
+        let mut list_state = ListState::default();
+        list_state.select(cursor.and_then(|id| filtered_options.iter().position(|opt| opt.id == id)));
+        f.render_stateful_widget(list, area, &mut list_state);
+        return;
+    }
+
     // Render Dropdown and SearchableDropdown as list when focused
     if is_focused && (field.field_type == FieldType::Dropdown || field.field_type == FieldType::SearchableDropdown) {
         let current_id = value.and_then(|v| v.as_option_id());
@@ -389,12 +525,14 @@ fn render_form_field(
         };
         let title = format!("{}{}{}", field.label, required_marker, hint);
 
-        let list = List::new(items).block(
+        let list = List::new(items).block(with_field_error(
+            app,
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(app.theme.warning))
                 .title(title),
-        );
+            form.field_error(&field.key),
+        ));
 
         // Calculate selected index for highlighting
         if let Some(current_id) = current_id {
@@ -423,6 +561,19 @@ fn render_form_field(
                 .unwrap_or_else(|| format!("ID: {}", id))
         }
         Some(crate::form_field::FieldValue::OptionId(None)) => "(None)".to_string(),
+        Some(crate::form_field::FieldValue::OptionIds(ids)) if ids.is_empty() => "(None)".to_string(),
+        Some(crate::form_field::FieldValue::OptionIds(ids)) => ids
+            .iter()
+            .map(|id| {
+                field
+                    .options
+                    .iter()
+                    .find(|opt| opt.id == *id)
+                    .map(|opt| opt.name.clone())
+                    .unwrap_or_else(|| format!("ID: {}", id))
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
         Some(crate::form_field::FieldValue::Number(Some(n))) => format!("{}%", n),
         Some(crate::form_field::FieldValue::Number(None)) => "0%".to_string(),
         Some(crate::form_field::FieldValue::Float(Some(f))) => format!("{}", f),
@@ -438,20 +589,46 @@ fn render_form_field(
     };
     
     // Add help text for date fields when focused
-    let title_with_help = if is_focused && field.help_text.is_some() {
-        format!("{} ({})", title, field.help_text.as_ref().unwrap())
-    } else {
-        title
+    let title_with_help = match &field.help_text {
+        Some(help) if is_focused => format!("{} ({})", title, help),
+        _ => title,
     };
 
+    let error = form.field_error(&field.key);
+    let mut block = Block::default().borders(Borders::ALL).title(title_with_help);
+    if error.is_some() && !is_focused {
+        block = block.border_style(Style::default().fg(app.theme.error));
+    }
+
     let widget = Paragraph::new(display_text)
         .style(style)
-        .block(Block::default().borders(Borders::ALL).title(title_with_help))
+        .block(with_field_error(app, block, error))
         .wrap(Wrap { trim: false });
 
     f.render_widget(widget, area);
 }
 
+/// Show the server's validation message for a field on the bottom border of its box
+fn with_field_error<'a>(app: &App, block: Block<'a>, error: Option<&str>) -> Block<'a> {
+    match error {
+        Some(message) => block.title_bottom(Line::styled(
+            format!(" ✗ {} ", message),
+            Style::default().fg(app.theme.error).add_modifier(Modifier::BOLD),
+        )),
+        None => block,
+    }
+}
+
+/// Validation messages from the server that don't belong to a single field
+fn form_error_lines(app: &App, form: &crate::issue_form::IssueForm) -> Vec<Line<'static>> {
+    form.form_errors
+        .iter()
+        .map(|message| {
+            Line::styled(format!("✗ {}", message), Style::default().fg(app.theme.error)).alignment(Alignment::Center)
+        })
+        .collect()
+}
+
 pub fn draw_bulk_edit_form(f: &mut Frame, app: &App, main_area: Rect) {
     use crate::form_field::FieldType;
 
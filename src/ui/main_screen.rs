@@ -7,12 +7,13 @@ use ratatui::{
 };
 
 use super::widgets::get_loading_spinner;
-use crate::app::{App, Pane};
+use crate::app::{is_local_id, App, Pane};
+use crate::redmine::Issue;
 
 pub fn draw_main_screen(f: &mut Frame, app: &mut App, area: Rect) {
-    if app.selected_project.is_none() {
+    if !app.shows_issue_list() {
         // No project selected - show only projects pane
-        draw_projects_pane(f, app, area);
+        draw_projects_column(f, app, area);
     } else {
         // Project selected - split into two panes (or maximize issues pane)
         if app.issues_pane_maximized {
@@ -25,12 +26,27 @@ pub fn draw_main_screen(f: &mut Frame, app: &mut App, area: Rect) {
                 .constraints([Constraint::Percentage(30), Constraint::Percentage(70)].as_ref())
                 .split(area);
 
-            draw_projects_pane(f, app, panes[0]);
+            draw_projects_column(f, app, panes[0]);
             draw_issues_pane(f, app, panes[1]);
         }
     }
 }
 
+/// Projects, with the saved queries that apply to the selected one below them
+fn draw_projects_column(f: &mut Frame, app: &mut App, area: Rect) {
+    let queries_height = app.saved_queries_pane_height(area.height);
+    if queries_height == 0 {
+        draw_projects_pane(f, app, area);
+        return;
+    }
+    let sections = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(queries_height)])
+        .split(area);
+    draw_projects_pane(f, app, sections[0]);
+    draw_saved_queries_pane(f, app, sections[1]);
+}
+
 fn draw_projects_pane(f: &mut Frame, app: &mut App, area: Rect) {
     let is_focused = app.focused_pane == Pane::Projects;
 
@@ -195,6 +211,69 @@ fn draw_projects_pane(f: &mut Frame, app: &mut App, area: Rect) {
     );
 }
 
+fn draw_saved_queries_pane(f: &mut Frame, app: &App, area: Rect) {
+    let is_focused = app.focused_pane == Pane::Queries;
+
+    let queries = app.visible_saved_queries();
+    let items: Vec<ListItem> = if queries.is_empty() {
+        let message = if app.fetch_saved_queries_flag {
+            "  Loading saved queries..."
+        } else {
+            "  No saved queries for this project"
+        };
+        vec![ListItem::new(message).style(Style::default().fg(app.theme.text_muted))]
+    } else {
+        queries
+            .iter()
+            .enumerate()
+            .map(|(i, query)| {
+                let style = if i == app.saved_queries_list_state && is_focused {
+                    Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)
+                } else if is_focused {
+                    Style::default().fg(app.theme.text)
+                } else {
+                    Style::default().fg(app.theme.text_muted)
+                };
+                let active = app.active_saved_query.as_ref().is_some_and(|q| q.id == query.id);
+
+                let mut spans = vec![
+                    Span::styled(if active { "▶ " } else { "  " }, Style::default().fg(app.theme.success)),
+                    Span::styled(query.name.clone(), style),
+                ];
+                if query.project_id.is_none() {
+                    spans.push(Span::styled(
+                        " (all projects)",
+                        Style::default().fg(app.theme.text_muted),
+                    ));
+                }
+                if !query.is_public {
+                    spans.push(Span::styled(" (private)", Style::default().fg(app.theme.text_muted)));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    };
+
+    let border_style = if is_focused {
+        Style::default().fg(app.theme.border_focused)
+    } else {
+        Style::default().fg(app.theme.border)
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(format!("Saved Queries ({})", queries.len())),
+    );
+
+    let mut list_state = ListState::default();
+    if !queries.is_empty() {
+        list_state.select(Some(app.saved_queries_list_state));
+    }
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
 fn draw_issues_pane(f: &mut Frame, app: &mut App, area: Rect) {
     let is_focused = app.focused_pane == Pane::Issues;
 
@@ -299,9 +378,6 @@ fn draw_issues_pane(f: &mut Frame, app: &mut App, area: Rect) {
                         })
                         .unwrap_or_else(|| "(none) ".to_string());
 
-                    // Check if issue is updated since last sync
-                    let is_updated = app.is_issue_updated_since_last_sync(issue);
-                    let update_marker = if is_updated { "*" } else { "" };
 
                     // Checkbox for bulk operations
                     let mut content = vec![Span::raw("  ")]; // Indent
@@ -334,7 +410,7 @@ fn draw_issues_pane(f: &mut Frame, app: &mut App, area: Rect) {
 
                     content.extend(vec![
                         Span::styled(
-                            format!("#{:<6}{} ", issue.id, update_marker),
+                            issue_number(app, issue),
                             issue_num_style,
                         ),
                         Span::styled(
@@ -380,9 +456,6 @@ fn draw_issues_pane(f: &mut Frame, app: &mut App, area: Rect) {
                     })
                     .unwrap_or_else(|| "(none) ".to_string());
 
-                // Check if issue is updated since last sync
-                let is_updated = app.is_issue_updated_since_last_sync(issue);
-                let update_marker = if is_updated { "*" } else { "" };
 
                 // Checkbox for bulk operations
                 let mut content = vec![];
@@ -399,6 +472,14 @@ fn draw_issues_pane(f: &mut Frame, app: &mut App, area: Rect) {
                     ));
                 }
 
+                // Tree view: subtasks indented under their parent
+                if let Some(depth) = app.issue_depths.get(&issue.id).filter(|d| **d > 0) {
+                    content.push(Span::styled(
+                        format!("{}└ ", "  ".repeat(depth - 1)),
+                        Style::default().fg(app.theme.text_muted),
+                    ));
+                }
+
                 // Determine if this issue is at cursor
                 let is_at_cursor = i == app.issues_list_state;
                 
@@ -415,7 +496,7 @@ fn draw_issues_pane(f: &mut Frame, app: &mut App, area: Rect) {
 
                 content.extend(vec![
                     Span::styled(
-                        format!("#{:<6}{} ", issue.id, update_marker),
+                        issue_number(app, issue),
                         issue_num_style,
                     ),
                     Span::styled(format!("[{}] ", issue.status.name), Style::default().fg(status_color)),
@@ -446,28 +527,36 @@ fn draw_issues_pane(f: &mut Frame, app: &mut App, area: Rect) {
         String::new()
     };
 
-    let title = if let Some(project) = &app.selected_project {
+    let scope_name = match (&app.active_saved_query, &app.selected_project) {
+        (Some(query), _) => Some(format!("Query '{}'", query.name)),
+        (None, Some(project)) => Some(project.name.clone()),
+        (None, None) => None,
+    };
+    let title = if let Some(scope_name) = scope_name {
         let sort_indicator = format!(" [Sort: {}]", app.issue_sort_order.as_str());
         let my_issues_indicator = if app.my_issues_filter { " [My Issues]" } else { "" };
+        let tree_indicator = if app.issue_tree_view && !app.group_issues_by_status { " [Tree]" } else { "" };
         if !app.issue_filter.is_empty() {
             format!(
-                "{}: Issues ({}/{}){}{}{} [Filter: {}]",
-                project.name,
+                "{}: Issues ({}/{}){}{}{}{} [Filter: {}]",
+                scope_name,
                 app.filtered_issues.len(),
                 app.total_issues,
                 loader,
                 sort_indicator,
                 my_issues_indicator,
+                tree_indicator,
                 app.issue_filter
             )
         } else {
             format!(
-                "{}: Issues ({}){}{}{}",
-                project.name,
+                "{}: Issues ({}){}{}{}{}",
+                scope_name,
                 app.filtered_issues.len(),
                 loader,
                 sort_indicator,
-                my_issues_indicator
+                my_issues_indicator,
+                tree_indicator
             )
         }
     } else {
@@ -510,3 +599,20 @@ fn draw_issues_pane(f: &mut Frame, app: &mut App, area: Rect) {
         &mut scrollbar_state,
     );
 }
+
+/// Issue number column: `*` marks issues updated since the last sync, `⇅` issues with queued
+/// offline changes, and issues created offline show as `new` until the server assigns an id
+pub(super) fn issue_number(app: &App, issue: &Issue) -> String {
+    let marker = if app.pending_issue_ids.contains(&issue.id) {
+        "⇅"
+    } else if app.is_issue_updated_since_last_sync(issue) {
+        "*"
+    } else {
+        ""
+    };
+    if is_local_id(issue.id) {
+        format!("{:<7}{} ", "new", marker)
+    } else {
+        format!("#{:<6}{} ", issue.id, marker)
+    }
+}
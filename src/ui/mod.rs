@@ -1,6 +1,9 @@
+mod board;
+mod dashboard;
 mod forms;
 mod main_screen;
 mod popups;
+mod roadmap;
 mod status_bar;
 mod widgets;
 
@@ -11,11 +14,17 @@ use ratatui::{
 
 use crate::app::{App, InputMode, Screen};
 
-pub use forms::{draw_bulk_edit_form, draw_create_issue_form, draw_reply_form};
+pub use board::draw_board;
+pub use dashboard::draw_dashboard;
+pub use forms::{
+    draw_bulk_edit_form, draw_create_issue_form, draw_relation_form, draw_reply_form, draw_time_entry_form,
+};
 pub use main_screen::draw_main_screen;
 pub use popups::{
-    draw_attachment_manager, draw_config, draw_error_popup, draw_file_explorer, draw_help, draw_image_viewer, draw_issue_popup,
+    draw_attachment_manager, draw_config, draw_conflict, draw_error_popup, draw_file_explorer, draw_filter_presets, draw_global_search, draw_help, draw_image_viewer,
+    draw_issue_popup, draw_pending_operations, draw_time_entries,
 };
+pub use roadmap::draw_roadmap;
 pub use status_bar::draw_status_bar;
 
 /// Main draw function - routes to appropriate screen
@@ -26,8 +35,13 @@ pub fn draw(f: &mut Frame, app: &mut App) {
         .split(f.area());
 
     match app.screen {
-        Screen::Main => {
-            draw_main_screen(f, app, chunks[0]);
+        Screen::Main | Screen::Dashboard | Screen::Roadmap | Screen::Board => {
+            match app.screen {
+                Screen::Dashboard => draw_dashboard(f, app, chunks[0]),
+                Screen::Roadmap => draw_roadmap(f, app, chunks[0]),
+                Screen::Board => draw_board(f, app, chunks[0]),
+                _ => draw_main_screen(f, app, chunks[0]),
+            }
             // Draw popups on top if needed
             if app.show_issue_popup {
                 draw_issue_popup(f, app, chunks[0]);
@@ -38,6 +52,28 @@ pub fn draw(f: &mut Frame, app: &mut App) {
             if app.input_mode == InputMode::ReplyingToIssue {
                 draw_reply_form(f, app, chunks[0]);
             }
+            if app.input_mode == InputMode::ResolvingConflict {
+                draw_conflict(f, app, chunks[0]);
+            }
+            // Draw time entries list and the log time form on top of it
+            if app.show_time_entries_popup {
+                draw_time_entries(f, app, chunks[0]);
+            }
+            if app.input_mode == InputMode::LoggingTime {
+                draw_time_entry_form(f, app, chunks[0]);
+            }
+            if app.input_mode == InputMode::AddingRelation {
+                draw_relation_form(f, app, chunks[0]);
+            }
+            if app.input_mode == InputMode::ManagingPendingOperations {
+                draw_pending_operations(f, app, chunks[0]);
+            }
+            if app.input_mode == InputMode::GlobalSearch {
+                draw_global_search(f, app, chunks[0]);
+            }
+            if app.input_mode == InputMode::ManagingFilterPresets {
+                draw_filter_presets(f, app, chunks[0]);
+            }
             // Draw bulk edit form
             if app.bulk_edit_form.is_some() {
                 draw_bulk_edit_form(f, app, chunks[0]);
@@ -1,7 +1,6 @@
 use ratatui::{
     layout::Rect,
     style::{Modifier, Style},
-    text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, ListState},
     Frame,
 };
@@ -0,0 +1,93 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{field_label, App};
+use crate::ui::widgets::centered_rect;
+
+pub fn draw_conflict(f: &mut Frame, app: &App, main_area: Rect) {
+    let Some(conflict) = &app.issue_conflict else {
+        return;
+    };
+
+    let area = centered_rect(75, 60, main_area);
+    f.render_widget(Clear, area);
+
+    let muted = Style::default().fg(app.theme.text_muted);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "Changed on the server since you opened the form:",
+            Style::default().fg(app.theme.text),
+        )),
+        Line::from(""),
+    ];
+
+    for change in &conflict.changes {
+        let (marker, marker_style) = if change.is_conflict() {
+            ("✗ ", Style::default().fg(app.theme.error).add_modifier(Modifier::BOLD))
+        } else {
+            ("• ", Style::default().fg(app.theme.info))
+        };
+
+        let mut spans = vec![
+            Span::styled(format!("  {}", marker), marker_style),
+            Span::styled(
+                format!("{:<16}", field_label(&change.field)),
+                Style::default().fg(app.theme.text).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(app.conflict_value_label(&change.field, change.before.as_deref()), muted),
+            Span::raw(" → "),
+            Span::styled(
+                app.conflict_value_label(&change.field, change.theirs.as_deref()),
+                Style::default().fg(app.theme.warning),
+            ),
+            Span::styled(format!(" ({})", change.changed_by), muted),
+        ];
+        if change.edited_by_us() {
+            spans.push(Span::raw("   yours: "));
+            spans.push(Span::styled(
+                app.conflict_value_label(&change.field, change.ours.as_deref()),
+                Style::default().fg(app.theme.success),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    if !conflict.new_notes.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("New notes:", Style::default().fg(app.theme.text))));
+        for (author, note) in &conflict.new_notes {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}: ", author), Style::default().fg(app.theme.accent)),
+                Span::styled(note.lines().next().unwrap_or_default().to_string(), muted),
+            ]));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  m ", Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)),
+        Span::raw("Merge: keep their changes to fields you didn't edit, send yours"),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  o ", Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)),
+        Span::raw("Overwrite: send every field of your form as it is"),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  a ", Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)),
+        Span::raw("Abort: back to the form without sending"),
+    ]));
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.error))
+            .title(format!(" Conflict on #{} ", conflict.issue_id))
+            .title_bottom(" m: Merge | o: Overwrite | a/ESC: Abort "),
+    );
+    f.render_widget(paragraph, area);
+}
@@ -1,6 +1,6 @@
 use ratatui::{
     layout::Rect,
-    style::Style,
+    style::{Modifier, Style},
     text::Line,
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
@@ -17,14 +17,42 @@ pub fn draw_error_popup(f: &mut Frame, app: &App, main_area: Rect) {
         f.render_widget(Clear, area);
 
         // Split error message into lines for display
-        let error_lines: Vec<Line> = error.lines().map(|line| Line::from(line.to_string())).collect();
+        let mut error_lines: Vec<Line> = error.lines().map(|line| Line::from(line.to_string())).collect();
+
+        // Technical detail stays collapsed under the user-facing message
+        let detail = app.current_error_detail();
+        if let Some(detail) = detail {
+            error_lines.push(Line::from(""));
+            if app.show_error_detail {
+                error_lines.push(Line::styled(
+                    "▾ Technical details",
+                    Style::default().fg(app.theme.text_secondary).add_modifier(Modifier::BOLD),
+                ));
+                error_lines.extend(
+                    detail
+                        .lines()
+                        .map(|line| Line::styled(format!("  {}", line), Style::default().fg(app.theme.text_muted))),
+                );
+            } else {
+                error_lines.push(Line::styled(
+                    "▸ Technical details",
+                    Style::default().fg(app.theme.text_secondary),
+                ));
+            }
+        }
+
+        let title = match (detail, app.show_error_detail) {
+            (Some(_), false) => " Error (d: Show details | ESC: Close) ",
+            (Some(_), true) => " Error (d: Hide details | ESC: Close) ",
+            (None, _) => " Error Details (ESC to close) ",
+        };
 
         let paragraph = Paragraph::new(error_lines)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(app.theme.error))
-                    .title(" Error Details (ESC to close) "),
+                    .title(title),
             )
             .wrap(Wrap { trim: false });
 
@@ -0,0 +1,125 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::app::{App, FilterPreset, IssueSortOrder};
+use crate::ui::widgets::centered_rect;
+
+/// One-line summary of what a preset shows
+fn describe_preset(app: &App, preset: &FilterPreset) -> String {
+    let mut parts = Vec::new();
+    match preset.project_id {
+        Some(project_id) => parts.push(
+            app.db
+                .get_project_name(project_id)
+                .ok()
+                .flatten()
+                .unwrap_or_else(|| format!("project #{}", project_id)),
+        ),
+        None => parts.push("any project".to_string()),
+    }
+    if !preset.issue_filter.is_empty() {
+        parts.push(format!("/{}", preset.issue_filter));
+    }
+    if preset.my_issues {
+        parts.push("my issues".to_string());
+    }
+    if preset.sort_order != IssueSortOrder::UpdatedDesc {
+        parts.push(format!("sort: {}", preset.sort_order.as_str()));
+    }
+    if preset.group_by_status {
+        parts.push("grouped".to_string());
+    }
+    parts.join(" · ")
+}
+
+pub fn draw_filter_presets(f: &mut Frame, app: &App, main_area: Rect) {
+    let area = centered_rect(70, 60, main_area);
+    f.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(if app.filter_preset_name_input.is_some() {
+            [Constraint::Min(3), Constraint::Length(3)]
+        } else {
+            [Constraint::Min(3), Constraint::Length(0)]
+        })
+        .split(area);
+
+    let items: Vec<ListItem> = if app.filter_presets.is_empty() {
+        vec![
+            ListItem::new("  No presets yet, press s to save the current issue list view")
+                .style(Style::default().fg(app.theme.text_muted)),
+        ]
+    } else {
+        app.filter_presets
+            .iter()
+            .enumerate()
+            .map(|(i, preset)| {
+                let style = if i == app.filter_presets_list_state {
+                    Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(app.theme.text)
+                };
+                let key = preset
+                    .slot
+                    .map(|slot| format!("[{}] ", slot))
+                    .unwrap_or_else(|| "    ".to_string());
+
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled(format!("  {}", key), Style::default().fg(app.theme.info)),
+                        Span::styled(preset.name.clone(), style),
+                    ]),
+                    Line::from(Span::styled(
+                        format!("      {}", describe_preset(app, preset)),
+                        Style::default().fg(app.theme.text_muted),
+                    )),
+                ])
+            })
+            .collect()
+    };
+
+    let hints = if app.confirm_delete_filter_preset {
+        " Delete selected preset? y: Confirm | any other key: Cancel "
+    } else if app.filter_preset_name_input.is_some() {
+        " Enter: Save | ESC: Cancel "
+    } else {
+        " Enter: Apply | s: Save current | u: Update | 1-9: Bind key | 0: Unbind | d: Delete | ESC: Close "
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if app.confirm_delete_filter_preset {
+                app.theme.error
+            } else {
+                app.theme.success
+            }))
+            .title(format!(" Filter Presets ({}) ", app.filter_presets.len()))
+            .title_bottom(hints),
+    );
+
+    let mut list_state = ListState::default();
+    if !app.filter_presets.is_empty() {
+        list_state.select(Some(app.filter_presets_list_state));
+    }
+    f.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    if let Some(name) = &app.filter_preset_name_input {
+        let input = Paragraph::new(name.as_str())
+            .style(Style::default().fg(app.theme.text))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(app.theme.warning))
+                    .title(" Save current view as "),
+            );
+        f.render_widget(input, chunks[1]);
+        f.set_cursor_position((chunks[1].x + 1 + name.chars().count() as u16, chunks[1].y + 1));
+    }
+}
@@ -0,0 +1,106 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::app::App;
+use crate::db::{SNIPPET_MATCH_END, SNIPPET_MATCH_START};
+use crate::ui::widgets::centered_rect;
+
+pub fn draw_global_search(f: &mut Frame, app: &App, main_area: Rect) {
+    let area = centered_rect(80, 80, main_area);
+    f.render_widget(Clear, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled(app.global_search_query.as_str(), Style::default().fg(app.theme.text)),
+        Span::styled("█", Style::default().fg(app.theme.accent)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.accent))
+            .title(" Search all cached issues (subject, description, notes) "),
+    );
+    f.render_widget(input, chunks[0]);
+
+    let items: Vec<ListItem> = if app.global_search_results.is_empty() {
+        let message = if app.global_search_query.trim().is_empty() {
+            "  Type to search every cached project"
+        } else {
+            "  No cached issue matches"
+        };
+        vec![ListItem::new(message).style(Style::default().fg(app.theme.text_muted))]
+    } else {
+        app.global_search_results
+            .iter()
+            .enumerate()
+            .map(|(i, hit)| {
+                let style = if i == app.global_search_list_state {
+                    Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(app.theme.text)
+                };
+
+                let issue = &hit.issue;
+                let title = Line::from(vec![
+                    Span::styled(format!("  #{:<6} ", issue.id), Style::default().fg(app.theme.accent)),
+                    Span::styled(issue.subject.clone(), style),
+                    Span::styled(
+                        format!("  {} · {}", issue.project.name, issue.status.name),
+                        Style::default().fg(app.theme.text_muted),
+                    ),
+                ]);
+
+                let mut snippet = vec![Span::raw("          ")];
+                snippet.extend(snippet_spans(app, &hit.snippet));
+                ListItem::new(vec![title, Line::from(snippet)])
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.accent))
+            .title(format!(" Results ({}) ", app.global_search_results.len()))
+            .title_bottom(" ↑/↓: Navigate | Enter: Open | ESC: Close "),
+    );
+
+    let mut list_state = ListState::default();
+    if !app.global_search_results.is_empty() {
+        list_state.select(Some(app.global_search_list_state));
+    }
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+}
+
+/// Split a search snippet into spans with the matched terms highlighted
+fn snippet_spans(app: &App, snippet: &str) -> Vec<Span<'static>> {
+    let muted = Style::default().fg(app.theme.text_muted);
+    let highlight = Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD);
+
+    // Notes are joined with newlines in the index
+    let snippet = snippet.replace(['\n', '\r'], " ");
+    let mut spans = Vec::new();
+    for (i, part) in snippet.split(SNIPPET_MATCH_START).enumerate() {
+        // Everything before the first start marker is plain text
+        let (matched, rest) = match part.split_once(SNIPPET_MATCH_END) {
+            Some((matched, rest)) if i > 0 => (matched, rest),
+            _ => ("", part),
+        };
+        if !matched.is_empty() {
+            spans.push(Span::styled(matched.to_string(), highlight));
+        }
+        if !rest.is_empty() {
+            spans.push(Span::styled(rest.to_string(), muted));
+        }
+    }
+    spans
+}
@@ -49,7 +49,7 @@ pub fn draw_image_viewer(f: &mut Frame, app: &mut App, main_area: Rect) {
                         if a.content_url.starts_with("http://") || a.content_url.starts_with("https://") {
                             a.content_url.clone()
                         } else {
-                            format!("{}{}", app.config.redmine_url, a.content_url)
+                            format!("{}{}", app.config.redmine_url(), a.content_url)
                         };
                     attachment_url == *url
                 })
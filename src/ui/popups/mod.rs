@@ -1,14 +1,24 @@
 // Re-export all popup drawing functions
 mod attachment_manager;
+mod conflict;
 mod error_dialog;
 mod file_explorer;
+mod filter_presets;
+mod global_search;
 mod image_viewer;
 mod journal_helpers;
+mod pending_operations;
+mod time_entries;
 
 pub use attachment_manager::draw_attachment_manager;
+pub use conflict::draw_conflict;
 pub use error_dialog::draw_error_popup;
 pub use file_explorer::draw_file_explorer;
+pub use filter_presets::draw_filter_presets;
+pub use global_search::draw_global_search;
 pub use image_viewer::draw_image_viewer;
+pub use pending_operations::draw_pending_operations;
+pub use time_entries::draw_time_entries;
 
 // Keep the larger functions in this file temporarily
 // These can be split further in future iterations if needed
@@ -22,6 +32,8 @@ use ratatui::{
 };
 
 use crate::app::{App, InputMode};
+use crate::config::{ApiKeySource, API_KEY_ENV};
+use crate::redmine::IssueChild;
 use crate::ui::widgets::centered_rect;
 
 pub fn draw_issue_popup(f: &mut Frame, app: &mut App, main_area: Rect) {
@@ -121,6 +133,131 @@ pub fn draw_issue_popup(f: &mut Frame, app: &mut App, main_area: Rect) {
             ]));
         }
 
+        // Spent time is only returned by the issue detail endpoint
+        if issue.spent_hours.is_some() || issue.estimated_hours.is_some() {
+            let mut spans = vec![
+                Span::styled("Spent: ", Style::default().fg(app.theme.primary)),
+                Span::styled(
+                    format!("{:.2}h", issue.spent_hours.unwrap_or(0.0)),
+                    Style::default().fg(app.theme.info).add_modifier(Modifier::BOLD),
+                ),
+            ];
+            if let Some(total) = issue.total_spent_hours.filter(|t| Some(*t) != issue.spent_hours) {
+                spans.push(Span::raw(format!(" ({:.2}h with subtasks)", total)));
+            }
+            if let Some(estimated) = issue.estimated_hours {
+                spans.push(Span::raw("  "));
+                spans.push(Span::styled("Estimated: ", Style::default().fg(app.theme.primary)));
+                spans.push(Span::raw(format!("{:.2}h", estimated)));
+            }
+            text.push(Line::from(spans));
+        }
+
+        // Parents, relations and subtasks share one highlight, walked with Tab
+        let links = app.issue_links();
+        let selected_link = app.selected_issue_link_index(links.len());
+        let mut link_index = 0;
+
+        let parents = app.parent_chain(issue);
+        if !parents.is_empty() {
+            text.push(Line::from(""));
+            text.push(Line::from(vec![Span::styled(
+                "Parent issues",
+                Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD),
+            )]));
+            for (depth, parent_id) in parents.iter().enumerate() {
+                let is_selected = link_index == selected_link;
+                link_index += 1;
+                let mut spans = vec![Span::raw("  ".repeat(depth))];
+                spans.extend(linked_issue_spans(app, *parent_id, is_selected, None));
+                text.push(Line::from(spans));
+            }
+        }
+
+        // Relations and subtasks are only returned by the issue detail endpoint
+        let related = issue.related_issues();
+        if !related.is_empty() {
+            text.push(Line::from(""));
+            text.push(Line::from(vec![Span::styled(
+                format!("Related issues ({})", related.len()),
+                Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD),
+            )]));
+            let mut previous_label = "";
+            for (label, relation) in &related {
+                if *label != previous_label {
+                    text.push(Line::from(Span::styled(
+                        format!("  {}", label),
+                        Style::default().fg(app.theme.primary),
+                    )));
+                    previous_label = label;
+                }
+
+                let is_selected = link_index == selected_link;
+                link_index += 1;
+                let mut spans = vec![Span::raw("  ")];
+                spans.extend(linked_issue_spans(
+                    app,
+                    relation.other_issue_id(issue.id),
+                    is_selected,
+                    None,
+                ));
+                if let Some(delay) = relation.delay.filter(|d| *d != 0) {
+                    spans.push(Span::styled(
+                        format!(" ({} days)", delay),
+                        Style::default().fg(app.theme.text_muted),
+                    ));
+                }
+                text.push(Line::from(spans));
+            }
+        }
+
+        let subtasks = app.visible_subtasks(issue);
+        if !subtasks.is_empty() {
+            text.push(Line::from(""));
+            text.push(Line::from(vec![Span::styled(
+                format!("Subtasks ({})", issue.children.len()),
+                Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD),
+            )]));
+            for (depth, child) in subtasks {
+                let is_selected = link_index == selected_link;
+                link_index += 1;
+                let fold = if child.children.is_empty() {
+                    "  "
+                } else if app.collapsed_subtasks.contains(&child.id) {
+                    "▸ "
+                } else {
+                    "▾ "
+                };
+                let mut spans = vec![
+                    Span::raw("  ".repeat(depth)),
+                    Span::styled(fold, Style::default().fg(app.theme.text_muted)),
+                ];
+                spans.extend(linked_issue_spans(app, child.id, is_selected, Some(child)));
+                text.push(Line::from(spans));
+            }
+        }
+
+        // Watchers are only returned by the issue detail endpoint
+        let watching = app.is_watching(issue.id);
+        if !issue.watchers.is_empty() || watching {
+            text.push(Line::from(""));
+            let mut header = vec![Span::styled(
+                format!("Watchers ({})", issue.watchers.len()),
+                Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD),
+            )];
+            if watching {
+                header.push(Span::styled(
+                    "  you are watching",
+                    Style::default().fg(app.theme.success),
+                ));
+            }
+            text.push(Line::from(header));
+            if !issue.watchers.is_empty() {
+                let names: Vec<&str> = issue.watchers.iter().map(|w| w.name.as_str()).collect();
+                text.push(Line::from(format!("  {}", names.join(", "))));
+            }
+        }
+
         text.push(Line::from(""));
         text.push(Line::from(vec![Span::styled(
             "Description:",
@@ -182,7 +319,7 @@ pub fn draw_issue_popup(f: &mut Frame, app: &mut App, main_area: Rect) {
                 };
 
                 // Check if it's an image
-                let is_image = attachment.content_type.as_ref().map_or(false, |ct| ct.starts_with("image/"));
+                let is_image = attachment.content_type.as_ref().is_some_and(|ct| ct.starts_with("image/"));
                 let icon = if is_image { "🖼️ " } else { "📎 " };
 
                 text.push(Line::from(vec![
@@ -251,7 +388,7 @@ pub fn draw_issue_popup(f: &mut Frame, app: &mut App, main_area: Rect) {
 
             for journal in issue.journals.iter() {
                 // Show journals that have notes OR details (changes)
-                let has_notes = journal.notes.as_ref().map_or(false, |n| !n.trim().is_empty());
+                let has_notes = journal.notes.as_ref().is_some_and(|n| !n.trim().is_empty());
                 let has_details = !journal.details.is_empty();
                 
                 if has_notes || has_details {
@@ -439,10 +576,12 @@ pub fn draw_issue_popup(f: &mut Frame, app: &mut App, main_area: Rect) {
             )]));
         }
 
-        let title = if app.loading_issue {
+        let title = if app.confirm_delete_relation {
+            " Remove the selected relation? y: Confirm | any other key: Cancel "
+        } else if app.loading_issue {
             " Issue Detail - Loading... ⟳ "
         } else {
-            " Issue Detail (j/k scroll, g/G top/bottom, r reply, Shift+O browser, ESC close) "
+            " Issue Detail (j/k scroll, g/G top/bottom, r reply, t/T time, Shift+O browser, ESC close) "
         };
 
         let content_height = text.len();
@@ -454,7 +593,11 @@ pub fn draw_issue_popup(f: &mut Frame, app: &mut App, main_area: Rect) {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(app.theme.primary))
+                    .border_style(Style::default().fg(if app.confirm_delete_relation {
+                        app.theme.error
+                    } else {
+                        app.theme.primary
+                    }))
                     .title(title),
             )
             .scroll((app.popup_scroll as u16, 0))
@@ -481,6 +624,44 @@ pub fn draw_issue_popup(f: &mut Frame, app: &mut App, main_area: Rect) {
 }
 
 // Placeholder for help and config - these will remain in separate modules
+/// "▶ #id subject [status]" for an issue linked from the popup. Issues missing from the
+/// cache fall back to what the parent's subtask list says about them.
+fn linked_issue_spans(app: &App, issue_id: u64, is_selected: bool, child: Option<&IssueChild>) -> Vec<Span<'static>> {
+    let mut spans = vec![
+        Span::styled(
+            if is_selected { "  ▶ " } else { "    " },
+            Style::default().fg(app.theme.warning),
+        ),
+        Span::styled(
+            format!("#{} ", issue_id),
+            Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD),
+        ),
+    ];
+    let subject_style = if is_selected {
+        Style::default().fg(app.theme.text).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(app.theme.text)
+    };
+    match (app.db.get_issue(issue_id).ok().flatten(), child) {
+        (Some(other), _) => {
+            spans.push(Span::styled(other.subject, subject_style));
+            spans.push(Span::styled(
+                format!(" [{}]", other.status.name),
+                Style::default().fg(app.theme.get_status_color(&other.status.name)),
+            ));
+        }
+        (None, Some(child)) => {
+            spans.push(Span::styled(child.subject.clone(), subject_style));
+            spans.push(Span::styled(
+                format!(" [{}]", child.tracker.name),
+                Style::default().fg(app.theme.text_muted),
+            ));
+        }
+        (None, None) => spans.push(Span::styled("(not cached)", Style::default().fg(app.theme.text_muted))),
+    }
+    spans
+}
+
 pub fn draw_help(f: &mut Frame, app: &App, main_area: Rect) {
     // Render help as a centered popup overlay (80% width, 90% height)
     let area = centered_rect(80, 90, main_area);
@@ -508,6 +689,38 @@ pub fn draw_help(f: &mut Frame, app: &App, main_area: Rect) {
             Span::styled("  c ", Style::default().fg(app.theme.warning)),
             Span::raw("Open configuration"),
         ]),
+        Line::from(vec![
+            Span::styled("  F ", Style::default().fg(app.theme.warning)),
+            Span::raw("Search issues and notes in all cached projects"),
+        ]),
+        Line::from(vec![
+            Span::styled("  f ", Style::default().fg(app.theme.warning)),
+            Span::raw("Filter presets (save, apply, bind to 1-9)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  1-9 ", Style::default().fg(app.theme.warning)),
+            Span::raw("Apply the filter preset bound to the key"),
+        ]),
+        Line::from(vec![
+            Span::styled("  v ", Style::default().fg(app.theme.warning)),
+            Span::raw("Saved queries under the projects: Enter runs one, ESC returns to the project"),
+        ]),
+        Line::from(vec![
+            Span::styled("  V ", Style::default().fg(app.theme.warning)),
+            Span::raw("Roadmap of the selected project's versions"),
+        ]),
+        Line::from(vec![
+            Span::styled("  C ", Style::default().fg(app.theme.warning)),
+            Span::raw("Board: the issue list as one column per status"),
+        ]),
+        Line::from(vec![
+            Span::styled("  D ", Style::default().fg(app.theme.warning)),
+            Span::raw("Toggle between the My Work dashboard and the issue list"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Q ", Style::default().fg(app.theme.warning)),
+            Span::raw("Review changes queued while offline (r retry, d drop)"),
+        ]),
         Line::from(vec![
             Span::styled("  q ", Style::default().fg(app.theme.warning)),
             Span::raw("Quit application"),
@@ -517,6 +730,75 @@ pub fn draw_help(f: &mut Frame, app: &App, main_area: Rect) {
             Span::raw("Force quit"),
         ]),
         Line::from(""),
+        Line::from(vec![Span::styled(
+            "Dashboard",
+            Style::default()
+                .fg(app.theme.success)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )]),
+        Line::from(vec![
+            Span::styled("  Tab/h/l ", Style::default().fg(app.theme.warning)),
+            Span::raw("Switch section (1-4 jump to a section)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  s ", Style::default().fg(app.theme.warning)),
+            Span::raw("Cycle the section's sort order"),
+        ]),
+        Line::from(vec![
+            Span::styled("  p ", Style::default().fg(app.theme.warning)),
+            Span::raw("Go to the selected issue in its project"),
+        ]),
+        Line::from(vec![
+            Span::styled("  I/R ", Style::default().fg(app.theme.warning)),
+            Span::raw("Refresh your issues from the server"),
+        ]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "Board",
+            Style::default()
+                .fg(app.theme.success)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )]),
+        Line::from(vec![
+            Span::styled("  h/l ", Style::default().fg(app.theme.warning)),
+            Span::raw("Switch column (j/k move between cards)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  H/L ", Style::default().fg(app.theme.warning)),
+            Span::raw("Move the card to the previous/next status"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Enter ", Style::default().fg(app.theme.warning)),
+            Span::raw("Open the card's issue (J/K step through the column)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  C/ESC ", Style::default().fg(app.theme.warning)),
+            Span::raw("Back to the issue list"),
+        ]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "Roadmap",
+            Style::default()
+                .fg(app.theme.success)
+                .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+        )]),
+        Line::from(vec![
+            Span::styled("  Enter/l ", Style::default().fg(app.theme.warning)),
+            Span::raw("Show the version's issues (Enter again opens one, J/K step through them)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  h/ESC ", Style::default().fg(app.theme.warning)),
+            Span::raw("Back to the versions, then to the issue list"),
+        ]),
+        Line::from(vec![
+            Span::styled("  s ", Style::default().fg(app.theme.warning)),
+            Span::raw("Cycle the sort order of the version's issues"),
+        ]),
+        Line::from(vec![
+            Span::styled("  I/R ", Style::default().fg(app.theme.warning)),
+            Span::raw("Refresh versions (and the version's issues) from the server"),
+        ]),
+        Line::from(""),
         Line::from(vec![Span::styled(
             "Navigation",
             Style::default()
@@ -567,16 +849,24 @@ pub fn draw_help(f: &mut Frame, app: &App, main_area: Rect) {
         )]),
         Line::from(vec![
             Span::styled("  / ", Style::default().fg(app.theme.warning)),
-            Span::raw("Search/filter issues"),
+            Span::raw("Filter issues, e.g. status:open assignee:me priority:>=High due:<7d login"),
+        ]),
+        Line::from(vec![
+            Span::styled("  I ", Style::default().fg(app.theme.warning)),
+            Span::raw("Sync issues changed on the server"),
         ]),
         Line::from(vec![
             Span::styled("  R ", Style::default().fg(app.theme.warning)),
-            Span::raw("Refresh issues from server"),
+            Span::raw("Re-download all issues of the project"),
         ]),
         Line::from(vec![
             Span::styled("  s ", Style::default().fg(app.theme.warning)),
             Span::raw("Cycle sort order (updated, created, id, priority)"),
         ]),
+        Line::from(vec![
+            Span::styled("  H ", Style::default().fg(app.theme.warning)),
+            Span::raw("Toggle tree view (subtasks under their parent)"),
+        ]),
         Line::from(vec![
             Span::styled("  f ", Style::default().fg(app.theme.warning)),
             Span::raw("Filter by assignee"),
@@ -589,6 +879,10 @@ pub fn draw_help(f: &mut Frame, app: &App, main_area: Rect) {
             Span::styled("  o ", Style::default().fg(app.theme.warning)),
             Span::raw("Open selected issue in browser"),
         ]),
+        Line::from(vec![
+            Span::styled("  S ", Style::default().fg(app.theme.warning)),
+            Span::raw("Start/stop timer on issue (stopping opens a prefilled time entry)"),
+        ]),
         Line::from(vec![
             Span::styled("  b/B ", Style::default().fg(app.theme.warning)),
             Span::raw("Toggle bulk operation mode"),
@@ -639,6 +933,18 @@ pub fn draw_help(f: &mut Frame, app: &App, main_area: Rect) {
             Span::styled("  r ", Style::default().fg(app.theme.warning)),
             Span::raw("Reply / Add comment to issue"),
         ]),
+        Line::from(vec![
+            Span::styled("  t ", Style::default().fg(app.theme.warning)),
+            Span::raw("Log time on issue"),
+        ]),
+        Line::from(vec![
+            Span::styled("  T ", Style::default().fg(app.theme.warning)),
+            Span::raw("List time entries (n new, e edit, d delete)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  S ", Style::default().fg(app.theme.warning)),
+            Span::raw("Start/stop timer"),
+        ]),
         Line::from(vec![
             Span::styled("  1-9 ", Style::default().fg(app.theme.warning)),
             Span::raw("View/open attachment ("),
@@ -653,13 +959,41 @@ pub fn draw_help(f: &mut Frame, app: &App, main_area: Rect) {
             Span::styled("  Shift+O ", Style::default().fg(app.theme.warning)),
             Span::raw("Open issue in web browser"),
         ]),
+        Line::from(vec![
+            Span::styled("  Tab/Shift+Tab ", Style::default().fg(app.theme.warning)),
+            Span::raw("Select a parent, related issue or subtask"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Enter ", Style::default().fg(app.theme.warning)),
+            Span::raw("Open the selected issue (Backspace goes back)"),
+        ]),
+        Line::from(vec![
+            Span::styled("  Space ", Style::default().fg(app.theme.warning)),
+            Span::raw("Fold/unfold the selected subtask's subtasks"),
+        ]),
+        Line::from(vec![
+            Span::styled("  L ", Style::default().fg(app.theme.warning)),
+            Span::raw("Relate this issue to another one"),
+        ]),
+        Line::from(vec![
+            Span::styled("  X ", Style::default().fg(app.theme.warning)),
+            Span::raw("Remove the selected relation"),
+        ]),
+        Line::from(vec![
+            Span::styled("  N ", Style::default().fg(app.theme.warning)),
+            Span::raw("Create a subtask of this issue"),
+        ]),
+        Line::from(vec![
+            Span::styled("  W ", Style::default().fg(app.theme.warning)),
+            Span::raw("Watch/unwatch this issue"),
+        ]),
         Line::from(vec![
             Span::styled("  ESC ", Style::default().fg(app.theme.warning)),
             Span::raw("Close detail popup"),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Forms (Create Issue / Reply)",
+            "Forms (Create Issue / Reply / Log Time / Relation)",
             Style::default()
                 .fg(app.theme.success)
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
@@ -680,6 +1014,10 @@ pub fn draw_help(f: &mut Frame, app: &App, main_area: Rect) {
             Span::styled("  / ", Style::default().fg(app.theme.warning)),
             Span::raw("Search in dropdown (when focused)"),
         ]),
+        Line::from(vec![
+            Span::styled("  Space ", Style::default().fg(app.theme.warning)),
+            Span::raw("Check/uncheck option (multi-select, e.g. Watchers)"),
+        ]),
         Line::from(vec![
             Span::styled("  ESC ", Style::default().fg(app.theme.warning)),
             Span::raw("Cancel / Close form"),
@@ -708,7 +1046,8 @@ pub fn draw_config(f: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(13), // Logo and subtitle with spacing
+            Constraint::Length(10), // Logo and subtitle with spacing
+            Constraint::Length(3),  // Profile selector
             Constraint::Length(3),  // URL field
             Constraint::Length(3),  // API Key field
             Constraint::Length(17), // Theme selector (list of all themes)
@@ -752,9 +1091,6 @@ pub fn draw_config(f: &mut Frame, app: &App, area: Rect) {
             Span::styled("v0.1.0", Style::default().fg(app.theme.text_muted).add_modifier(Modifier::ITALIC)),
         ]),
         Line::from(""), // 1 line below subtitle
-        Line::from(""), // 2 lines below subtitle
-        Line::from(""), // 3 lines below subtitle
-        Line::from(""), // 4 lines below subtitle
     ];
 
     let logo_paragraph = Paragraph::new(logo_text)
@@ -762,18 +1098,62 @@ pub fn draw_config(f: &mut Frame, app: &App, area: Rect) {
         .block(Block::default().borders(Borders::NONE));
     f.render_widget(logo_paragraph, chunks[0]);
 
-    let url_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 0;
-    let api_key_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 1;
-    let theme_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 2;
-    let exclude_subprojects_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 3;
+    let profile_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 0;
+    let url_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 1;
+    let api_key_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 2;
+    let theme_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 3;
+    let exclude_subprojects_focused = matches!(app.input_mode, InputMode::Normal) && app.config_focused_field == 4;
 
+    let profile_editing = matches!(app.input_mode, InputMode::EditingProfileName);
     let url_editing = matches!(app.input_mode, InputMode::EditingUrl);
     let api_key_editing = matches!(app.input_mode, InputMode::EditingApiKey);
 
+    // Profile selector (or the new profile name while adding one)
+    let profile_block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(if profile_editing || profile_focused {
+            app.theme.warning
+        } else {
+            app.theme.text_secondary
+        }))
+        .title(if profile_editing {
+            " New Profile Name (Editing) "
+        } else if profile_focused {
+            " Profile (←/→: Select | Enter: Switch | a: Add | d: Remove) "
+        } else {
+            " Profile "
+        });
+
+    if profile_editing {
+        let profile_paragraph = Paragraph::new(app.profile_name_input.clone())
+            .style(Style::default().fg(app.theme.text))
+            .block(profile_block);
+        f.render_widget(profile_paragraph, chunks[1]);
+
+        let cursor_x = chunks[1].x + 1 + app.profile_name_input.len() as u16;
+        f.set_cursor_position((cursor_x, chunks[1].y + 1));
+    } else {
+        let active = app.config.profile_name();
+        let mut profile_spans = Vec::new();
+        for (i, name) in app.config.profile_names().iter().enumerate() {
+            let marker = if name == active { "● " } else { "  " };
+            let style = if i == app.profile_selector_index && profile_focused {
+                Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)
+            } else if name == active {
+                Style::default().fg(app.theme.success)
+            } else {
+                Style::default().fg(app.theme.text_muted)
+            };
+            profile_spans.push(Span::styled(format!("{}{}   ", marker, name), style));
+        }
+        let profile_paragraph = Paragraph::new(Line::from(profile_spans)).block(profile_block);
+        f.render_widget(profile_paragraph, chunks[1]);
+    }
+
     // Redmine URL field
     let url_border_color = if url_editing || url_focused {
         app.theme.warning
-    } else if app.config.redmine_url.is_empty() {
+    } else if app.config.redmine_url().is_empty() {
         app.theme.error
     } else {
         app.theme.text_secondary
@@ -792,29 +1172,29 @@ pub fn draw_config(f: &mut Frame, app: &App, area: Rect) {
         // When editing, show the input buffer
         app.url_input.clone()
     } else {
-        app.config.redmine_url.clone()
+        app.config.redmine_url().to_string()
     };
 
     let url_paragraph = Paragraph::new(url_text.clone())
-        .style(Style::default().fg(if app.config.redmine_url.is_empty() {
+        .style(Style::default().fg(if app.config.redmine_url().is_empty() {
             app.theme.text_muted
         } else {
             app.theme.text
         }))
         .block(url_block);
-    f.render_widget(url_paragraph, chunks[1]);
+    f.render_widget(url_paragraph, chunks[2]);
 
     // Show cursor when editing URL
     if url_editing {
-        let cursor_x = chunks[1].x + 1 + app.url_input.len() as u16;
-        let cursor_y = chunks[1].y + 1;
+        let cursor_x = chunks[2].x + 1 + app.url_input.len() as u16;
+        let cursor_y = chunks[2].y + 1;
         f.set_cursor_position((cursor_x, cursor_y));
     }
 
     // API Key field
     let api_key_border_color = if api_key_editing || api_key_focused {
         app.theme.warning
-    } else if app.config.api_key.is_empty() {
+    } else if app.config.api_key().is_empty() {
         app.theme.error
     } else {
         app.theme.text_secondary
@@ -829,26 +1209,34 @@ pub fn draw_config(f: &mut Frame, app: &App, area: Rect) {
             " API Key * "
         });
 
+    // Never echo the key: mask the input and only show where the active key comes from
     let api_key_display = if api_key_editing {
-        // When editing, show the input buffer
-        app.api_key_input.clone()
+        "•".repeat(app.api_key_input.chars().count())
     } else {
-        app.config.api_key.clone()
+        match app.config.api_key_source() {
+            Some(ApiKeySource::Environment) => format!("•••••••• (from {} environment variable)", API_KEY_ENV),
+            Some(ApiKeySource::Command) => format!(
+                "•••••••• (from api_key_command: {})",
+                app.config.api_key_command().unwrap_or_default()
+            ),
+            Some(ApiKeySource::ConfigFile) => "•••••••• (stored in config file)".to_string(),
+            None => String::new(),
+        }
     };
 
     let api_key_paragraph = Paragraph::new(api_key_display.clone())
-        .style(Style::default().fg(if app.config.api_key.is_empty() {
+        .style(Style::default().fg(if app.config.api_key().is_empty() {
             app.theme.text_muted
         } else {
             app.theme.text
         }))
         .block(api_key_block);
-    f.render_widget(api_key_paragraph, chunks[2]);
+    f.render_widget(api_key_paragraph, chunks[3]);
 
     // Show cursor when editing API key
     if api_key_editing {
-        let cursor_x = chunks[2].x + 1 + app.api_key_input.len() as u16;
-        let cursor_y = chunks[2].y + 1;
+        let cursor_x = chunks[3].x + 1 + app.api_key_input.chars().count() as u16;
+        let cursor_y = chunks[3].y + 1;
         f.set_cursor_position((cursor_x, cursor_y));
     }
 
@@ -896,7 +1284,7 @@ pub fn draw_config(f: &mut Frame, app: &App, area: Rect) {
 
     let theme_paragraph = Paragraph::new(theme_lines)
         .block(theme_block);
-    f.render_widget(theme_paragraph, chunks[3]);
+    f.render_widget(theme_paragraph, chunks[4]);
 
     // Exclude subprojects checkbox
     let exclude_subprojects_border_color = if exclude_subprojects_focused {
@@ -921,10 +1309,10 @@ pub fn draw_config(f: &mut Frame, app: &App, area: Rect) {
     let exclude_subprojects_paragraph = Paragraph::new(exclude_subprojects_text)
         .style(Style::default().fg(app.theme.text))
         .block(exclude_subprojects_block);
-    f.render_widget(exclude_subprojects_paragraph, chunks[4]);
+    f.render_widget(exclude_subprojects_paragraph, chunks[5]);
 
     // Instructions at the bottom
-    let instructions = if url_editing || api_key_editing {
+    let instructions = if url_editing || api_key_editing || profile_editing {
         "Editing mode: Type to input, ESC to finish editing"
     } else {
         "Tab/Shift+Tab: Navigate fields | ↑/↓ or j/k: Select theme | Enter: Edit/Save | ESC: Cancel"
@@ -934,5 +1322,5 @@ pub fn draw_config(f: &mut Frame, app: &App, area: Rect) {
         .style(Style::default().fg(app.theme.text_muted))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::NONE));
-    f.render_widget(instructions_paragraph, chunks[6]);
+    f.render_widget(instructions_paragraph, chunks[7]);
 }
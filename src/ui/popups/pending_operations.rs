@@ -0,0 +1,86 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::app::App;
+use crate::ui::widgets::centered_rect;
+
+pub fn draw_pending_operations(f: &mut Frame, app: &App, main_area: Rect) {
+    let area = centered_rect(70, 60, main_area);
+    f.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = if app.pending_operations.is_empty() {
+        vec![ListItem::new("  Nothing queued, every change reached the server").style(Style::default().fg(app.theme.text_muted))]
+    } else {
+        app.pending_operations
+            .iter()
+            .enumerate()
+            .map(|(i, operation)| {
+                let style = if i == app.pending_operations_list_state {
+                    Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(app.theme.text)
+                };
+
+                let (marker, marker_color) = if operation.blocked {
+                    ("✗ ", app.theme.error)
+                } else {
+                    ("⇅ ", app.theme.info)
+                };
+
+                let mut lines = vec![Line::from(vec![
+                    Span::styled(format!("  {}", marker), Style::default().fg(marker_color)),
+                    Span::styled(
+                        format!("{}  ", operation.created_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")),
+                        Style::default().fg(app.theme.text_muted),
+                    ),
+                    Span::styled(app.describe_operation(operation), style),
+                ])];
+                if let Some(error) = &operation.last_error {
+                    let color = if operation.blocked { app.theme.error } else { app.theme.text_muted };
+                    lines.push(Line::from(Span::styled(
+                        format!("      {} attempt(s): {}", operation.attempts, error),
+                        Style::default().fg(color),
+                    )));
+                }
+
+                ListItem::new(lines)
+            })
+            .collect()
+    };
+
+    let blocked = app.pending_operations.iter().filter(|op| op.blocked).count();
+    let title = if blocked > 0 {
+        format!(" Pending Changes ({}, {} rejected) ", app.pending_operations.len(), blocked)
+    } else {
+        format!(" Pending Changes ({}) ", app.pending_operations.len())
+    };
+
+    let hints = if app.confirm_drop_pending_operation {
+        " Drop selected change? y: Confirm | any other key: Cancel "
+    } else {
+        " j/k: Navigate | r: Retry | d: Drop | ESC: Close "
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if app.confirm_drop_pending_operation || blocked > 0 {
+                app.theme.error
+            } else {
+                app.theme.success
+            }))
+            .title(title)
+            .title_bottom(hints),
+    );
+
+    let mut list_state = ListState::default();
+    if !app.pending_operations.is_empty() {
+        list_state.select(Some(app.pending_operations_list_state));
+    }
+    f.render_stateful_widget(list, area, &mut list_state);
+}
@@ -0,0 +1,73 @@
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::app::App;
+use crate::ui::widgets::centered_rect;
+
+pub fn draw_time_entries(f: &mut Frame, app: &App, main_area: Rect) {
+    let area = centered_rect(70, 60, main_area);
+    f.render_widget(Clear, area);
+
+    let items: Vec<ListItem> = if app.time_entries.is_empty() {
+        vec![ListItem::new("  No time logged yet (press n to log time)").style(Style::default().fg(app.theme.text_muted))]
+    } else {
+        app.time_entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let style = if i == app.time_entries_list_state {
+                    Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(app.theme.text)
+                };
+
+                let mut spans = vec![
+                    Span::styled(format!("  {}  ", entry.spent_on), Style::default().fg(app.theme.text_muted)),
+                    Span::styled(format!("{:>6.2}h  ", entry.hours), style),
+                    Span::styled(format!("{:<14} ", entry.activity.name), Style::default().fg(app.theme.info)),
+                    Span::styled(format!("{:<20} ", entry.user.name), Style::default().fg(app.theme.text_secondary)),
+                ];
+                if let Some(comments) = entry.comments.as_deref().filter(|c| !c.is_empty()) {
+                    spans.push(Span::styled(comments.to_string(), style));
+                }
+
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    };
+
+    let total: f32 = app.time_entries.iter().map(|e| e.hours).sum();
+    let title = match app.time_entry_issue_id {
+        Some(issue_id) => format!(" Time Entries #{} ({:.2}h total) ", issue_id, total),
+        None => format!(" Time Entries ({:.2}h total) ", total),
+    };
+
+    let hints = if app.confirm_delete_time_entry {
+        " Delete selected time entry? y: Confirm | any other key: Cancel "
+    } else {
+        " j/k: Navigate | n: Log time | e/Enter: Edit | d: Delete | ESC: Close "
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(if app.confirm_delete_time_entry {
+                app.theme.error
+            } else {
+                app.theme.success
+            }))
+            .title(title)
+            .title_bottom(hints),
+    );
+
+    let mut list_state = ListState::default();
+    if !app.time_entries.is_empty() {
+        list_state.select(Some(app.time_entries_list_state));
+    }
+    f.render_stateful_widget(list, area, &mut list_state);
+}
@@ -0,0 +1,203 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use super::main_screen::issue_number;
+use super::widgets::get_loading_spinner;
+use crate::app::App;
+use crate::redmine::Version;
+
+/// Width of a version's progress bar in cells
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// Roadmap: the project's versions on the left, the highlighted version's issues on the right
+pub fn draw_roadmap(f: &mut Frame, app: &App, area: Rect) {
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(area);
+    draw_versions(f, app, panes[0]);
+    draw_version_issues(f, app, panes[1]);
+}
+
+fn draw_versions(f: &mut Frame, app: &App, area: Rect) {
+    let is_focused = !app.roadmap_focus_issues;
+    let today = chrono::Local::now().date_naive().format("%Y-%m-%d").to_string();
+
+    let items: Vec<ListItem> = if app.roadmap_versions.is_empty() {
+        vec![ListItem::new("  No versions (press R to refresh)").style(Style::default().fg(app.theme.text_muted))]
+    } else {
+        app.roadmap_versions
+            .iter()
+            .enumerate()
+            .map(|(index, version)| {
+                let is_at_cursor = index == app.roadmap_list_state;
+                let name_style = match (is_at_cursor, is_focused) {
+                    (true, true) => Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD),
+                    (true, false) => Style::default()
+                        .fg(app.theme.text_secondary)
+                        .add_modifier(Modifier::BOLD),
+                    _ if !version.is_open() => Style::default().fg(app.theme.text_muted),
+                    _ => Style::default().fg(app.theme.text).add_modifier(Modifier::BOLD),
+                };
+
+                let mut header = vec![
+                    Span::styled(version.name.clone(), name_style),
+                    Span::styled(
+                        format!(" [{}]", version.status),
+                        Style::default().fg(if version.is_open() {
+                            app.theme.status_progress
+                        } else {
+                            app.theme.status_closed
+                        }),
+                    ),
+                ];
+                if Some(version.project.id) != app.roadmap_project_id {
+                    header.push(Span::styled(
+                        format!(" {}", version.project.name),
+                        Style::default().fg(app.theme.accent),
+                    ));
+                }
+                if let Some(due) = &version.due_date {
+                    let color = if version.is_open() && due.as_str() < today.as_str() {
+                        app.theme.error
+                    } else {
+                        app.theme.text_muted
+                    };
+                    header.push(Span::styled(format!("  due {}", due), Style::default().fg(color)));
+                }
+
+                ListItem::new(vec![Line::from(header), progress_line(app, version)])
+            })
+            .collect()
+    };
+
+    let loader = if app.loading && is_focused {
+        format!(" {} Loading...", get_loading_spinner())
+    } else {
+        String::new()
+    };
+    let project = app.selected_project.as_ref().map(|p| p.name.as_str()).unwrap_or("");
+    let title = format!(" Roadmap: {} ({}){} ", project, app.roadmap_versions.len(), loader);
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(pane_border(app, is_focused))
+            .title(title),
+    );
+    let mut list_state = ListState::default();
+    if !app.roadmap_versions.is_empty() {
+        list_state.select(Some(app.roadmap_list_state));
+    }
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+/// Progress bar with closed/total counts, from the cached issues targeting the version
+fn progress_line<'a>(app: &App, version: &Version) -> Line<'a> {
+    let Some(progress) = app.version_progress.get(&version.id).filter(|p| p.total > 0) else {
+        return Line::from(Span::styled(
+            "  No cached issues",
+            Style::default().fg(app.theme.text_muted),
+        ));
+    };
+
+    let percent = progress.percent_done();
+    let filled = (percent as usize * PROGRESS_BAR_WIDTH / 100).min(PROGRESS_BAR_WIDTH);
+    Line::from(vec![
+        Span::raw("  "),
+        Span::styled("█".repeat(filled), Style::default().fg(app.theme.success)),
+        Span::styled(
+            "░".repeat(PROGRESS_BAR_WIDTH - filled),
+            Style::default().fg(app.theme.text_muted),
+        ),
+        Span::styled(format!(" {:>3}%", percent), Style::default().fg(app.theme.text)),
+        Span::styled(
+            format!("  {}/{} closed", progress.closed, progress.total),
+            Style::default().fg(app.theme.text_secondary),
+        ),
+    ])
+}
+
+fn draw_version_issues(f: &mut Frame, app: &App, area: Rect) {
+    let is_focused = app.roadmap_focus_issues;
+
+    let items: Vec<ListItem> = if app.roadmap_issues.is_empty() {
+        vec![ListItem::new("  No cached issues target this version").style(Style::default().fg(app.theme.text_muted))]
+    } else {
+        app.roadmap_issues
+            .iter()
+            .enumerate()
+            .map(|(index, issue)| {
+                let is_at_cursor = index == app.roadmap_issues_list_state;
+                let line_style = match (is_at_cursor, is_focused) {
+                    (true, true) => Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD),
+                    (true, false) => Style::default()
+                        .fg(app.theme.text_secondary)
+                        .add_modifier(Modifier::BOLD),
+                    _ => Style::default().fg(app.theme.text),
+                };
+
+                let mut spans = vec![
+                    Span::styled(issue_number(app, issue), Style::default().fg(app.theme.text_muted)),
+                    Span::styled(
+                        format!("{} ", issue.priority.name.chars().next().unwrap_or('N')),
+                        Style::default()
+                            .fg(app.theme.get_priority_color(&issue.priority.name))
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("[{}] ", issue.status.name),
+                        Style::default().fg(app.theme.get_status_color(&issue.status.name)),
+                    ),
+                    Span::styled(issue.subject.clone(), line_style),
+                ];
+                if let Some(done_ratio) = issue.done_ratio.filter(|ratio| *ratio > 0) {
+                    spans.push(Span::styled(
+                        format!("  {}%", done_ratio),
+                        Style::default().fg(app.theme.text_muted),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect()
+    };
+
+    let loader = if app.loading && is_focused {
+        format!(" {} Loading...", get_loading_spinner())
+    } else {
+        String::new()
+    };
+    let version = app.selected_roadmap_version().map(|v| v.name.as_str()).unwrap_or("");
+    let title = format!(
+        " {} ({}){} [Sort: {}] ",
+        version,
+        app.roadmap_issues.len(),
+        loader,
+        app.roadmap_sort_order.as_str()
+    );
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(pane_border(app, is_focused))
+            .title(title),
+    );
+    let mut list_state = ListState::default();
+    if !app.roadmap_issues.is_empty() {
+        list_state.select(Some(app.roadmap_issues_list_state));
+    }
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn pane_border(app: &App, is_focused: bool) -> Style {
+    if is_focused {
+        Style::default().fg(app.theme.border_focused)
+    } else {
+        Style::default().fg(app.theme.border)
+    }
+}
@@ -20,9 +20,26 @@ fn get_contextual_help(app: &App) -> Vec<(String, String)> {
                     ("J/K".to_string(), "Next/Prev Issue".to_string()),
                     ("g/G".to_string(), "Top/Bottom".to_string()),
                     ("r".to_string(), "Reply".to_string()),
+                    ("t/T".to_string(), "Log/List Time".to_string()),
+                    ("S".to_string(), if app.active_timer.is_some() { "Stop Timer" } else { "Start Timer" }.to_string()),
                     ("O".to_string(), "Open in Browser".to_string()),
                     ("1-9".to_string(), "View Attachment".to_string()),
                     ("[/]".to_string(), "Prev/Next Page".to_string()),
+                    ("Tab".to_string(), "Select Linked".to_string()),
+                    ("Enter".to_string(), "Open Linked".to_string()),
+                    ("Space".to_string(), "Fold Subtasks".to_string()),
+                    ("Bksp".to_string(), "Back".to_string()),
+                    ("L/X".to_string(), "Link/Unlink".to_string()),
+                    ("N".to_string(), "New Subtask".to_string()),
+                    (
+                        "W".to_string(),
+                        if app.current_issue.as_ref().is_some_and(|i| app.is_watching(i.id)) {
+                            "Unwatch"
+                        } else {
+                            "Watch"
+                        }
+                        .to_string(),
+                    ),
                 ]
             } else {
                 match (app.screen, app.focused_pane) {
@@ -32,9 +49,16 @@ fn get_contextual_help(app: &App) -> Vec<(String, String)> {
                         ("Space".to_string(), "Collapse".to_string()),
                         ("/".to_string(), "Search".to_string()),
                         ("P".to_string(), "Refresh".to_string()),
+                        ("v".to_string(), "Queries".to_string()),
+                        ("l".to_string(), "→Issues".to_string()),
+                    ],
+                    (Screen::Main, Pane::Queries) => vec![
+                        ("Enter".to_string(), "Run".to_string()),
+                        ("j/k".to_string(), "Navigate".to_string()),
+                        ("v".to_string(), "←Projects".to_string()),
                         ("l".to_string(), "→Issues".to_string()),
                     ],
-                    (Screen::Main, Pane::Issues) if app.selected_project.is_some() => {
+                    (Screen::Main, Pane::Issues) if app.shows_issue_list() => {
                         let mut help = vec![
                             ("j/k".to_string(), "Navigate".to_string()),
                             ("n".to_string(), "New".to_string()),
@@ -52,8 +76,15 @@ fn get_contextual_help(app: &App) -> Vec<(String, String)> {
                             } else {
                                 help.push(("s".to_string(), "Sort".to_string()));
                                 help.push(("g".to_string(), "Group".to_string()));
+                                help.push(("H".to_string(), "Tree".to_string()));
                                 help.push(("b".to_string(), "Bulk".to_string()));
                                 help.push(("m".to_string(), "My Issues".to_string()));
+                                help.push(("f".to_string(), "Presets".to_string()));
+                                help.push(("v".to_string(), "Queries".to_string()));
+                                help.push(("V".to_string(), "Roadmap".to_string()));
+                                help.push(("C".to_string(), "Board".to_string()));
+                                help.push(("D".to_string(), "Dashboard".to_string()));
+                                help.push(("S".to_string(), if app.active_timer.is_some() { "Stop Timer" } else { "Timer" }.to_string()));
                                 help.push(("z".to_string(), "Maximize".to_string()));
                             }
                         }
@@ -62,12 +93,42 @@ fn get_contextual_help(app: &App) -> Vec<(String, String)> {
                     (Screen::Main, Pane::Issues) => vec![
                         ("h".to_string(), "←Projects".to_string()),
                     ],
+                    (Screen::Dashboard, _) => vec![
+                        ("Tab".to_string(), "Section".to_string()),
+                        ("j/k".to_string(), "Navigate".to_string()),
+                        ("Enter".to_string(), "View".to_string()),
+                        ("s".to_string(), "Sort".to_string()),
+                        ("p".to_string(), "Go to Project".to_string()),
+                        ("I".to_string(), "Refresh".to_string()),
+                        ("D".to_string(), "Issues".to_string()),
+                    ],
+                    (Screen::Board, _) => vec![
+                        ("h/l".to_string(), "Column".to_string()),
+                        ("j/k".to_string(), "Navigate".to_string()),
+                        ("H/L".to_string(), "Move to Prev/Next Status".to_string()),
+                        ("Enter".to_string(), "View".to_string()),
+                        ("s".to_string(), "Sort".to_string()),
+                        ("I".to_string(), "Refresh".to_string()),
+                        ("C".to_string(), "List".to_string()),
+                    ],
+                    (Screen::Roadmap, _) if app.roadmap_focus_issues => vec![
+                        ("j/k".to_string(), "Navigate".to_string()),
+                        ("Enter".to_string(), "View".to_string()),
+                        ("s".to_string(), "Sort".to_string()),
+                        ("R".to_string(), "Refresh".to_string()),
+                        ("h".to_string(), "←Versions".to_string()),
+                    ],
+                    (Screen::Roadmap, _) => vec![
+                        ("j/k".to_string(), "Navigate".to_string()),
+                        ("Enter".to_string(), "Open Version".to_string()),
+                        ("R".to_string(), "Refresh".to_string()),
+                        ("V".to_string(), "Issues".to_string()),
+                    ],
                     (Screen::Config, _) => vec![
                         ("Tab".to_string(), "Next".to_string()),
                         ("Enter".to_string(), "Edit/Toggle".to_string()),
                         ("ESC".to_string(), "Back".to_string()),
                     ],
-                    _ => vec![],
                 }
             }
         },
@@ -97,6 +158,42 @@ fn get_contextual_help(app: &App) -> Vec<(String, String)> {
             ("d/Del".to_string(), "Remove".to_string()),
             ("ESC".to_string(), "Close".to_string()),
         ],
+        InputMode::LoggingTime | InputMode::AddingRelation => vec![
+            ("Tab".to_string(), "Next Field".to_string()),
+            ("Ctrl+S".to_string(), "Save".to_string()),
+            ("ESC".to_string(), "Cancel".to_string()),
+        ],
+        InputMode::ManagingTimeEntries => vec![
+            ("j/k".to_string(), "Navigate".to_string()),
+            ("n".to_string(), "Log Time".to_string()),
+            ("e".to_string(), "Edit".to_string()),
+            ("d".to_string(), "Delete".to_string()),
+            ("ESC".to_string(), "Close".to_string()),
+        ],
+        InputMode::ManagingPendingOperations => vec![
+            ("j/k".to_string(), "Navigate".to_string()),
+            ("r".to_string(), "Retry".to_string()),
+            ("d".to_string(), "Drop".to_string()),
+            ("ESC".to_string(), "Close".to_string()),
+        ],
+        InputMode::ResolvingConflict => vec![
+            ("m".to_string(), "Merge".to_string()),
+            ("o".to_string(), "Overwrite".to_string()),
+            ("a".to_string(), "Abort".to_string()),
+        ],
+        InputMode::GlobalSearch => vec![
+            ("↑/↓".to_string(), "Navigate".to_string()),
+            ("Enter".to_string(), "Open".to_string()),
+            ("ESC".to_string(), "Close".to_string()),
+        ],
+        InputMode::ManagingFilterPresets => vec![
+            ("j/k".to_string(), "Navigate".to_string()),
+            ("Enter".to_string(), "Apply".to_string()),
+            ("s".to_string(), "Save Current".to_string()),
+            ("1-9".to_string(), "Bind Key".to_string()),
+            ("d".to_string(), "Delete".to_string()),
+            ("ESC".to_string(), "Close".to_string()),
+        ],
         _ => vec![],
     }
 }
@@ -111,6 +208,7 @@ fn get_compact_help(app: &App) -> Vec<(String, String)> {
                     ("J/K".to_string(), "Issue".to_string()),
                     ("r".to_string(), "Reply".to_string()),
                     ("1-9".to_string(), "Attach".to_string()),
+                    ("Tab".to_string(), "Linked".to_string()),
                 ]
             } else {
                 match (app.screen, app.focused_pane) {
@@ -120,6 +218,11 @@ fn get_compact_help(app: &App) -> Vec<(String, String)> {
                         ("/".to_string(), "Search".to_string()),
                         ("l".to_string(), "→".to_string()),
                     ],
+                    (Screen::Main, Pane::Queries) => vec![
+                        ("↵".to_string(), "Run".to_string()),
+                        ("j/k".to_string(), "↕".to_string()),
+                        ("v".to_string(), "←".to_string()),
+                    ],
                     (Screen::Main, Pane::Issues) if !app.filtered_issues.is_empty() => {
                         if app.bulk_operation_mode {
                             vec![
@@ -137,18 +240,35 @@ fn get_compact_help(app: &App) -> Vec<(String, String)> {
                             ]
                         }
                     },
-                    (Screen::Main, Pane::Issues) if app.selected_project.is_some() => vec![
+                    (Screen::Main, Pane::Issues) if app.shows_issue_list() => vec![
                         ("n".to_string(), "New".to_string()),
                         ("h".to_string(), "←".to_string()),
                     ],
                     (Screen::Main, Pane::Issues) => vec![
                         ("h".to_string(), "←Projects".to_string()),
                     ],
+                    (Screen::Dashboard, _) => vec![
+                        ("Tab".to_string(), "Section".to_string()),
+                        ("↵".to_string(), "View".to_string()),
+                        ("p".to_string(), "Project".to_string()),
+                        ("D".to_string(), "Issues".to_string()),
+                    ],
+                    (Screen::Board, _) => vec![
+                        ("h/l".to_string(), "↔".to_string()),
+                        ("H/L".to_string(), "Move".to_string()),
+                        ("↵".to_string(), "View".to_string()),
+                        ("C".to_string(), "List".to_string()),
+                    ],
+                    (Screen::Roadmap, _) => vec![
+                        ("j/k".to_string(), "↕".to_string()),
+                        ("↵".to_string(), "Open".to_string()),
+                        ("h".to_string(), "←".to_string()),
+                        ("V".to_string(), "Issues".to_string()),
+                    ],
                     (Screen::Config, _) => vec![
                         ("Tab".to_string(), "Next".to_string()),
                         ("↵".to_string(), "Edit".to_string()),
                     ],
-                    _ => vec![],
                 }
             }
         },
@@ -174,66 +294,75 @@ fn get_compact_help(app: &App) -> Vec<(String, String)> {
             ("j/k".to_string(), "↕".to_string()),
             ("d".to_string(), "Remove".to_string()),
         ],
+        InputMode::LoggingTime | InputMode::AddingRelation => vec![
+            ("^S".to_string(), "Save".to_string()),
+            ("Esc".to_string(), "Cancel".to_string()),
+        ],
+        InputMode::ManagingTimeEntries => vec![
+            ("n".to_string(), "New".to_string()),
+            ("e".to_string(), "Edit".to_string()),
+            ("d".to_string(), "Del".to_string()),
+        ],
+        InputMode::ManagingPendingOperations => vec![
+            ("r".to_string(), "Retry".to_string()),
+            ("d".to_string(), "Drop".to_string()),
+        ],
+        InputMode::ResolvingConflict => vec![
+            ("m".to_string(), "Merge".to_string()),
+            ("o".to_string(), "Overwr".to_string()),
+            ("a".to_string(), "Abort".to_string()),
+        ],
+        InputMode::GlobalSearch => vec![
+            ("↵".to_string(), "Open".to_string()),
+            ("Esc".to_string(), "Close".to_string()),
+        ],
+        InputMode::ManagingFilterPresets => vec![
+            ("↵".to_string(), "Apply".to_string()),
+            ("s".to_string(), "Save".to_string()),
+            ("1-9".to_string(), "Bind".to_string()),
+        ],
         _ => vec![],
     }
 }
 
 pub fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
-    // Helper function to generate current selection line
-    let get_selection_line = || -> Line {
-        let mut spans = vec![];
-
-        // Show current project with last activity (use helper to get project at cursor)
-        if let Some(project) = app.get_project_at_cursor() {
-            spans.push(Span::styled("Project: ", Style::default().fg(app.theme.text_muted)));
-            spans.push(Span::styled(
-                &project.name,
-                Style::default().fg(app.theme.primary).add_modifier(Modifier::BOLD),
-            ));
+    // Helper function to generate sync info line
+    let get_sync_line = || -> Line {
+        let mut line2_spans = vec![];
 
-            // Show last activity date
-            if let Some(last_activity) = project.last_issue_activity.as_ref()
-                .or(project.updated_on.as_ref())
-                .or(project.created_on.as_ref())
-            {
-                let activity_str = last_activity.format("%Y-%m-%d %H:%M").to_string();
-                spans.push(Span::raw(" ("));
-                spans.push(Span::styled(
-                    activity_str,
-                    Style::default().fg(app.theme.accent),
-                ));
-                spans.push(Span::raw(")"));
-            }
-        } else {
-            spans.push(Span::styled("No project selected", Style::default().fg(app.theme.text_muted)));
+        // Running timer
+        if let Some(timer) = &app.active_timer {
+            let elapsed = timer.elapsed();
+            line2_spans.extend(vec![
+                Span::styled(
+                    format!(
+                        "⏱ #{} {} {:02}:{:02}:{:02}",
+                        timer.issue_id,
+                        timer.subject.chars().take(24).collect::<String>(),
+                        elapsed.num_hours(),
+                        elapsed.num_minutes() % 60,
+                        elapsed.num_seconds() % 60
+                    ),
+                    Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" | "),
+            ]);
         }
 
-        // Show current issue if any (using helper that handles grouped mode)
-        if let Some(issue) = app.get_issue_at_cursor() {
-            spans.push(Span::raw(" | "));
-            spans.push(Span::styled("Issue: ", Style::default().fg(app.theme.text_muted)));
-            spans.push(Span::styled(
-                format!("#{}", issue.id),
-                Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD),
-            ));
-            spans.push(Span::raw(" "));
-
-            // Truncate subject if too long
-            let subject = if issue.subject.len() > 60 {
-                format!("{}...", issue.subject.chars().take(57).collect::<String>())
-            } else {
-                issue.subject.clone()
-            };
-            spans.push(Span::raw(subject));
+        // Writes waiting to reach the server
+        if app.pending_operations_count > 0 {
+            let blocked = app.pending_operations.iter().any(|op| op.blocked);
+            line2_spans.extend(vec![
+                Span::styled(
+                    format!("⇅ {} pending", app.pending_operations_count),
+                    Style::default()
+                        .fg(if blocked { app.theme.error } else { app.theme.info })
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(" (Q) | "),
+            ]);
         }
 
-        Line::from(spans)
-    };
-
-    // Helper function to generate sync info line
-    let get_sync_line = || -> Line {
-        let mut line2_spans = vec![];
-
         // Projects sync time
         if let Some(sync_time) = &app.last_projects_sync {
             let now = chrono::Utc::now();
@@ -299,13 +428,32 @@ pub fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
     };
 
     let status_text = if app.input_mode == InputMode::Searching {
+        // Mark the query from where a syntax error starts
+        let split = app
+            .search_error
+            .as_ref()
+            .and_then(|e| e.position)
+            .filter(|&p| app.search_query.is_char_boundary(p))
+            .unwrap_or(app.search_query.len());
+        let (valid, invalid) = app.search_query.split_at(split);
+        let second_line = match &app.search_error {
+            Some(error) => Line::from(vec![
+                Span::styled("✗ ", Style::default().fg(app.theme.error).add_modifier(Modifier::BOLD)),
+                Span::styled(error.message.clone(), Style::default().fg(app.theme.error)),
+            ]),
+            None => get_sync_line(),
+        };
         vec![
             Line::from(vec![
                 Span::styled(
                     "Search: /",
                     Style::default().fg(app.theme.warning).add_modifier(Modifier::BOLD),
                 ),
-                Span::styled(&app.search_query, Style::default().fg(app.theme.text)),
+                Span::styled(valid, Style::default().fg(app.theme.text)),
+                Span::styled(
+                    invalid,
+                    Style::default().fg(app.theme.error).add_modifier(Modifier::UNDERLINED),
+                ),
                 Span::styled("█", Style::default().fg(app.theme.warning)),
                 Span::raw("  "),
                 Span::styled("Enter", Style::default().fg(app.theme.success)),
@@ -313,7 +461,7 @@ pub fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
                 Span::styled("ESC", Style::default().fg(app.theme.error)),
                 Span::raw(" to cancel"),
             ]),
-            get_sync_line(),
+            second_line,
         ]
     } else if let Some(error) = &app.error_message {
         // Truncate error to fit in status bar, add hint to view full error
@@ -339,7 +487,13 @@ pub fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
         // Show status message but also show contextual help if in a special mode
         if app.show_issue_popup || app.show_create_issue_form || app.bulk_edit_form.is_some() 
             || app.update_issue_form.is_some() || app.input_mode == InputMode::AddingAttachment
-            || app.input_mode == InputMode::ManagingAttachments {
+            || app.input_mode == InputMode::ManagingAttachments
+            || app.input_mode == InputMode::LoggingTime || app.show_time_entries_popup
+            || app.input_mode == InputMode::AddingRelation
+            || app.input_mode == InputMode::ManagingPendingOperations
+            || app.input_mode == InputMode::ResolvingConflict
+            || app.input_mode == InputMode::GlobalSearch
+            || app.input_mode == InputMode::ManagingFilterPresets {
             // In special modes, show compact help instead of full status
             let use_compact = area.width < 100;
             let contextual_help = if use_compact {
@@ -1,27 +1,4 @@
-use ratatui::{layout::Rect, style::Color};
-
-/// Get color for status name
-pub fn get_status_color(status: &str) -> Color {
-    match status.to_lowercase().as_str() {
-        s if s.contains("new") => Color::Cyan,
-        s if s.contains("progress") => Color::Yellow,
-        s if s.contains("feedback") => Color::Magenta,
-        s if s.contains("resolved") => Color::Green,
-        s if s.contains("closed") => Color::DarkGray,
-        _ => Color::White,
-    }
-}
-
-/// Get color for priority name
-pub fn get_priority_color(priority: &str) -> Color {
-    match priority.to_lowercase().as_str() {
-        s if s.contains("urgent") || s.contains("immediate") => Color::Red,
-        s if s.contains("high") => Color::LightRed,
-        s if s.contains("normal") => Color::White,
-        s if s.contains("low") => Color::DarkGray,
-        _ => Color::White,
-    }
-}
+use ratatui::layout::Rect;
 
 /// Create a centered rectangle for modals/popups
 pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
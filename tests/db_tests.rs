@@ -1,6 +1,9 @@
 use chrono::Utc;
-use minecli::db::Database;
-use minecli::redmine::{IdName, Issue, Journal, JournalDetail, Project};
+use minecli::db::{Database, SchemaTooNew, SCHEMA_VERSION};
+use minecli::issue_query::{FilterQuery, QueryContext, QueryError};
+use minecli::redmine::{
+    Attachment, IdName, Issue, IssueCustomField, IssueRelation, Journal, JournalDetail, Project, TimeEntry,
+};
 use tempfile::TempDir;
 
 fn create_test_db() -> (Database, TempDir) {
@@ -49,6 +52,9 @@ fn create_test_issue(id: u64, project_id: u64, subject: &str) -> Issue {
             name: "Test User".to_string(),
         },
         assigned_to: None,
+        parent: None,
+        category: None,
+        fixed_version: None,
         subject: subject.to_string(),
         description: Some("Test description".to_string()),
         start_date: None,
@@ -59,9 +65,42 @@ fn create_test_issue(id: u64, project_id: u64, subject: &str) -> Issue {
         done_ratio: Some(0),
         is_private: None,
         estimated_hours: None,
+        total_estimated_hours: None,
+        spent_hours: None,
+        total_spent_hours: None,
         journals: vec![],
         custom_fields: vec![],
         attachments: vec![],
+        relations: vec![],
+        children: vec![],
+        watchers: vec![],
+    }
+}
+
+fn create_test_time_entry(id: u64, issue_id: u64, hours: f32, spent_on: &str) -> TimeEntry {
+    TimeEntry {
+        id,
+        project: IdName {
+            id: 1,
+            name: "Test Project".to_string(),
+        },
+        issue: Some(IdName {
+            id: issue_id,
+            name: String::new(),
+        }),
+        user: IdName {
+            id: 1,
+            name: "Test User".to_string(),
+        },
+        activity: IdName {
+            id: 9,
+            name: "Development".to_string(),
+        },
+        hours,
+        comments: Some("Worked on it".to_string()),
+        spent_on: spent_on.to_string(),
+        created_on: Utc::now(),
+        updated_on: Utc::now(),
     }
 }
 
@@ -115,7 +154,7 @@ fn test_insert_and_get_issues() {
 
     // Insert project first
     let project = create_test_project(1, "Test Project");
-    db.insert_projects(&vec![project]).unwrap();
+    db.insert_projects(&[project]).unwrap();
 
     // Insert issues
     let issues = vec![
@@ -126,7 +165,7 @@ fn test_insert_and_get_issues() {
     db.insert_issues(&issues).unwrap();
 
     let retrieved = db
-        .get_issues(Some(1), minecli::app::IssueSortOrder::UpdatedDesc, None, None)
+        .get_issues(Some(1), minecli::app::IssueSortOrder::UpdatedDesc, &FilterQuery::default(), &QueryContext::default())
         .unwrap();
     assert_eq!(retrieved.len(), 2);
 }
@@ -137,7 +176,7 @@ fn test_clear_project_issues() {
 
     // Insert project
     let project = create_test_project(1, "Test Project");
-    db.insert_projects(&vec![project]).unwrap();
+    db.insert_projects(&[project]).unwrap();
 
     // Insert issues
     let issues = vec![create_test_issue(1, 1, "Issue 1"), create_test_issue(2, 1, "Issue 2")];
@@ -145,7 +184,7 @@ fn test_clear_project_issues() {
 
     // Verify inserted
     let retrieved = db
-        .get_issues(Some(1), minecli::app::IssueSortOrder::UpdatedDesc, None, None)
+        .get_issues(Some(1), minecli::app::IssueSortOrder::UpdatedDesc, &FilterQuery::default(), &QueryContext::default())
         .unwrap();
     assert_eq!(retrieved.len(), 2);
 
@@ -154,7 +193,7 @@ fn test_clear_project_issues() {
 
     // Verify cleared
     let retrieved = db
-        .get_issues(Some(1), minecli::app::IssueSortOrder::UpdatedDesc, None, None)
+        .get_issues(Some(1), minecli::app::IssueSortOrder::UpdatedDesc, &FilterQuery::default(), &QueryContext::default())
         .unwrap();
     assert_eq!(retrieved.len(), 0);
 }
@@ -165,7 +204,7 @@ fn test_issue_with_journals() {
 
     // Insert project
     let project = create_test_project(1, "Test Project");
-    db.insert_projects(&vec![project]).unwrap();
+    db.insert_projects(&[project]).unwrap();
 
     // Create issue with journals
     let mut issue = create_test_issue(1, 1, "Issue with Notes");
@@ -176,6 +215,7 @@ fn test_issue_with_journals() {
             name: "Test User".to_string(),
         },
         notes: Some("This is a comment".to_string()),
+        private_notes: false,
         created_on: Utc::now(),
         details: vec![JournalDetail {
             property: "attr".to_string(),
@@ -196,13 +236,123 @@ fn test_issue_with_journals() {
     assert_eq!(retrieved.journals[0].details.len(), 1);
 }
 
+fn id_name(id: u64, name: &str) -> IdName {
+    IdName {
+        id,
+        name: name.to_string(),
+    }
+}
+
+/// An issue with every optional field set, as returned by a full issue fetch
+fn create_full_issue(id: u64, project_id: u64) -> Issue {
+    let mut issue = create_test_issue(id, project_id, "Fully populated");
+    issue.assigned_to = Some(id_name(3, "Assignee"));
+    issue.parent = Some(id_name(10, ""));
+    issue.category = Some(id_name(4, "Backend"));
+    issue.fixed_version = Some(id_name(5, "1.0"));
+    issue.start_date = Some("2025-01-01".to_string());
+    issue.due_date = Some("2025-02-01".to_string());
+    issue.done_ratio = Some(40);
+    issue.is_private = Some(true);
+    issue.estimated_hours = Some(8.5);
+    issue.total_estimated_hours = Some(12.0);
+    issue.spent_hours = Some(2.25);
+    issue.total_spent_hours = Some(3.75);
+    issue.closed_on = Some(Utc::now());
+    issue.custom_fields = vec![
+        IssueCustomField {
+            id: 7,
+            name: "Severity".to_string(),
+            value: "High".to_string(),
+        },
+        IssueCustomField {
+            id: 2,
+            name: "Customer".to_string(),
+            value: String::new(),
+        },
+    ];
+    issue.attachments = vec![Attachment {
+        id: 100,
+        filename: "trace.log".to_string(),
+        filesize: 2048,
+        content_type: Some("text/plain".to_string()),
+        description: "Stack trace".to_string(),
+        content_url: "https://redmine.example.com/attachments/download/100/trace.log".to_string(),
+        thumbnail_url: None,
+        author: id_name(1, "Test User"),
+        created_on: Utc::now(),
+    }];
+    issue.journals = vec![Journal {
+        id: 1,
+        user: id_name(1, "Test User"),
+        notes: Some("Internal only".to_string()),
+        private_notes: true,
+        created_on: Utc::now(),
+        details: vec![],
+    }];
+    issue.relations = vec![IssueRelation {
+        id: 50,
+        issue_id: id,
+        issue_to_id: 99,
+        relation_type: "precedes".to_string(),
+        delay: Some(2),
+    }];
+    issue
+}
+
+#[test]
+fn test_full_issue_roundtrip() {
+    let (db, _temp) = create_test_db();
+    let issue = create_full_issue(1, 1);
+    let as_json = |issue: &Issue| serde_json::to_value(issue).unwrap();
+
+    db.insert_issue_with_journals(&issue).unwrap();
+    let retrieved = db.get_issue_with_journals(1).unwrap().unwrap();
+    assert_eq!(as_json(&retrieved), as_json(&issue));
+
+    // Lists carry everything but the journals
+    let listed = db
+        .get_issues(Some(1), minecli::app::IssueSortOrder::UpdatedDesc, &FilterQuery::default(), &QueryContext::default())
+        .unwrap()
+        .remove(0);
+    let mut without_journals = issue.clone();
+    without_journals.journals.clear();
+    assert_eq!(as_json(&listed), as_json(&without_journals));
+}
+
+#[test]
+fn test_issue_list_sync_keeps_attachments() {
+    let (db, _temp) = create_test_db();
+    db.insert_issue_with_journals(&create_full_issue(1, 1)).unwrap();
+
+    // Issue lists come without attachments but with the current custom fields
+    let mut listed = create_full_issue(1, 1);
+    listed.attachments.clear();
+    listed.custom_fields.truncate(1);
+    listed.category = None;
+    db.insert_issues(&[listed]).unwrap();
+
+    let retrieved = db.get_issue_with_journals(1).unwrap().unwrap();
+    assert_eq!(retrieved.attachments.len(), 1);
+    assert_eq!(retrieved.attachments[0].filename, "trace.log");
+    assert_eq!(retrieved.custom_fields.len(), 1);
+    assert!(retrieved.category.is_none());
+
+    db.delete_issue(1).unwrap();
+    assert!(db.get_issue_with_journals(1).unwrap().is_none());
+    db.insert_issues(&[create_test_issue(1, 1, "Recreated")]).unwrap();
+    let recreated = db.get_issue_with_journals(1).unwrap().unwrap();
+    assert!(recreated.attachments.is_empty());
+    assert!(recreated.custom_fields.is_empty());
+}
+
 #[test]
 fn test_issue_filtering() {
     let (db, _temp) = create_test_db();
 
     // Insert project
     let project = create_test_project(1, "Test Project");
-    db.insert_projects(&vec![project]).unwrap();
+    db.insert_projects(&[project]).unwrap();
 
     // Insert issues with different properties
     let mut issue1 = create_test_issue(1, 1, "Bug in login");
@@ -219,25 +369,154 @@ fn test_issue_filtering() {
 
     let issue3 = create_test_issue(3, 1, "Another bug");
 
-    db.insert_issues(&vec![issue1, issue2, issue3]).unwrap();
+    db.insert_issues(&[issue1, issue2, issue3]).unwrap();
 
     // Filter by subject
     let filtered = db
         .get_issues(
             Some(1),
             minecli::app::IssueSortOrder::UpdatedDesc,
-            Some("bug"),
-            None,
+            &FilterQuery::parse("bug").unwrap(),
+            &QueryContext::default(),
         )
         .unwrap();
     assert_eq!(filtered.len(), 2); // "Bug in login" and "Another bug"
 
     // Filter by assignee
     let filtered = db
-        .get_issues(Some(1), minecli::app::IssueSortOrder::UpdatedDesc, None, Some(5))
+        .get_issues(
+            Some(1),
+            minecli::app::IssueSortOrder::UpdatedDesc,
+            &FilterQuery::parse("assignee:5").unwrap(),
+            &QueryContext::default(),
+        )
         .unwrap();
     assert_eq!(filtered.len(), 1);
     assert_eq!(filtered[0].subject, "Bug in login");
+
+    // Counting applies the same scope and filter without loading the issues
+    let count = |project_id: Option<u64>, query: &str| {
+        db.count_issues(project_id, &FilterQuery::parse(query).unwrap(), &QueryContext::default())
+            .unwrap()
+    };
+    assert_eq!(count(Some(1), ""), 3);
+    assert_eq!(count(Some(1), "bug"), 2);
+    assert_eq!(count(Some(2), "bug"), 0);
+    assert_eq!(count(None, "assignee:5"), 1);
+}
+
+#[test]
+fn test_structured_issue_filter() {
+    let (db, _temp) = create_test_db();
+
+    let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+    let mut urgent = create_test_issue(1, 1, "Urgent fix");
+    urgent.priority = id_name(4, "Urgent");
+    urgent.assigned_to = Some(id_name(7, "Alice"));
+    urgent.due_date = Some("2026-03-12".to_string());
+    let mut closed = create_test_issue(2, 1, "Done already");
+    closed.status = id_name(5, "Closed");
+    closed.priority = id_name(3, "High");
+    let mut later = create_test_issue(3, 1, "Someday");
+    later.priority = id_name(1, "Low");
+    later.due_date = Some("2026-06-01".to_string());
+    db.insert_issues(&[urgent, closed, later]).unwrap();
+
+    let context = QueryContext {
+        current_user_id: Some(7),
+        closed_status_ids: vec![5],
+        priorities: vec![
+            (1, "Low".to_string()),
+            (2, "Normal".to_string()),
+            (3, "High".to_string()),
+            (4, "Urgent".to_string()),
+        ],
+        today,
+    };
+    let ids = |query: &str| -> Vec<u64> {
+        let mut ids: Vec<u64> = db
+            .get_issues(
+                Some(1),
+                minecli::app::IssueSortOrder::UpdatedDesc,
+                &FilterQuery::parse(query).unwrap(),
+                &context,
+            )
+            .unwrap()
+            .iter()
+            .map(|issue| issue.id)
+            .collect();
+        ids.sort();
+        ids
+    };
+
+    assert_eq!(ids("status:open"), vec![1, 3]);
+    assert_eq!(ids("status:closed"), vec![2]);
+    assert_eq!(ids("priority:>=high"), vec![1, 2]);
+    assert_eq!(ids("priority:<norm"), vec![3]);
+    assert_eq!(ids("due:<7d"), vec![1]);
+    assert_eq!(ids("due:>2026-04-01"), vec![3]);
+    assert_eq!(ids("assignee:me"), vec![1]);
+    assert_eq!(ids("assignee:none status:open"), vec![3]);
+    assert_eq!(ids("assignee:!me"), vec![2, 3]);
+
+    // Names the cache doesn't know fail when compiling
+    let err = db
+        .get_issues(
+            Some(1),
+            minecli::app::IssueSortOrder::UpdatedDesc,
+            &FilterQuery::parse("priority:blocker").unwrap(),
+            &context,
+        )
+        .unwrap_err();
+    assert!(err.downcast_ref::<QueryError>().unwrap().message.contains("blocker"));
+}
+
+#[test]
+fn test_full_text_search() {
+    let (db, _temp) = create_test_db();
+
+    let mut login = create_test_issue(1, 1, "Login page crashes");
+    login.description = Some("Stack trace attached".to_string());
+    let mut export = create_test_issue(2, 2, "CSV export is slow");
+    export.journals = vec![Journal {
+        id: 1,
+        user: id_name(1, "Test User"),
+        notes: Some("Only happens after the login redirect".to_string()),
+        private_notes: false,
+        created_on: Utc::now(),
+        details: vec![],
+    }];
+    db.insert_issues(&[login]).unwrap();
+    db.insert_issue_with_journals(&export).unwrap();
+
+    // Searches every project and the notes; subject matches rank first
+    let hits = db.search_issues("login", 10).unwrap();
+    let ids: Vec<u64> = hits.iter().map(|hit| hit.issue.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+    assert!(hits[1].snippet.contains("\u{2}login\u{3}"));
+
+    // Words match as prefixes, all of them must match, and FTS syntax is taken literally
+    assert_eq!(db.search_issues("crash pag", 10).unwrap().len(), 1);
+    assert!(db.search_issues("login csv", 10).unwrap().iter().all(|hit| hit.issue.id == 2));
+    assert!(db.search_issues("\"login OR* (", 10).is_ok());
+    assert!(db.search_issues("-- *", 10).unwrap().is_empty());
+
+    // The project issue filter uses the same index
+    let filtered = db
+        .get_issues(
+            Some(2),
+            minecli::app::IssueSortOrder::UpdatedDesc,
+            &FilterQuery::parse("redirect").unwrap(),
+            &QueryContext::default(),
+        )
+        .unwrap();
+    assert_eq!(filtered.len(), 1);
+
+    // Refetched or deleted issues don't leave stale entries behind
+    export.journals.clear();
+    db.insert_issue_with_journals(&export).unwrap();
+    db.delete_issue(1).unwrap();
+    assert!(db.search_issues("login", 10).unwrap().is_empty());
 }
 
 #[test]
@@ -256,3 +535,647 @@ fn test_sync_timestamps() {
     let last_sync = db.get_last_projects_sync().unwrap();
     assert!(last_sync.is_some());
 }
+
+#[test]
+fn test_project_issues_sync() {
+    let (db, _temp) = create_test_db();
+    db.insert_projects(&[create_test_project(1, "Test Project")]).unwrap();
+    assert!(db.get_project_issues_sync(1).unwrap().is_none());
+
+    // Caching issues or a single issue detail doesn't count as a project sync
+    db.insert_issues(&[create_test_issue(1, 1, "Cached")]).unwrap();
+    db.insert_issue_with_journals(&create_test_issue(2, 1, "Opened")).unwrap();
+    assert!(db.get_project_issues_sync(1).unwrap().is_none());
+
+    let synced_at = Utc::now();
+    db.set_project_issues_sync(1, synced_at).unwrap();
+    let stored = db.get_project_issues_sync(1).unwrap().unwrap();
+    assert_eq!(stored.timestamp(), synced_at.timestamp());
+
+    // Re-inserting the project keeps its sync time
+    db.insert_projects(&[create_test_project(1, "Renamed")]).unwrap();
+    assert!(db.get_project_issues_sync(1).unwrap().is_some());
+
+    assert!(db.get_project_issues_sync(99).unwrap().is_none());
+}
+
+#[test]
+fn test_remove_stale_issues() {
+    let (db, _temp) = create_test_db();
+
+    let mut child = create_test_project(2, "Child");
+    child.parent = Some(IdName {
+        id: 1,
+        name: "Parent".to_string(),
+    });
+    db.insert_projects(&[create_test_project(1, "Parent"), child, create_test_project(3, "Other")])
+        .unwrap();
+
+    let mut deleted = create_test_issue(2, 1, "Deleted on server");
+    deleted.journals = vec![Journal {
+        id: 20,
+        user: IdName {
+            id: 1,
+            name: "Test User".to_string(),
+        },
+        notes: Some("Note".to_string()),
+        created_on: Utc::now(),
+        private_notes: false,
+        details: vec![],
+    }];
+    db.insert_issues(&[
+        create_test_issue(1, 1, "Still there"),
+        create_test_issue(3, 2, "Gone from child"),
+        create_test_issue(4, 3, "Other project"),
+    ])
+    .unwrap();
+    db.insert_issue_with_journals(&deleted).unwrap();
+
+    // Without subprojects only the parent's own issues are checked
+    assert_eq!(db.remove_stale_issues(1, false, &[1]).unwrap(), 1);
+    let remaining: Vec<u64> = db
+        .get_issues(None, minecli::app::IssueSortOrder::UpdatedDesc, &FilterQuery::default(), &QueryContext::default())
+        .unwrap()
+        .iter()
+        .map(|i| i.id)
+        .collect();
+    assert!(remaining.contains(&3));
+    assert!(!remaining.contains(&2));
+    assert!(db.get_issue_with_journals(2).unwrap().is_none());
+
+    // With subprojects the child's issues are checked, other projects never are
+    assert_eq!(db.remove_stale_issues(1, true, &[1]).unwrap(), 1);
+    let mut remaining: Vec<u64> = db
+        .get_issues(None, minecli::app::IssueSortOrder::UpdatedDesc, &FilterQuery::default(), &QueryContext::default())
+        .unwrap()
+        .iter()
+        .map(|i| i.id)
+        .collect();
+    remaining.sort();
+    assert_eq!(remaining, vec![1, 4]);
+
+    assert_eq!(db.remove_stale_issues(1, true, &[1]).unwrap(), 0);
+}
+
+#[test]
+fn test_time_entries() {
+    let (db, _temp) = create_test_db();
+
+    let entries = [
+        create_test_time_entry(1, 10, 1.5, "2024-11-01"),
+        create_test_time_entry(2, 10, 0.25, "2024-11-03"),
+        create_test_time_entry(3, 11, 2.0, "2024-11-02"),
+    ];
+    db.insert_time_entries(10, &entries[..2]).unwrap();
+    db.insert_time_entries(11, &entries[2..]).unwrap();
+
+    // Newest first, scoped to the issue
+    let retrieved = db.get_time_entries(10).unwrap();
+    assert_eq!(retrieved.len(), 2);
+    assert_eq!(retrieved[0].id, 2);
+    assert_eq!(retrieved[0].activity.name, "Development");
+    assert_eq!(retrieved[1].hours, 1.5);
+    assert_eq!(retrieved[1].comments.as_deref(), Some("Worked on it"));
+
+    // Single upsert updates in place
+    let mut edited = create_test_time_entry(1, 10, 3.0, "2024-11-01");
+    edited.comments = None;
+    db.insert_time_entry(&edited).unwrap();
+    let retrieved = db.get_time_entries(10).unwrap();
+    assert_eq!(retrieved.len(), 2);
+    assert_eq!(retrieved[1].hours, 3.0);
+    assert!(retrieved[1].comments.is_none());
+
+    // Deleting only removes that entry
+    db.delete_time_entry(2).unwrap();
+    assert_eq!(db.get_time_entries(10).unwrap().len(), 1);
+    assert_eq!(db.get_time_entries(11).unwrap().len(), 1);
+
+    // Refreshing replaces the cached list for the issue
+    db.insert_time_entries(10, &[]).unwrap();
+    assert!(db.get_time_entries(10).unwrap().is_empty());
+}
+
+#[test]
+fn test_metadata_roundtrip() {
+    let (db, _temp) = create_test_db();
+
+    assert!(db.get_metadata("active_timer").unwrap().is_none());
+
+    db.set_metadata("active_timer", "{\"issue_id\":1}").unwrap();
+    db.set_metadata("active_timer", "{\"issue_id\":2}").unwrap();
+    assert_eq!(db.get_metadata("active_timer").unwrap().as_deref(), Some("{\"issue_id\":2}"));
+
+    db.delete_metadata("active_timer").unwrap();
+    assert!(db.get_metadata("active_timer").unwrap().is_none());
+}
+
+#[test]
+fn test_pending_operations_queue() {
+    use minecli::app::{QueuedWrite, LOCAL_ID_BASE};
+    use minecli::redmine::{CreateIssue, UpdateIssue};
+
+    let (db, _temp) = create_test_db();
+
+    let new_issue = CreateIssue {
+        project_id: 1,
+        tracker_id: 1,
+        status_id: 1,
+        priority_id: 2,
+        subject: "Written on a plane".to_string(),
+        description: None,
+        assigned_to_id: None,
+        category_id: None,
+        start_date: None,
+        due_date: None,
+        estimated_hours: None,
+        done_ratio: None,
+        fixed_version_id: None,
+        parent_issue_id: None,
+        watcher_user_ids: None,
+        uploads: None,
+    };
+    let create = QueuedWrite::CreateIssue(new_issue.clone());
+    let comment = QueuedWrite::UpdateIssue(UpdateIssue {
+        notes: Some("Offline note".to_string()),
+        ..Default::default()
+    });
+
+    // Creates get a local issue id, updates keep theirs
+    let created = db.queue_operation(None, &create).unwrap();
+    assert_eq!(created.issue_id, LOCAL_ID_BASE + created.id);
+    let updated = db.queue_operation(Some(created.issue_id), &comment).unwrap();
+    let other = db.queue_operation(Some(7), &comment).unwrap();
+    assert_eq!(db.count_pending_operations().unwrap(), 3);
+
+    let operations = db.get_pending_operations().unwrap();
+    let ids: Vec<u64> = operations.iter().map(|op| op.id).collect();
+    assert_eq!(ids, vec![created.id, updated.id, other.id]);
+    assert!(matches!(&operations[0].write, QueuedWrite::CreateIssue(c) if c.subject == "Written on a plane"));
+    assert!(matches!(&operations[1].write, QueuedWrite::UpdateIssue(u) if u.notes.as_deref() == Some("Offline note")));
+
+    db.record_pending_failure(created.id, "Subject cannot be blank", true).unwrap();
+    let failed = &db.get_pending_operations().unwrap()[0];
+    assert_eq!(failed.attempts, 1);
+    assert!(failed.blocked);
+    assert_eq!(failed.last_error.as_deref(), Some("Subject cannot be blank"));
+    db.unblock_pending_operation(created.id).unwrap();
+    assert!(!db.get_pending_operations().unwrap()[0].blocked);
+
+    // Once created on the server, queued updates follow the real id
+    db.delete_pending_operation(created.id).unwrap();
+    db.reassign_pending_operations(created.issue_id, 42).unwrap();
+    let issue_ids: Vec<u64> = db.get_pending_operations().unwrap().iter().map(|op| op.issue_id).collect();
+    assert_eq!(issue_ids, vec![42, 7]);
+
+    db.delete_pending_operations_for_issue(42).unwrap();
+    assert_eq!(db.count_pending_operations().unwrap(), 1);
+
+    // Removing a parent is sent as a blank id and survives the queue
+    let unparent = UpdateIssue {
+        parent_issue_id: Some(0),
+        ..Default::default()
+    };
+    assert_eq!(serde_json::to_value(&unparent).unwrap()["parent_issue_id"], "");
+    db.queue_operation(Some(7), &QueuedWrite::UpdateIssue(unparent)).unwrap();
+    let operations = db.get_pending_operations().unwrap();
+    assert!(matches!(&operations[1].write, QueuedWrite::UpdateIssue(u) if u.parent_issue_id == Some(0)));
+
+    // Writes naming a locally created issue as parent follow its real id too
+    let parent = db.queue_operation(None, &create).unwrap();
+    let subtask = CreateIssue {
+        parent_issue_id: Some(parent.issue_id),
+        ..new_issue
+    };
+    db.queue_operation(None, &QueuedWrite::CreateIssue(subtask)).unwrap();
+    let reparent = UpdateIssue {
+        parent_issue_id: Some(parent.issue_id),
+        ..Default::default()
+    };
+    db.queue_operation(Some(7), &QueuedWrite::UpdateIssue(reparent)).unwrap();
+    db.delete_pending_operation(parent.id).unwrap();
+    db.reassign_pending_operations(parent.issue_id, 43).unwrap();
+    let parents: Vec<Option<u64>> = db
+        .get_pending_operations()
+        .unwrap()
+        .iter()
+        .map(|op| match &op.write {
+            QueuedWrite::CreateIssue(c) => c.parent_issue_id,
+            QueuedWrite::UpdateIssue(u) => u.parent_issue_id,
+        })
+        .collect();
+    assert_eq!(parents, vec![None, Some(0), Some(43), Some(43)]);
+}
+
+#[test]
+fn test_schema_migrations() {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("legacy.db");
+
+    // A cache from before schema versioning, without the later project columns
+    {
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        conn.execute(
+            "CREATE TABLE projects (id INTEGER PRIMARY KEY, name TEXT NOT NULL, identifier TEXT NOT NULL,
+             description TEXT, status INTEGER, created_on TEXT, updated_on TEXT)",
+            [],
+        )
+        .unwrap();
+    }
+
+    let db = Database::open(db_path.clone()).unwrap();
+    assert_eq!(db.schema_version().unwrap(), 0);
+    assert_eq!(db.migrate().unwrap(), 0);
+    assert_eq!(db.schema_version().unwrap(), SCHEMA_VERSION);
+    db.insert_projects(&[create_test_project(1, "Legacy")]).unwrap();
+    assert_eq!(db.get_projects(None).unwrap().len(), 1);
+
+    // Running again is a no-op
+    assert_eq!(db.migrate().unwrap(), SCHEMA_VERSION);
+
+    // A cache from a newer minecli is refused rather than half-upgraded
+    db.set_metadata("schema_version", &(SCHEMA_VERSION + 1).to_string()).unwrap();
+    drop(db);
+    let err = Database::new(db_path.clone()).err().unwrap();
+    let too_new = err.downcast_ref::<SchemaTooNew>().unwrap();
+    assert_eq!(too_new.found, SCHEMA_VERSION + 1);
+
+    // Resetting starts over with an empty cache at the current version
+    Database::reset(&db_path).unwrap();
+    let db = Database::new(db_path).unwrap();
+    assert_eq!(db.schema_version().unwrap(), SCHEMA_VERSION);
+    assert!(db.get_projects(None).unwrap().is_empty());
+}
+
+#[test]
+fn test_filter_presets() {
+    use minecli::app::{FilterPreset, IssueSortOrder};
+
+    let (db, _temp) = create_test_db();
+    let preset = |name: &str, filter: &str| FilterPreset {
+        id: 0,
+        name: name.to_string(),
+        slot: None,
+        project_id: Some(1),
+        issue_filter: filter.to_string(),
+        sort_order: IssueSortOrder::PriorityDesc,
+        group_by_status: true,
+        my_issues: false,
+    };
+
+    let triage = db.save_filter_preset(&preset("Triage", "status:open assignee:none")).unwrap();
+    let mine = db.save_filter_preset(&preset("Mine", "assignee:me")).unwrap();
+    db.set_filter_preset_slot(triage, Some(1)).unwrap();
+
+    let presets = db.get_filter_presets().unwrap();
+    assert_eq!(presets.len(), 2);
+    assert_eq!(presets[0].name, "Triage");
+    assert_eq!(presets[0].slot, Some(1));
+    assert_eq!(presets[0].sort_order, IssueSortOrder::PriorityDesc);
+    assert!(presets[0].group_by_status);
+
+    // Saving under an existing name updates it and keeps its key
+    assert_eq!(db.save_filter_preset(&preset("Triage", "status:open")).unwrap(), triage);
+    let presets = db.get_filter_presets().unwrap();
+    assert_eq!(presets[0].issue_filter, "status:open");
+    assert_eq!(presets[0].slot, Some(1));
+
+    // A key belongs to one preset at a time
+    db.set_filter_preset_slot(mine, Some(1)).unwrap();
+    let presets = db.get_filter_presets().unwrap();
+    assert_eq!((presets[0].id, presets[0].slot), (mine, Some(1)));
+    assert_eq!(presets[1].slot, None);
+
+    db.delete_filter_preset(mine).unwrap();
+    assert_eq!(db.get_filter_presets().unwrap().len(), 1);
+}
+
+#[test]
+fn test_saved_query_results() {
+    use minecli::issue_query::Term;
+    use minecli::redmine::SavedQuery;
+
+    let (db, _temp) = create_test_db();
+    db.insert_issues(&[
+        create_test_issue(1, 1, "Backlog item"),
+        create_test_issue(2, 2, "Other project item"),
+        create_test_issue(3, 1, "Not in the sprint"),
+    ])
+    .unwrap();
+
+    let query = |id: u64, name: &str, project_id: Option<u64>| SavedQuery {
+        id,
+        name: name.to_string(),
+        is_public: true,
+        project_id,
+    };
+    db.replace_saved_queries(&[query(7, "Sprint backlog", Some(1)), query(8, "All open", None)])
+        .unwrap();
+    let queries = db.get_saved_queries().unwrap();
+    assert_eq!(queries[0].name, "All open");
+    assert_eq!(queries[1].project_id, Some(1));
+
+    // Results can span projects and combine with typed filters
+    db.set_saved_query_issues(7, &[1, 2]).unwrap();
+    assert!(db.has_saved_query_issues(7).unwrap());
+    let ids = |terms: Vec<Term>| -> Vec<u64> {
+        let mut ids: Vec<u64> = db
+            .get_issues(
+                None,
+                minecli::app::IssueSortOrder::UpdatedDesc,
+                &FilterQuery { terms },
+                &QueryContext::default(),
+            )
+            .unwrap()
+            .iter()
+            .map(|issue| issue.id)
+            .collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(ids(vec![Term::SavedQuery(7)]), vec![1, 2]);
+    assert_eq!(ids(vec![Term::SavedQuery(7), Term::Word("other".to_string())]), vec![2]);
+
+    // Queries deleted on the server take their results with them
+    db.replace_saved_queries(&[query(8, "All open", None)]).unwrap();
+    assert!(!db.has_saved_query_issues(7).unwrap());
+    assert!(ids(vec![Term::SavedQuery(7)]).is_empty());
+}
+
+#[test]
+fn test_dashboard_sections() {
+    use minecli::app::DashboardSection;
+
+    let (db, _temp) = create_test_db();
+    let me = IdName {
+        id: 5,
+        name: "Me".to_string(),
+    };
+    let mut assigned = create_test_issue(1, 1, "Assigned to me");
+    assigned.assigned_to = Some(me.clone());
+    let mut overdue = create_test_issue(2, 2, "Overdue in another project");
+    overdue.assigned_to = Some(me.clone());
+    overdue.due_date = Some("2020-01-01".to_string());
+    let mut reported = create_test_issue(3, 1, "Reported by me");
+    reported.author = me.clone();
+    let watched = create_test_issue(4, 2, "Watched");
+    let mut closed = create_test_issue(5, 1, "Closed and assigned to me");
+    closed.assigned_to = Some(me);
+    closed.closed_on = Some(Utc::now());
+    db.insert_issues(&[assigned, overdue, reported, watched, closed]).unwrap();
+    db.set_watched_issues(&[4, 5]).unwrap();
+
+    let context = QueryContext {
+        current_user_id: Some(5),
+        ..QueryContext::default()
+    };
+    let ids = |section: DashboardSection| -> Vec<u64> {
+        let mut ids: Vec<u64> = db
+            .get_issues(None, minecli::app::IssueSortOrder::UpdatedDesc, &section.query(), &context)
+            .unwrap()
+            .iter()
+            .map(|issue| issue.id)
+            .collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(ids(DashboardSection::Assigned), vec![1, 2]);
+    assert_eq!(ids(DashboardSection::Reported), vec![3]);
+    assert_eq!(ids(DashboardSection::Watched), vec![4]);
+    assert_eq!(ids(DashboardSection::Overdue), vec![2]);
+
+    // Replacing the watched set forgets issues no longer watched
+    db.set_watched_issues(&[1]).unwrap();
+    assert_eq!(ids(DashboardSection::Watched), vec![1]);
+}
+
+#[test]
+fn test_issue_relations() {
+    let (db, _temp) = create_test_db();
+    let relation = |id: u64, issue_id: u64, issue_to_id: u64, relation_type: &str| IssueRelation {
+        id,
+        issue_id,
+        issue_to_id,
+        relation_type: relation_type.to_string(),
+        delay: None,
+    };
+
+    let mut blocker = create_test_issue(1, 1, "Blocker");
+    blocker.relations = vec![relation(10, 1, 2, "blocks"), relation(11, 3, 1, "relates")];
+    db.insert_issue_with_journals(&blocker).unwrap();
+    db.insert_issues(&[create_test_issue(2, 1, "Blocked"), create_test_issue(3, 1, "Related")])
+        .unwrap();
+
+    // A relation is cached once and read from both ends, labelled from the reader's side
+    let blocked = db.get_issue_with_journals(2).unwrap().unwrap();
+    assert_eq!(blocked.relations, vec![relation(10, 1, 2, "blocks")]);
+    let labels = |issue: &Issue| -> Vec<(&'static str, u64)> {
+        issue
+            .related_issues()
+            .into_iter()
+            .map(|(label, r)| (label, r.other_issue_id(issue.id)))
+            .collect()
+    };
+    assert_eq!(labels(&blocked), vec![("Blocked by", 1)]);
+    let blocker = db.get_issue_with_journals(1).unwrap().unwrap();
+    assert_eq!(labels(&blocker), vec![("Blocks", 2), ("Related to", 3)]);
+
+    // Lists read the relations of all listed issues at once, from both ends
+    let listed = db
+        .get_issues(None, minecli::app::IssueSortOrder::UpdatedDesc, &FilterQuery::default(), &QueryContext::default())
+        .unwrap();
+    assert_eq!(listed.len(), 3);
+    for issue in &listed {
+        let single = db.get_issue_with_journals(issue.id).unwrap().unwrap();
+        assert_eq!(issue.relations, single.relations);
+    }
+
+    // A fresh fetch of an issue replaces its relations, including ones stored from the other end
+    let mut refetched = create_test_issue(2, 1, "Blocked");
+    refetched.relations = vec![relation(12, 2, 3, "duplicates")];
+    db.insert_issue_with_journals(&refetched).unwrap();
+    let blocker = db.get_issue_with_journals(1).unwrap().unwrap();
+    assert_eq!(labels(&blocker), vec![("Related to", 3)]);
+    let related = db.get_issue_with_journals(3).unwrap().unwrap();
+    assert_eq!(labels(&related), vec![("Related to", 1), ("Duplicated by", 2)]);
+
+    // Deleting an issue drops its relations
+    db.delete_issue(3).unwrap();
+    assert!(db.get_issue_with_journals(1).unwrap().unwrap().relations.is_empty());
+}
+
+#[test]
+fn test_subtask_tree() {
+    let (db, _temp) = create_test_db();
+    let subtask = |id: u64, parent_id: u64, subject: &str| {
+        let mut issue = create_test_issue(id, 1, subject);
+        issue.parent = Some(IdName {
+            id: parent_id,
+            name: String::new(),
+        });
+        issue
+    };
+    db.insert_issues(&[
+        create_test_issue(1, 1, "Epic"),
+        subtask(3, 1, "Second story"),
+        subtask(2, 1, "First story"),
+        subtask(4, 2, "Task"),
+        create_test_issue(5, 1, "Unrelated"),
+    ])
+    .unwrap();
+
+    // The cached tree follows parent ids, nested and ordered by id
+    fn tree(children: &[minecli::redmine::IssueChild]) -> Vec<(u64, Vec<u64>)> {
+        children
+            .iter()
+            .map(|c| (c.id, c.children.iter().map(|g| g.id).collect()))
+            .collect()
+    }
+    let epic = db.get_issue_with_journals(1).unwrap().unwrap();
+    assert_eq!(tree(&epic.children), vec![(2, vec![4]), (3, vec![])]);
+    assert_eq!(epic.children[0].subject, "First story");
+
+    let story = db.get_issue_with_journals(2).unwrap().unwrap();
+    assert_eq!(story.parent.map(|p| p.id), Some(1));
+    assert_eq!(tree(&story.children), vec![(4, vec![])]);
+    assert!(db.get_issue_with_journals(5).unwrap().unwrap().children.is_empty());
+}
+
+#[test]
+fn test_issue_watchers() {
+    let (db, _temp) = create_test_db();
+    let mut issue = create_test_issue(1, 1, "Watched by two");
+    issue.watchers = vec![id_name(7, "Zoe"), id_name(5, "Me")];
+    db.insert_issue_with_journals(&issue).unwrap();
+    db.insert_issues(&[create_test_issue(2, 1, "Not watched")]).unwrap();
+
+    // Watchers come back from the cache sorted by name
+    let names = |id: u64| -> Vec<String> {
+        db.get_issue_with_journals(id)
+            .unwrap()
+            .unwrap()
+            .watchers
+            .into_iter()
+            .map(|w| w.name)
+            .collect()
+    };
+    assert_eq!(names(1), vec!["Me", "Zoe"]);
+
+    // A list sync doesn't know watchers and keeps them; a fresh fetch replaces them
+    db.insert_issues(&[create_test_issue(1, 1, "Watched by two")]).unwrap();
+    assert_eq!(names(1), vec!["Me", "Zoe"]);
+    issue.watchers = vec![id_name(7, "Zoe")];
+    db.insert_issue_with_journals(&issue).unwrap();
+    assert_eq!(names(1), vec!["Zoe"]);
+
+    // watcher:me and watcher:!me follow the issues marked as watched
+    let context = QueryContext {
+        current_user_id: Some(5),
+        ..QueryContext::default()
+    };
+    let ids = |filter: &str| -> Vec<u64> {
+        let mut ids: Vec<u64> = db
+            .get_issues(
+                Some(1),
+                minecli::app::IssueSortOrder::UpdatedDesc,
+                &FilterQuery::parse(filter).unwrap(),
+                &context,
+            )
+            .unwrap()
+            .iter()
+            .map(|issue| issue.id)
+            .collect();
+        ids.sort();
+        ids
+    };
+    db.set_issue_watched(2, true).unwrap();
+    assert!(db.is_issue_watched(2).unwrap());
+    assert_eq!(ids("watcher:me"), vec![2]);
+    assert_eq!(ids("watcher:!me"), vec![1]);
+    db.set_issue_watched(2, false).unwrap();
+    assert!(ids("watcher:me").is_empty());
+
+    // Deleting an issue drops its watchers
+    db.delete_issue(1).unwrap();
+    db.insert_issues(&[create_test_issue(1, 1, "Synced again")]).unwrap();
+    assert!(names(1).is_empty());
+}
+
+#[test]
+fn test_project_versions() {
+    use minecli::redmine::Version;
+
+    let (db, _temp) = create_test_db();
+    let version = |id: u64, name: &str, due_date: Option<&str>| Version {
+        id,
+        project: id_name(1, "Test Project"),
+        name: name.to_string(),
+        description: None,
+        status: "open".to_string(),
+        due_date: due_date.map(str::to_string),
+        sharing: Some("none".to_string()),
+    };
+
+    // Roadmap order: by due date, undated versions last
+    db.replace_project_versions(
+        1,
+        &[
+            version(3, "Someday", None),
+            version(2, "2.0", Some("2026-12-01")),
+            version(1, "1.0", Some("2026-06-01")),
+        ],
+    )
+    .unwrap();
+    let names: Vec<String> = db.get_project_versions(1).unwrap().into_iter().map(|v| v.name).collect();
+    assert_eq!(names, vec!["1.0", "2.0", "Someday"]);
+    assert_eq!(db.get_version_name(2).unwrap().as_deref(), Some("2.0"));
+    assert!(db.get_project_versions(2).unwrap().is_empty());
+
+    // Replacing drops versions the server no longer lists
+    db.replace_project_versions(1, &[version(1, "1.0", Some("2026-06-01"))]).unwrap();
+    assert_eq!(db.get_project_versions(1).unwrap().len(), 1);
+
+    // Progress: closed issues count as done, open ones by their done ratio
+    let mut issues = Vec::new();
+    for (id, status_id, done_ratio) in [(1, 5, 0), (2, 1, 50), (3, 1, 0)] {
+        let mut issue = create_test_issue(id, 1, "In 1.0");
+        issue.status = id_name(status_id, if status_id == 5 { "Closed" } else { "New" });
+        issue.done_ratio = Some(done_ratio);
+        issue.fixed_version = Some(id_name(1, "1.0"));
+        issues.push(issue);
+    }
+    issues.push(create_test_issue(4, 1, "No version"));
+    db.insert_issues(&issues).unwrap();
+
+    let context = QueryContext {
+        closed_status_ids: vec![5],
+        ..QueryContext::default()
+    };
+    let progress = db.get_version_progress(&context).unwrap();
+    assert_eq!(progress.len(), 1);
+    let progress = &progress[&1];
+    assert_eq!((progress.total, progress.closed), (3, 1));
+    assert_eq!(progress.percent_done(), 50);
+
+    // version: filters by id, name or no version at all
+    let ids = |filter: &str| -> Vec<u64> {
+        let mut ids: Vec<u64> = db
+            .get_issues(
+                Some(1),
+                minecli::app::IssueSortOrder::UpdatedDesc,
+                &FilterQuery::parse(filter).unwrap(),
+                &context,
+            )
+            .unwrap()
+            .iter()
+            .map(|issue| issue.id)
+            .collect();
+        ids.sort();
+        ids
+    };
+    assert_eq!(ids("version:1"), vec![1, 2, 3]);
+    assert_eq!(ids("version:\"1.0\""), vec![1, 2, 3]);
+    assert_eq!(ids("version:none"), vec![4]);
+    assert_eq!(ids("version:!none"), vec![1, 2, 3]);
+    assert_eq!(ids("version:!1"), vec![4]);
+}